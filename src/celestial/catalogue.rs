@@ -0,0 +1,282 @@
+// fisica::celestial::catalogue
+//
+// The bodies of the Solar System.
+//
+// Data from NASA's planetary fact sheets and the JPL Solar System Dynamics.
+// - <https://nssdc.gsfc.nasa.gov/planetary/factsheet/>
+// - <https://ssd.jpl.nasa.gov/>
+
+use core::f64::consts::PI;
+
+use super::{CelestialBody, CelestialOrbit};
+use crate::units::{Length, Mass, Time};
+use crate::Magnitude;
+
+/// Degrees to radians.
+const DEG: Magnitude = PI / 180.;
+/// Days to seconds.
+const DAY: Magnitude = 86_400.;
+/// Hours to seconds.
+const HOUR: Magnitude = 3_600.;
+/// Kilometres to metres.
+const KM: Magnitude = 1e3;
+/// Astronomical units to metres.
+const AU: Magnitude = Length::ASTRONOMICAL_UNIT.m();
+
+const fn body(
+    name: &'static str,
+    mass: Magnitude,
+    radius: Magnitude,
+    rotation_period: Magnitude,
+    orbit: Option<CelestialOrbit>,
+) -> CelestialBody {
+    CelestialBody {
+        name,
+        mass: Mass::new(mass),
+        radius: Length::new(radius),
+        rotation_period: Time::new(rotation_period),
+        orbit,
+    }
+}
+
+const fn orbit(
+    primary: &'static CelestialBody,
+    semi_major_axis: Magnitude,
+    eccentricity: Magnitude,
+    inclination: Magnitude,
+) -> Option<CelestialOrbit> {
+    Some(CelestialOrbit {
+        primary,
+        semi_major_axis: Length::new(semi_major_axis),
+        eccentricity,
+        inclination,
+    })
+}
+
+/// # The Sun and the planets
+impl CelestialBody {
+    /// The [Sun](https://en.wikipedia.org/wiki/Sun).
+    pub const SUN: Self = body("Sun", 1.988_47e30, 695_700. * KM, 25.38 * DAY, None);
+
+    /// [Mercury](https://en.wikipedia.org/wiki/Mercury_(planet)).
+    pub const MERCURY: Self = body(
+        "Mercury",
+        3.3011e23,
+        2_439.7 * KM,
+        58.646 * DAY,
+        orbit(&Self::SUN, 0.387_098 * AU, 0.205_630, 7.005 * DEG),
+    );
+
+    /// [Venus](https://en.wikipedia.org/wiki/Venus).
+    pub const VENUS: Self = body(
+        "Venus",
+        4.8675e24,
+        6_051.8 * KM,
+        -243.0226 * DAY,
+        orbit(&Self::SUN, 0.723_332 * AU, 0.006_772, 3.394_58 * DEG),
+    );
+
+    /// [Earth](https://en.wikipedia.org/wiki/Earth).
+    pub const EARTH: Self = body(
+        "Earth",
+        5.972_168e24,
+        6_371.0 * KM,
+        0.997_269_68 * DAY,
+        orbit(&Self::SUN, 1.000_001_018 * AU, 0.016_708_6, 0.000_05 * DEG),
+    );
+
+    /// [Mars](https://en.wikipedia.org/wiki/Mars).
+    pub const MARS: Self = body(
+        "Mars",
+        6.4171e23,
+        3_389.5 * KM,
+        1.025_957 * DAY,
+        orbit(&Self::SUN, 1.523_680_55 * AU, 0.0934, 1.850 * DEG),
+    );
+
+    /// [Jupiter](https://en.wikipedia.org/wiki/Jupiter).
+    pub const JUPITER: Self = body(
+        "Jupiter",
+        1.8982e27,
+        69_911. * KM,
+        9.9250 * HOUR,
+        orbit(&Self::SUN, 5.2038 * AU, 0.0489, 1.303 * DEG),
+    );
+
+    /// [Saturn](https://en.wikipedia.org/wiki/Saturn).
+    pub const SATURN: Self = body(
+        "Saturn",
+        5.6834e26,
+        58_232. * KM,
+        10.561 * HOUR,
+        orbit(&Self::SUN, 9.5826 * AU, 0.0565, 2.485 * DEG),
+    );
+
+    /// [Uranus](https://en.wikipedia.org/wiki/Uranus).
+    pub const URANUS: Self = body(
+        "Uranus",
+        8.6810e25,
+        25_362. * KM,
+        -0.718_33 * DAY,
+        orbit(&Self::SUN, 19.191_26 * AU, 0.047_17, 0.773 * DEG),
+    );
+
+    /// [Neptune](https://en.wikipedia.org/wiki/Neptune).
+    pub const NEPTUNE: Self = body(
+        "Neptune",
+        1.024_13e26,
+        24_622. * KM,
+        16.11 * HOUR,
+        orbit(&Self::SUN, 30.07 * AU, 0.008_678, 1.770 * DEG),
+    );
+}
+
+/// # Major moons
+impl CelestialBody {
+    /// The [Moon](https://en.wikipedia.org/wiki/Moon), of the Earth.
+    pub const MOON: Self = body(
+        "Moon",
+        7.342e22,
+        1_737.4 * KM,
+        27.321_661 * DAY,
+        orbit(&Self::EARTH, 384_399. * KM, 0.0549, 5.145 * DEG),
+    );
+
+    /// [Io](https://en.wikipedia.org/wiki/Io_(moon)), of Jupiter.
+    pub const IO: Self = body(
+        "Io",
+        8.931_938e22,
+        1_821.6 * KM,
+        1.769_137_786 * DAY,
+        orbit(&Self::JUPITER, 421_700. * KM, 0.0041, 0.05 * DEG),
+    );
+
+    /// [Europa](https://en.wikipedia.org/wiki/Europa_(moon)), of Jupiter.
+    pub const EUROPA: Self = body(
+        "Europa",
+        4.799_844e22,
+        1_560.8 * KM,
+        3.551_181 * DAY,
+        orbit(&Self::JUPITER, 670_900. * KM, 0.009, 0.470 * DEG),
+    );
+
+    /// [Ganymede](https://en.wikipedia.org/wiki/Ganymede_(moon)), of Jupiter.
+    pub const GANYMEDE: Self = body(
+        "Ganymede",
+        1.4819e23,
+        2_634.1 * KM,
+        7.154_553 * DAY,
+        orbit(&Self::JUPITER, 1_070_400. * KM, 0.0013, 0.20 * DEG),
+    );
+
+    /// [Callisto](https://en.wikipedia.org/wiki/Callisto_(moon)), of Jupiter.
+    pub const CALLISTO: Self = body(
+        "Callisto",
+        1.075_938e23,
+        2_410.3 * KM,
+        16.689_018 * DAY,
+        orbit(&Self::JUPITER, 1_882_700. * KM, 0.0074, 0.192 * DEG),
+    );
+
+    /// [Titan](https://en.wikipedia.org/wiki/Titan_(moon)), of Saturn.
+    pub const TITAN: Self = body(
+        "Titan",
+        1.3452e23,
+        2_574.73 * KM,
+        15.945 * DAY,
+        orbit(&Self::SATURN, 1_221_870. * KM, 0.0288, 0.348_54 * DEG),
+    );
+
+    /// [Triton](https://en.wikipedia.org/wiki/Triton_(moon)), of Neptune,
+    /// in a retrograde orbit.
+    pub const TRITON: Self = body(
+        "Triton",
+        2.139e22,
+        1_353.4 * KM,
+        -5.876_854 * DAY,
+        orbit(&Self::NEPTUNE, 354_759. * KM, 0.000_016, 156.885 * DEG),
+    );
+
+    /// [Charon](https://en.wikipedia.org/wiki/Charon_(moon)), of Pluto.
+    pub const CHARON: Self = body(
+        "Charon",
+        1.586e21,
+        606. * KM,
+        6.387_221 * DAY,
+        orbit(&Self::PLUTO, 19_591. * KM, 0.000_2, 0.080 * DEG),
+    );
+}
+
+/// # Dwarf planets
+impl CelestialBody {
+    /// [Ceres](https://en.wikipedia.org/wiki/Ceres_(dwarf_planet)).
+    pub const CERES: Self = body(
+        "Ceres",
+        9.3835e20,
+        469.73 * KM,
+        9.074_170 * HOUR,
+        orbit(&Self::SUN, 2.7675 * AU, 0.0785, 10.59 * DEG),
+    );
+
+    /// [Pluto](https://en.wikipedia.org/wiki/Pluto).
+    pub const PLUTO: Self = body(
+        "Pluto",
+        1.303e22,
+        1_188.3 * KM,
+        -6.387_230 * DAY,
+        orbit(&Self::SUN, 39.482 * AU, 0.2488, 17.16 * DEG),
+    );
+
+    /// [Haumea](https://en.wikipedia.org/wiki/Haumea).
+    pub const HAUMEA: Self = body(
+        "Haumea",
+        4.006e21,
+        780. * KM,
+        3.9155 * HOUR,
+        orbit(&Self::SUN, 43.116 * AU, 0.196_42, 28.2137 * DEG),
+    );
+
+    /// [Makemake](https://en.wikipedia.org/wiki/Makemake).
+    pub const MAKEMAKE: Self = body(
+        "Makemake",
+        3.1e21,
+        715. * KM,
+        22.83 * HOUR,
+        orbit(&Self::SUN, 45.430 * AU, 0.161_26, 28.9835 * DEG),
+    );
+
+    /// [Eris](https://en.wikipedia.org/wiki/Eris_(dwarf_planet)).
+    pub const ERIS: Self = body(
+        "Eris",
+        1.6466e22,
+        1_163. * KM,
+        15.786 * DAY,
+        orbit(&Self::SUN, 67.864 * AU, 0.436_07, 44.040 * DEG),
+    );
+}
+
+/// All the bodies of the catalogue.
+pub const CELESTIAL_CATALOGUE: &[&CelestialBody] = &[
+    &CelestialBody::SUN,
+    &CelestialBody::MERCURY,
+    &CelestialBody::VENUS,
+    &CelestialBody::EARTH,
+    &CelestialBody::MARS,
+    &CelestialBody::JUPITER,
+    &CelestialBody::SATURN,
+    &CelestialBody::URANUS,
+    &CelestialBody::NEPTUNE,
+    &CelestialBody::MOON,
+    &CelestialBody::IO,
+    &CelestialBody::EUROPA,
+    &CelestialBody::GANYMEDE,
+    &CelestialBody::CALLISTO,
+    &CelestialBody::TITAN,
+    &CelestialBody::TRITON,
+    &CelestialBody::CHARON,
+    &CelestialBody::CERES,
+    &CelestialBody::PLUTO,
+    &CelestialBody::HAUMEA,
+    &CelestialBody::MAKEMAKE,
+    &CelestialBody::ERIS,
+];
@@ -0,0 +1,176 @@
+// fisica::celestial
+//
+//! Celestial bodies
+//!
+//! A [`CelestialBody`] is described by its [`Mass`], mean radius, rotation
+//! period and the orbit around its primary, from which the rest of its
+//! properties are derived: the surface gravity, the escape speed, the orbital
+//! period and the mean density.
+//!
+//! A catalogue of the Sun, the planets, their major moons and the dwarf
+//! planets is provided as constants, like [`CelestialBody::EARTH`].
+//!
+//! # External links
+//! - <https://en.wikipedia.org/wiki/Astronomical_object>
+//! - <https://nssdc.gsfc.nasa.gov/planetary/factsheet/>
+//
+
+use core::f64::consts::PI;
+
+use crate::constants::GRAVITATIONAL_CONSTANT as G;
+use crate::units::{Density, Gfs, Length, Mass, Speed, Time, Volume};
+use crate::{Direction, Magnitude};
+
+mod catalogue;
+
+pub use catalogue::CELESTIAL_CATALOGUE;
+
+/// A natural body in space, like a star, a planet or a moon.
+#[derive(Clone, Copy, Debug)]
+pub struct CelestialBody {
+    pub name: &'static str,
+    pub mass: Mass,
+    /// The mean radius.
+    pub radius: Length,
+    /// The sidereal rotation period, negative for retrograde rotation.
+    pub rotation_period: Time,
+    /// The orbit around its primary body, if any.
+    pub orbit: Option<CelestialOrbit>,
+}
+
+/// The mean orbit of a [`CelestialBody`] around its primary.
+#[derive(Clone, Copy, Debug)]
+pub struct CelestialOrbit {
+    /// The body being orbited.
+    pub primary: &'static CelestialBody,
+    pub semi_major_axis: Length,
+    pub eccentricity: Magnitude,
+    /// The inclination, in radians, relative to the ecliptic for the bodies
+    /// orbiting the Sun, and to the equator of the primary for the moons.
+    pub inclination: Magnitude,
+}
+
+impl CelestialBody {
+    /// Returns the standard gravitational parameter `μ = G M`, in `m³/s²`.
+    pub fn gravitational_parameter(&self) -> Magnitude {
+        G * self.mass.m()
+    }
+
+    /// Returns the [`Volume`] of a sphere of the mean radius.
+    pub fn volume(&self) -> Volume {
+        let r = self.radius.m();
+        Volume::new(4. / 3. * PI * r * r * r)
+    }
+
+    /// Returns the mean [`Density`] (`ρ = M / V`).
+    pub fn mean_density(&self) -> Density {
+        Density::new(self.mass.m() / self.volume().m())
+    }
+
+    /// Returns the gravitational field strength at the mean surface, without
+    /// the effect of rotation (`g = G M / R²`).
+    ///
+    /// It points along `+y`, like the other [`Gfs`] constructors.
+    pub fn surface_gfs(&self) -> Gfs {
+        let r = self.radius.m();
+        Gfs::new(Direction::new(
+            0.,
+            self.gravitational_parameter() / (r * r),
+            0.,
+        ))
+    }
+
+    /// Returns the effective gravitational field strength felt at the given
+    /// `altitude` over the mean surface and `latitude` (in radians),
+    /// reduced by the centrifugal effect of the rotation.
+    ///
+    /// It points along `+y`, like the other [`Gfs`] constructors.
+    pub fn calc_gfs_at(&self, altitude: Length, latitude: Magnitude) -> Gfs {
+        let r = self.radius.m() + altitude.m();
+        let w = 2. * PI / self.rotation_period.m();
+        let (sin, cos) = latitude.sin_cos();
+        let g = self.gravitational_parameter() / (r * r);
+        let centrifugal = w * w * r * cos;
+        // radial and meridional components of the effective gravity
+        let radial = g - centrifugal * cos;
+        let meridional = centrifugal * sin;
+        Gfs::new(Direction::new(0., radial.hypot(meridional), 0.))
+    }
+
+    /// Returns the escape [`Speed`] from the mean surface (`v = √(2 G M / R)`).
+    pub fn escape_speed(&self) -> Speed {
+        self.calc_escape_speed_at(Length::new(0.))
+    }
+
+    /// Returns the escape [`Speed`] at the given `altitude` over the mean surface.
+    pub fn calc_escape_speed_at(&self, altitude: Length) -> Speed {
+        Speed::new((2. * self.gravitational_parameter() / (self.radius.m() + altitude.m())).sqrt())
+    }
+
+    /// Returns the [`Speed`] of a circular orbit at the given `altitude`
+    /// over the mean surface (`v = √(G M / r)`).
+    pub fn calc_circular_orbit_speed(&self, altitude: Length) -> Speed {
+        Speed::new((self.gravitational_parameter() / (self.radius.m() + altitude.m())).sqrt())
+    }
+
+    /// Returns the sidereal orbital period around its primary, if any,
+    /// by Kepler's third law (`T = 2π √(a³ / G (M + m))`).
+    pub fn orbital_period(&self) -> Option<Time> {
+        self.orbit.map(|o| {
+            let a = o.semi_major_axis.m();
+            let mu = G * (o.primary.mass.m() + self.mass.m());
+            Time::new(2. * PI * (a * a * a / mu).sqrt())
+        })
+    }
+
+    /// Returns the body of the [`CELESTIAL_CATALOGUE`] with the given `name`,
+    /// ignoring the case.
+    pub fn find(name: &str) -> Option<&'static CelestialBody> {
+        CELESTIAL_CATALOGUE
+            .iter()
+            .copied()
+            .find(|b| b.name.eq_ignore_ascii_case(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_eq::assert_float_eq;
+
+    /// Checks the derived properties match the published values.
+    #[test]
+    fn celestial_bodies() {
+        let earth = CelestialBody::EARTH;
+        assert_float_eq!(9.82, earth.surface_gfs().m(), abs <= 0.01);
+        assert_float_eq!(5_514., earth.mean_density().m(), abs <= 5.);
+        assert_float_eq!(11_186., earth.escape_speed().m(), abs <= 5.);
+        let year = earth.orbital_period().unwrap();
+        assert_float_eq!(365.256, year.m() / 86_400., abs <= 0.01);
+
+        // gravity is weaker at the equator than at the poles
+        let equator = earth.calc_gfs_at(Length::new(0.), 0.);
+        let pole = earth.calc_gfs_at(Length::new(0.), PI / 2.);
+        assert_float_eq!(0.0339, pole.m() - equator.m(), abs <= 0.0005);
+        assert!(earth.calc_gfs_at(Length::new(400e3), PI / 2.).m() < 8.7);
+
+        let moon = CelestialBody::MOON;
+        assert_float_eq!(1.62, moon.surface_gfs().m(), abs <= 0.01);
+        assert_float_eq!(2_380., moon.escape_speed().m(), abs <= 5.);
+        // the real period is perturbed by the Sun
+        assert_float_eq!(
+            27.32,
+            moon.orbital_period().unwrap().m() / 86_400.,
+            abs <= 0.05
+        );
+
+        assert_float_eq!(3.73, CelestialBody::MARS.surface_gfs().m(), abs <= 0.01);
+        assert_float_eq!(
+            1.41,
+            CelestialBody::SUN.mean_density().m() / 1e3,
+            abs <= 0.01
+        );
+        assert!(CelestialBody::SUN.orbital_period().is_none());
+        assert_eq!["Titan", CelestialBody::find("titan").unwrap().name];
+    }
+}
@@ -0,0 +1,359 @@
+// fisica::collision::grid
+//
+// Uniform grid broad phase.
+
+use alloc::{vec, vec::Vec};
+
+use super::{Aabb, Ray, RayCast, RayHit};
+use crate::units::Length;
+use crate::{Magnitude, Position};
+
+/// A uniform grid of cubic cells, stored in a fixed number of hashed buckets.
+///
+/// Each object is inserted with an `id` and its bounding [`Aabb`] into every
+/// cell it overlaps. It works best when the objects have a similar size,
+/// about the size of a cell, since big objects span many cells. The objects
+/// spanning more cells than there are buckets are kept in a separate list,
+/// checked by every query.
+///
+/// The grid is unbounded, and is meant to be cleared and refilled each step.
+///
+/// See: [*Optimized Spatial Hashing for Collision Detection of Deformable
+/// Objects*][0] (Teschner et al. 2003).
+///
+/// [0]:https://matthias-research.github.io/pages/publications/tetraederCollision.pdf
+#[derive(Clone, Debug)]
+pub struct SpatialHash {
+    cell_size: Magnitude,
+    buckets: Vec<Vec<usize>>,
+    /// The inserted objects, indexed from the buckets.
+    items: Vec<(usize, Aabb)>,
+    /// The indices of the objects too large to be stored in the buckets.
+    large: Vec<usize>,
+    /// The number of buckets that aren't empty.
+    occupied: usize,
+    /// The bounds of all the objects stored in the buckets.
+    bounds: Option<Aabb>,
+}
+
+impl SpatialHash {
+    /// New empty `SpatialHash` with cells of the given size, and the given
+    /// number of buckets.
+    ///
+    /// A good number of buckets is about twice the number of objects.
+    ///
+    /// # Panics
+    /// Panics if `cell_size` is not positive or `buckets` is 0.
+    pub fn new(cell_size: Length, buckets: usize) -> Self {
+        assert!(cell_size.m() > 0. && buckets > 0);
+        Self {
+            cell_size: cell_size.m(),
+            buckets: vec![Vec::new(); buckets],
+            items: Vec::new(),
+            large: Vec::new(),
+            occupied: 0,
+            bounds: None,
+        }
+    }
+
+    /// Returns the size of the cells.
+    pub fn cell_size(&self) -> Length {
+        Length::new(self.cell_size)
+    }
+
+    /// Returns the number of objects.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no objects.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Removes all the objects, keeping the allocated memory.
+    pub fn clear(&mut self) {
+        self.buckets.iter_mut().for_each(Vec::clear);
+        self.items.clear();
+        self.large.clear();
+        self.occupied = 0;
+        self.bounds = None;
+    }
+
+    /// Inserts an object with the given bounding box.
+    ///
+    /// Returns `false`, without inserting it, if the box is not finite.
+    pub fn insert(&mut self, id: usize, aabb: Aabb) -> bool {
+        if !(aabb.min.vector().is_finite() && aabb.max.vector().is_finite()) {
+            return false;
+        }
+        let index = self.items.len();
+        self.items.push((id, aabb));
+        let (lo, hi) = (self.cell(aabb.min), self.cell(aabb.max));
+        if count_cells(lo, hi).map_or(true, |n| n > self.buckets.len()) {
+            self.large.push(index);
+            return true;
+        }
+        self.bounds = Some(self.bounds.map_or(aabb, |b| b.union(&aabb)));
+        for x in lo[0]..=hi[0] {
+            for y in lo[1]..=hi[1] {
+                for z in lo[2]..=hi[2] {
+                    let bucket = self.bucket([x, y, z]);
+                    if self.buckets[bucket].is_empty() {
+                        self.occupied += 1;
+                    }
+                    self.buckets[bucket].push(index);
+                }
+            }
+        }
+        true
+    }
+
+    /// Inserts a point object at the given [`Position`].
+    ///
+    /// Returns `false`, without inserting it, if the position is not finite.
+    pub fn insert_point(&mut self, id: usize, p: Position) -> bool {
+        self.insert(id, Aabb::new(p, p))
+    }
+
+    /// Returns the ids of the objects whose bounding box overlaps `region`.
+    pub fn query_aabb(&self, region: &Aabb) -> Vec<usize> {
+        self.query_cells(region, |aabb| aabb.intersects(region))
+    }
+
+    /// Returns the ids of the objects whose bounding box is within the
+    /// `radius` of the `center`.
+    pub fn query_radius(&self, center: Position, radius: Length) -> Vec<usize> {
+        let r2 = radius.m() * radius.m();
+        let region = Aabb::new(center, center).expanded(radius);
+        self.query_cells(&region, |aabb| {
+            (aabb.calc_closest_point(center) - center).magnitude_squared() <= r2
+        })
+    }
+
+    /// Returns the ids of the objects whose bounding box is hit by the `ray`
+    /// within `max_distance`, with the hits sorted by distance.
+    ///
+    /// The cells are walked along the ray, using a 3D [*DDA*][0].
+    ///
+    /// [0]:http://www.cse.yorku.ca/~amana/research/grid.pdf
+    pub fn query_ray(&self, ray: &Ray, max_distance: Length) -> Vec<(usize, RayHit)> {
+        let mut hits: Vec<(usize, usize, RayHit)> = self
+            .large
+            .iter()
+            .filter_map(|&index| {
+                let (id, aabb) = self.items[index];
+                aabb.cast_ray(ray, max_distance).map(|hit| (index, id, hit))
+            })
+            .collect();
+        self.walk_ray(ray, max_distance, &mut hits);
+
+        hits.sort_by_key(|h| h.0);
+        hits.dedup_by_key(|h| h.0);
+        hits.sort_by(|a, b| a.2.distance.m().total_cmp(&b.2.distance.m()));
+        hits.into_iter().map(|(_, id, hit)| (id, hit)).collect()
+    }
+
+    /// Collects the hits of the `ray` with the objects in the cells it crosses,
+    /// along with their indices.
+    fn walk_ray(&self, ray: &Ray, max_distance: Length, hits: &mut Vec<(usize, usize, RayHit)>) {
+        let Some(bounds) = self.bounds else {
+            return;
+        };
+        // walk only the part of the ray within the bounds of the objects
+        let Some(enter) = bounds.cast_ray(ray, max_distance) else {
+            return;
+        };
+        let far = max_distance
+            .m()
+            .min(enter.distance.m() + (bounds.max - bounds.min).magnitude() + self.cell_size);
+
+        let start = ray.at(enter.distance);
+        let mut cell = self.cell(start);
+        let (o, d) = (start.vector(), ray.direction.vector());
+        let mut step = [0_i64; 3];
+        let mut t_next = [Magnitude::INFINITY; 3];
+        let mut t_delta = [Magnitude::INFINITY; 3];
+        for i in 0..3 {
+            if d[i] > 0. {
+                step[i] = 1;
+                t_next[i] = ((cell[i] + 1) as Magnitude * self.cell_size - o[i]) / d[i];
+                t_delta[i] = self.cell_size / d[i];
+            } else if d[i] < 0. {
+                step[i] = -1;
+                t_next[i] = (cell[i] as Magnitude * self.cell_size - o[i]) / d[i];
+                t_delta[i] = -self.cell_size / d[i];
+            }
+        }
+
+        let mut t = enter.distance.m();
+        while t <= far {
+            for &index in &self.buckets[self.bucket(cell)] {
+                let (id, aabb) = self.items[index];
+                if let Some(hit) = aabb.cast_ray(ray, max_distance) {
+                    hits.push((index, id, hit));
+                }
+            }
+            let i = if t_next[0] <= t_next[1] && t_next[0] <= t_next[2] {
+                0
+            } else if t_next[1] <= t_next[2] {
+                1
+            } else {
+                2
+            };
+            t = enter.distance.m() + t_next[i];
+            t_next[i] += t_delta[i];
+            cell[i] += step[i];
+        }
+    }
+
+    /// Returns all the pairs of ids of objects whose bounding boxes overlap.
+    ///
+    /// Each pair is returned once, with the id of the first inserted object
+    /// first.
+    pub fn pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for bucket in &self.buckets {
+            for (k, &i) in bucket.iter().enumerate() {
+                for &j in &bucket[k + 1..] {
+                    let (i, j) = (i.min(j), i.max(j));
+                    if i != j && self.items[i].1.intersects(&self.items[j].1) {
+                        pairs.push((i, j));
+                    }
+                }
+            }
+        }
+        for &i in &self.large {
+            for j in 0..self.items.len() {
+                if i != j && self.items[i].1.intersects(&self.items[j].1) {
+                    pairs.push((i.min(j), i.max(j)));
+                }
+            }
+        }
+        pairs.sort_unstable();
+        pairs.dedup();
+        pairs
+            .into_iter()
+            .map(|(i, j)| (self.items[i].0, self.items[j].0))
+            .collect()
+    }
+
+    /// Returns the ids of the objects in the cells overlapping `region`
+    /// that pass the `filter`.
+    fn query_cells(&self, region: &Aabb, filter: impl Fn(&Aabb) -> bool) -> Vec<usize> {
+        let (lo, hi) = (self.cell(region.min), self.cell(region.max));
+        let mut found = Vec::new();
+        // scanning all the objects is cheaper than visiting more cells than
+        // there are occupied
+        if count_cells(lo, hi).map_or(true, |n| n > self.occupied) {
+            found.extend((0..self.items.len()).filter(|i| filter(&self.items[*i].1)));
+        } else {
+            found.extend(self.large.iter().filter(|i| filter(&self.items[**i].1)));
+            for x in lo[0]..=hi[0] {
+                for y in lo[1]..=hi[1] {
+                    for z in lo[2]..=hi[2] {
+                        found.extend(
+                            self.buckets[self.bucket([x, y, z])]
+                                .iter()
+                                .filter(|i| filter(&self.items[**i].1)),
+                        );
+                    }
+                }
+            }
+            found.sort_unstable();
+            found.dedup();
+        }
+        found.into_iter().map(|i| self.items[i].0).collect()
+    }
+
+    /// Returns the integer coordinates of the cell containing `p`.
+    fn cell(&self, p: Position) -> [i64; 3] {
+        p.to_array().map(|c| (c / self.cell_size).floor() as i64)
+    }
+
+    /// Returns the bucket of the given cell.
+    fn bucket(&self, cell: [i64; 3]) -> usize {
+        let h = (cell[0].wrapping_mul(73_856_093))
+            ^ (cell[1].wrapping_mul(19_349_663))
+            ^ (cell[2].wrapping_mul(83_492_791));
+        (h as u64 % self.buckets.len() as u64) as usize
+    }
+}
+
+/// Returns the number of cells from `lo` to `hi`, or `None` if it overflows.
+fn count_cells(lo: [i64; 3], hi: [i64; 3]) -> Option<usize> {
+    (0..3).try_fold(1_usize, |n, i| {
+        let side = hi[i].checked_sub(lo[i])?.checked_add(1)?;
+        n.checked_mul(usize::try_from(side).ok()?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Direction;
+
+    /// Checks the queries over a line of particles.
+    #[test]
+    fn spatial_hash() {
+        let mut grid = SpatialHash::new(Length::new(1.), 64);
+        for i in 0..20 {
+            grid.insert_point(i, Position::new(i as Magnitude, 0., 0.));
+        }
+        assert_eq![20, grid.len()];
+
+        let mut near = grid.query_radius(Position::new(10., 0.5, 0.), Length::new(2.));
+        near.sort_unstable();
+        assert_eq![vec![9, 10, 11], near];
+
+        let region = Aabb::new(Position::new(-5., -1., -1.), Position::new(2.5, 1., 1.));
+        let mut inside = grid.query_aabb(&region);
+        inside.sort_unstable();
+        assert_eq![vec![0, 1, 2], inside];
+
+        let ray = Ray::new(Position::new(-3., 0., 0.), Direction::new(1., 0., 0.));
+        let hits = grid.query_ray(&ray, Length::new(5.5));
+        let ids: Vec<usize> = hits.iter().map(|h| h.0).collect();
+        assert_eq![vec![0, 1, 2], ids];
+
+        grid.clear();
+        grid.insert(7, Aabb::new(Position::ZERO, Position::ONE));
+        grid.insert(8, Aabb::new(Position::ONE * 0.5, Position::ONE * 1.5));
+        grid.insert(9, Aabb::new(Position::ONE * 3., Position::ONE * 4.));
+        assert_eq![vec![(7, 8)], grid.pairs()];
+    }
+
+    /// Checks huge and non-finite boxes don't stall the grid.
+    #[test]
+    fn spatial_hash_large() {
+        let mut grid = SpatialHash::new(Length::new(0.1), 16);
+        let ground = Aabb::new(
+            Position::new(-1e12, -1., -1e12),
+            Position::new(1e12, 0., 1e12),
+        );
+        assert!(grid.insert(0, ground));
+        assert!(grid.insert_point(1, Position::new(0., 0., 0.)));
+        assert!(grid.insert_point(2, Position::new(5., 5., 5.)));
+        assert!(!grid.insert_point(3, Position::new(Magnitude::NAN, 0., 0.)));
+        let infinite = Aabb::new(Position::ONE * Magnitude::NEG_INFINITY, Position::ZERO);
+        assert!(!grid.insert(4, infinite));
+        assert_eq![3, grid.len()];
+
+        assert_eq![vec![(0, 1)], grid.pairs()];
+        let mut near = grid.query_radius(Position::new(0., 0.5, 0.), Length::new(1.));
+        near.sort_unstable();
+        assert_eq![vec![0, 1], near];
+        // a region spanning far more cells than fit in an integer
+        let mut all = grid.query_radius(Position::ZERO, Length::new(1e300));
+        all.sort_unstable();
+        assert_eq![vec![0, 1, 2], all];
+
+        let ray = Ray::new(Position::new(5., 10., 5.), Direction::new(0., -1., 0.));
+        let ids: Vec<usize> = grid
+            .query_ray(&ray, Length::new(20.))
+            .iter()
+            .map(|h| h.0)
+            .collect();
+        assert_eq![vec![2, 0], ids];
+    }
+}
@@ -0,0 +1,177 @@
+// fisica::collision
+//
+//! Collision detection
+//!
+//! ## Narrow phase
+//!
+//! The collision primitives ([`Ball`], [`Plane`], [`Aabb`], [`Obb`] and
+//! [`Capsule`]) are positioned in space, and can be tested against each other
+//! with their `contact_*` methods, which return a [`Contact`] if they overlap.
+//!
+//! A `Contact` holds the unit normal [`Direction`] pointing from the first
+//! shape towards the second, and one or more [`ContactPoint`]s with their
+//! penetration depth as a [`Length`]. Moving the second shape along the normal
+//! by the deepest penetration separates both shapes.
+//!
+//! ## Broad phase
+//!
+//! Testing every pair of objects is too slow for many objects. The broad
+//! phase finds the candidate pairs whose bounding [`Aabb`]s overlap:
+//! - [`SpatialHash`]: a uniform grid, best for objects of similar size.
+//! - [`AabbTree`]: a dynamic tree of fat boxes, best for objects of mixed sizes.
+//!
+//! Both also support region, radius and ray queries, which are useful on
+//! their own, like finding all the particles within some distance.
+//!
+//! ## Ray casting
+//!
+//! All the primitives implement [`RayCast`], which returns the first
+//! [`RayHit`] of a [`Ray`] with the shape, if any.
+//!
+//! # External links
+//! - <https://en.wikipedia.org/wiki/Collision_detection>
+//! - <https://en.wikipedia.org/wiki/Hyperplane_separation_theorem>
+//! - <https://en.wikipedia.org/wiki/Bounding_volume_hierarchy>
+//
+
+use alloc::vec::Vec;
+
+use crate::units::Length;
+use crate::{Direction, Magnitude, Position};
+
+mod grid;
+mod narrow;
+mod ray;
+mod shapes;
+mod tree;
+
+pub use {
+    grid::SpatialHash,
+    ray::{Ray, RayCast, RayHit},
+    shapes::{Aabb, Ball, Capsule, Obb, Plane},
+    tree::AabbTree,
+};
+
+/// A point of contact between two shapes.
+#[derive(Clone, Copy, Debug)]
+pub struct ContactPoint {
+    /// The [`Position`] of the contact, midway between both surfaces.
+    pub position: Position,
+    /// The penetration depth along the contact normal.
+    pub depth: Length,
+}
+
+impl ContactPoint {
+    /// New ContactPoint.
+    #[inline]
+    pub const fn new(position: Position, depth: Length) -> Self {
+        Self { position, depth }
+    }
+}
+
+/// The contact data between two overlapping shapes.
+#[derive(Clone, Debug)]
+pub struct Contact {
+    /// The unit normal, pointing from the first shape towards the second.
+    pub normal: Direction,
+    /// The points of contact.
+    pub points: Vec<ContactPoint>,
+}
+
+impl Contact {
+    /// New Contact.
+    #[inline]
+    pub const fn new(normal: Direction, points: Vec<ContactPoint>) -> Self {
+        Self { normal, points }
+    }
+
+    /// New `Contact` with a single point.
+    pub fn single(normal: Direction, position: Position, depth: Length) -> Self {
+        Self::new(normal, alloc::vec![ContactPoint::new(position, depth)])
+    }
+
+    /// Returns the deepest penetration among all the contact points.
+    pub fn depth(&self) -> Length {
+        Length::new(
+            self.points
+                .iter()
+                .map(|p| p.depth.m())
+                .fold(0., Magnitude::max),
+        )
+    }
+
+    /// Returns the same contact seen from the second shape,
+    /// with the normal reversed.
+    pub fn flipped(mut self) -> Self {
+        self.normal = -self.normal;
+        self
+    }
+}
+
+/// Returns the closest points between the segments `p1 q1` and `p2 q2`.
+///
+/// <https://realtimecollisiondetection.net/> (5.1.9)
+pub(crate) fn closest_points_segments(
+    p1: Position,
+    q1: Position,
+    p2: Position,
+    q2: Position,
+) -> (Position, Position) {
+    let (d1, d2, r) = (q1 - p1, q2 - p2, p1 - p2);
+    let (a, e, f) = (d1.dot(d1), d2.dot(d2), d2.dot(r));
+    let eps = Magnitude::EPSILON;
+
+    let (s, t);
+    if a <= eps && e <= eps {
+        return (p1, p2);
+    } else if a <= eps {
+        s = 0.;
+        t = (f / e).clamp(0., 1.);
+    } else {
+        let c = d1.dot(r);
+        if e <= eps {
+            t = 0.;
+            s = (-c / a).clamp(0., 1.);
+        } else {
+            let b = d1.dot(d2);
+            let denom = a * e - b * b;
+            let s0 = if denom > eps {
+                ((b * f - c * e) / denom).clamp(0., 1.)
+            } else {
+                0.
+            };
+            let t0 = (b * s0 + f) / e;
+            if t0 < 0. {
+                t = 0.;
+                s = (-c / a).clamp(0., 1.);
+            } else if t0 > 1. {
+                t = 1.;
+                s = ((b - c) / a).clamp(0., 1.);
+            } else {
+                t = t0;
+                s = s0;
+            }
+        }
+    }
+    (p1 + d1 * s, p2 + d2 * t)
+}
+
+/// Returns the closest point to `p` on the segment `a b`.
+pub(crate) fn closest_point_segment(p: Position, a: Position, b: Position) -> Position {
+    let ab = b - a;
+    let len2 = ab.magnitude_squared();
+    if len2 <= Magnitude::EPSILON {
+        return a;
+    }
+    a + ab * ((p - a).dot(ab) / len2).clamp(0., 1.)
+}
+
+/// Returns the unit vector of `d`, or `fallback` if it's too small to normalize.
+pub(crate) fn normalize_or(d: Direction, fallback: Direction) -> Direction {
+    let len = d.magnitude();
+    if len <= Magnitude::EPSILON {
+        fallback
+    } else {
+        d / len
+    }
+}
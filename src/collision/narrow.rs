@@ -0,0 +1,504 @@
+// fisica::collision::narrow
+//
+// Narrow phase contact generation.
+
+use alloc::vec::Vec;
+
+use super::{
+    closest_point_segment, closest_points_segments, normalize_or, Aabb, Ball, Capsule, Contact,
+    ContactPoint, Obb, Plane,
+};
+use crate::math::V3;
+use crate::units::Length;
+use crate::{Direction, Magnitude, Position};
+
+/// The direction used as the contact normal when the shapes are concentric.
+const FALLBACK_NORMAL: Direction = Direction::from_vector(V3::Y);
+
+/// Returns the contact between two spheres.
+fn contact_spheres(ca: Position, ra: Magnitude, cb: Position, rb: Magnitude) -> Option<Contact> {
+    let d = cb - ca;
+    let dist2 = d.magnitude_squared();
+    let r = ra + rb;
+    if dist2 > r * r {
+        return None;
+    }
+    let dist = dist2.sqrt();
+    let normal = normalize_or(d, FALLBACK_NORMAL);
+    let depth = r - dist;
+    Some(Contact::single(
+        normal,
+        ca + normal * (ra - depth / 2.),
+        Length::new(depth),
+    ))
+}
+
+/// # Contacts
+impl Ball {
+    /// Returns the contact with another [`Ball`], if they overlap.
+    pub fn contact_ball(&self, other: &Ball) -> Option<Contact> {
+        contact_spheres(self.center, self.radius.m(), other.center, other.radius.m())
+    }
+
+    /// Returns the contact with a [`Plane`], if they overlap.
+    pub fn contact_plane(&self, plane: &Plane) -> Option<Contact> {
+        let (r, s) = (self.radius.m(), plane.calc_distance(self.center).m());
+        if s > r {
+            return None;
+        }
+        let n = plane.normal;
+        Some(Contact::single(
+            -n,
+            self.center - n * ((r + s) / 2.),
+            Length::new(r - s),
+        ))
+    }
+
+    /// Returns the contact with an [`Aabb`], if they overlap.
+    pub fn contact_aabb(&self, aabb: &Aabb) -> Option<Contact> {
+        self.contact_obb(&Obb::from(*aabb))
+    }
+
+    /// Returns the contact with an [`Obb`], if they overlap.
+    pub fn contact_obb(&self, obb: &Obb) -> Option<Contact> {
+        let r = self.radius.m();
+        let h = obb.half_extents.vector();
+        let p = obb.to_local(self.center).vector();
+        let q = p.clamp(-h, h);
+
+        let (normal, depth, surface) = if p != q {
+            // the center is outside the box
+            let d = q - p;
+            let dist2 = d.length_squared();
+            if dist2 > r * r {
+                return None;
+            }
+            let dist = dist2.sqrt();
+            (d / dist, r - dist, q)
+        } else {
+            // the center is inside the box: push it out through the nearest face
+            let gaps = h - p.abs();
+            let i = if gaps.x <= gaps.y && gaps.x <= gaps.z {
+                0
+            } else if gaps.y <= gaps.z {
+                1
+            } else {
+                2
+            };
+            let mut axis = V3::ZERO;
+            axis[i] = if p[i] < 0. { -1. } else { 1. };
+            let mut surface = p;
+            surface[i] = axis[i] * h[i];
+            (-axis, r + gaps[i], surface)
+        };
+
+        let normal = Direction::from_vector(normal).rotate(obb.orientation);
+        let surface = obb.to_world(Position::from_vector(surface));
+        Some(Contact::single(
+            normal,
+            (self.center + normal * r + surface) / 2.,
+            Length::new(depth),
+        ))
+    }
+
+    /// Returns the contact with a [`Capsule`], if they overlap.
+    pub fn contact_capsule(&self, capsule: &Capsule) -> Option<Contact> {
+        let q = closest_point_segment(self.center, capsule.a, capsule.b);
+        contact_spheres(self.center, self.radius.m(), q, capsule.radius.m())
+    }
+}
+
+/// # Contacts
+impl Aabb {
+    /// Returns the contact with another [`Aabb`], if they overlap.
+    ///
+    /// The contact point is the center of the overlapping region.
+    pub fn contact_aabb(&self, other: &Aabb) -> Option<Contact> {
+        let lo = self.min.vector().max(other.min.vector());
+        let hi = self.max.vector().min(other.max.vector());
+        let overlap = hi - lo;
+        if overlap.min_element() < 0. {
+            return None;
+        }
+        let i = if overlap.x <= overlap.y && overlap.x <= overlap.z {
+            0
+        } else if overlap.y <= overlap.z {
+            1
+        } else {
+            2
+        };
+        let mut normal = V3::ZERO;
+        normal[i] = if other.center().vector()[i] < self.center().vector()[i] {
+            -1.
+        } else {
+            1.
+        };
+        Some(Contact::single(
+            Direction::from_vector(normal),
+            Position::from_vector((lo + hi) / 2.),
+            Length::new(overlap[i]),
+        ))
+    }
+
+    /// Returns the contact with a [`Ball`], if they overlap.
+    pub fn contact_ball(&self, ball: &Ball) -> Option<Contact> {
+        ball.contact_aabb(self).map(Contact::flipped)
+    }
+}
+
+/// The axis of least penetration found by the separating axis test.
+#[derive(Clone, Copy)]
+enum SatAxis {
+    FaceA(usize),
+    FaceB(usize),
+    Edges(usize, usize),
+}
+
+/// # Contacts
+impl Obb {
+    /// Returns the contact with another [`Obb`], if they overlap.
+    ///
+    /// It uses the [*separating axis theorem*][0], testing the 3 face normals of
+    /// each box and the 9 cross products of their edges. Face contacts are
+    /// clipped to generate up to 8 contact points.
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Hyperplane_separation_theorem
+    pub fn contact_obb(&self, other: &Obb) -> Option<Contact> {
+        // relative tolerance favouring face axes over edge axes, for coherence
+        const BIAS: Magnitude = 0.95;
+
+        let a = self.axes().map(|d| d.vector());
+        let b = other.axes().map(|d| d.vector());
+        let ha = self.half_extents.vector();
+        let hb = other.half_extents.vector();
+        let t = (other.center - self.center).vector();
+
+        let overlap = |l: V3| {
+            let ra = ha.x * l.dot(a[0]).abs() + ha.y * l.dot(a[1]).abs() + ha.z * l.dot(a[2]).abs();
+            let rb = hb.x * l.dot(b[0]).abs() + hb.y * l.dot(b[1]).abs() + hb.z * l.dot(b[2]).abs();
+            ra + rb - l.dot(t).abs()
+        };
+
+        let mut best = (Magnitude::INFINITY, V3::ZERO, SatAxis::FaceA(0));
+        for (i, axis) in a.iter().enumerate() {
+            let o = overlap(*axis);
+            if o < 0. {
+                return None;
+            }
+            if o < best.0 {
+                best = (o, *axis, SatAxis::FaceA(i));
+            }
+        }
+        for (j, axis) in b.iter().enumerate() {
+            let o = overlap(*axis);
+            if o < 0. {
+                return None;
+            }
+            if o < best.0 * BIAS {
+                best = (o, *axis, SatAxis::FaceB(j));
+            }
+        }
+        for (i, ai) in a.iter().enumerate() {
+            for (j, bj) in b.iter().enumerate() {
+                let l = ai.cross(*bj);
+                let len = l.length();
+                if len < 1e-9 {
+                    continue; // parallel edges, already covered by the faces
+                }
+                let l = l / len;
+                let o = overlap(l);
+                if o < 0. {
+                    return None;
+                }
+                if o < best.0 * BIAS {
+                    best = (o, l, SatAxis::Edges(i, j));
+                }
+            }
+        }
+
+        let (depth, mut normal, kind) = best;
+        if normal.dot(t) < 0. {
+            normal = -normal;
+        }
+        let points = match kind {
+            SatAxis::FaceA(i) => clip_faces(self, i, other, normal),
+            SatAxis::FaceB(j) => clip_faces(other, j, self, -normal),
+            SatAxis::Edges(i, j) => {
+                let edge_a = a
+                    .iter()
+                    .enumerate()
+                    .fold(self.center.vector(), |c, (k, ak)| {
+                        if k == i {
+                            c
+                        } else {
+                            c + *ak * ha[k] * ak.dot(normal).signum()
+                        }
+                    });
+                let edge_b = b
+                    .iter()
+                    .enumerate()
+                    .fold(other.center.vector(), |c, (k, bk)| {
+                        if k == j {
+                            c
+                        } else {
+                            c - *bk * hb[k] * bk.dot(normal).signum()
+                        }
+                    });
+                let (da, db) = (a[i] * ha[i], b[j] * hb[j]);
+                let (p, q) = closest_points_segments(
+                    Position::from_vector(edge_a - da),
+                    Position::from_vector(edge_a + da),
+                    Position::from_vector(edge_b - db),
+                    Position::from_vector(edge_b + db),
+                );
+                alloc::vec![ContactPoint::new((p + q) / 2., Length::new(depth))]
+            }
+        };
+        let normal = Direction::from_vector(normal);
+        if points.is_empty() {
+            Some(Contact::single(
+                normal,
+                (self.center + other.center) / 2.,
+                Length::new(depth),
+            ))
+        } else {
+            Some(Contact::new(normal, points))
+        }
+    }
+
+    /// Returns the contact with a [`Plane`], if they overlap.
+    ///
+    /// Every corner of the box on the solid side of the plane is a contact point.
+    pub fn contact_plane(&self, plane: &Plane) -> Option<Contact> {
+        let n = plane.normal;
+        let points: Vec<_> = self
+            .corners()
+            .iter()
+            .filter_map(|c| {
+                let d = plane.calc_distance(*c).m();
+                (d <= 0.).then(|| ContactPoint::new(*c - n * (d / 2.), Length::new(-d)))
+            })
+            .collect();
+        (!points.is_empty()).then(|| Contact::new(-n, points))
+    }
+
+    /// Returns the contact with an [`Aabb`], if they overlap.
+    pub fn contact_aabb(&self, aabb: &Aabb) -> Option<Contact> {
+        self.contact_obb(&Obb::from(*aabb))
+    }
+
+    /// Returns the contact with a [`Ball`], if they overlap.
+    pub fn contact_ball(&self, ball: &Ball) -> Option<Contact> {
+        ball.contact_obb(self).map(Contact::flipped)
+    }
+}
+
+/// Clips the face of the `incident` box most opposed to `normal` against the
+/// face `axis` of the `reference` box, whose outward normal is `normal`.
+fn clip_faces(reference: &Obb, axis: usize, incident: &Obb, normal: V3) -> Vec<ContactPoint> {
+    let r_axes = reference.axes().map(|d| d.vector());
+    let r_half = reference.half_extents.vector();
+    let r_center = reference.center.vector();
+    let i_axes = incident.axes().map(|d| d.vector());
+    let i_half = incident.half_extents.vector();
+
+    // the incident face
+    let k = (0..3)
+        .max_by(|x, y| {
+            i_axes[*x]
+                .dot(normal)
+                .abs()
+                .total_cmp(&i_axes[*y].dot(normal).abs())
+        })
+        .unwrap_or(0);
+    let sign = if i_axes[k].dot(normal) > 0. { -1. } else { 1. };
+    let face_center = incident.center.vector() + i_axes[k] * i_half[k] * sign;
+    let (e1, e2) = ((k + 1) % 3, (k + 2) % 3);
+    let (u, v) = (i_axes[e1] * i_half[e1], i_axes[e2] * i_half[e2]);
+    let mut polygon: Vec<V3> = alloc::vec![
+        face_center + u + v,
+        face_center - u + v,
+        face_center - u - v,
+        face_center + u - v,
+    ];
+
+    // clip against the side planes of the reference face
+    for side in [(axis + 1) % 3, (axis + 2) % 3] {
+        for s in [1., -1.] {
+            let n = r_axes[side] * s;
+            let offset = n.dot(r_center) + r_half[side];
+            polygon = clip_polygon(&polygon, n, offset);
+        }
+    }
+
+    let face_offset = normal.dot(r_center) + r_half[axis];
+    polygon
+        .into_iter()
+        .filter_map(|p| {
+            let depth = face_offset - normal.dot(p);
+            (depth >= 0.).then(|| {
+                ContactPoint::new(
+                    Position::from_vector(p + normal * (depth / 2.)),
+                    Length::new(depth),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Clips a convex polygon keeping the part where `n · p <= offset`
+/// (Sutherland–Hodgman).
+fn clip_polygon(polygon: &[V3], n: V3, offset: Magnitude) -> Vec<V3> {
+    let mut out = Vec::with_capacity(polygon.len() + 1);
+    for (i, a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let (da, db) = (n.dot(*a) - offset, n.dot(b) - offset);
+        if da <= 0. {
+            out.push(*a);
+        }
+        if (da < 0. && db > 0.) || (da > 0. && db < 0.) {
+            out.push(*a + (b - *a) * (da / (da - db)));
+        }
+    }
+    out
+}
+
+/// # Contacts
+impl Capsule {
+    /// Returns the contact with another [`Capsule`], if they overlap.
+    pub fn contact_capsule(&self, other: &Capsule) -> Option<Contact> {
+        let (p, q) = closest_points_segments(self.a, self.b, other.a, other.b);
+        contact_spheres(p, self.radius.m(), q, other.radius.m())
+    }
+
+    /// Returns the contact with a [`Ball`], if they overlap.
+    pub fn contact_ball(&self, ball: &Ball) -> Option<Contact> {
+        ball.contact_capsule(self).map(Contact::flipped)
+    }
+
+    /// Returns the contact with a [`Plane`], if they overlap.
+    ///
+    /// Each end of the capsule may generate a contact point.
+    pub fn contact_plane(&self, plane: &Plane) -> Option<Contact> {
+        let (n, r) = (plane.normal, self.radius.m());
+        let points: Vec<_> = [self.a, self.b]
+            .iter()
+            .filter_map(|e| {
+                let d = plane.calc_distance(*e).m() - r;
+                (d <= 0.).then(|| ContactPoint::new(*e - n * (r + d / 2.), Length::new(-d)))
+            })
+            .collect();
+        (!points.is_empty()).then(|| Contact::new(-n, points))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Orientation;
+    use float_eq::assert_float_eq;
+
+    fn ball(x: Magnitude, y: Magnitude, z: Magnitude, r: Magnitude) -> Ball {
+        Ball::new(Position::new(x, y, z), Length::new(r))
+    }
+
+    /// Checks the contacts involving balls.
+    #[test]
+    fn contacts_ball() {
+        let c = ball(0., 0., 0., 1.)
+            .contact_ball(&ball(1.5, 0., 0., 1.))
+            .unwrap();
+        assert_eq![Direction::new(1., 0., 0.), c.normal];
+        assert_float_eq!(0.5, c.depth().m(), abs <= 1e-12);
+        assert_float_eq!(0.75, c.points[0].position.x(), abs <= 1e-12);
+        assert!(ball(0., 0., 0., 1.)
+            .contact_ball(&ball(2.5, 0., 0., 1.))
+            .is_none());
+
+        let ground = Plane::from_point_normal(Position::ZERO, Direction::new(0., 1., 0.));
+        let c = ball(0., 0.8, 0., 1.).contact_plane(&ground).unwrap();
+        assert_eq![Direction::new(0., -1., 0.), c.normal];
+        assert_float_eq!(0.2, c.depth().m(), abs <= 1e-12);
+        assert!(ball(0., 1.2, 0., 1.).contact_plane(&ground).is_none());
+
+        let cube = Obb::new(
+            Position::ZERO,
+            Direction::ONE,
+            Orientation::from_rotation_y(core::f64::consts::FRAC_PI_4),
+        );
+        let c = ball(0., 1.5, 0., 1.).contact_obb(&cube).unwrap();
+        assert_float_eq!(-1., c.normal.y(), abs <= 1e-12);
+        assert_float_eq!(0.5, c.depth().m(), abs <= 1e-12);
+        // center inside the box
+        let c = ball(0., 0.9, 0., 0.5).contact_obb(&cube).unwrap();
+        assert_float_eq!(-1., c.normal.y(), abs <= 1e-12);
+        assert_float_eq!(0.6, c.depth().m(), abs <= 1e-12);
+    }
+
+    /// Checks the contacts between boxes.
+    #[test]
+    fn contacts_box() {
+        let a = Aabb::new(Position::ZERO, Position::ONE);
+        let b = Aabb::new(Position::new(0.8, 0.5, 0.5), Position::new(2., 1.5, 1.5));
+        let c = a.contact_aabb(&b).unwrap();
+        assert_eq![Direction::new(1., 0., 0.), c.normal];
+        assert_float_eq!(0.2, c.depth().m(), abs <= 1e-12);
+
+        // a box resting on another one, face to face
+        let bottom = Obb::new(Position::ZERO, Direction::ONE, Orientation::IDENTITY);
+        let top = Obb::new(
+            Position::new(0., 1.9, 0.),
+            Direction::ONE,
+            Orientation::from_rotation_y(0.3),
+        );
+        let c = bottom.contact_obb(&top).unwrap();
+        assert_float_eq!(1., c.normal.y(), abs <= 1e-12);
+        assert_float_eq!(0.1, c.depth().m(), abs <= 1e-12);
+        assert!(c.points.len() >= 4);
+        for p in &c.points {
+            assert_float_eq!(0.95, p.position.y(), abs <= 1e-12);
+        }
+
+        // edge to edge
+        let tilted = Obb::new(
+            Position::new(1.9, 1.9, 0.),
+            Direction::ONE,
+            Orientation::from_rotation_x(core::f64::consts::FRAC_PI_4)
+                * Orientation::from_rotation_z(core::f64::consts::FRAC_PI_4),
+        );
+        assert!(bottom.contact_obb(&tilted).is_some());
+        let far = Obb::new(
+            Position::new(3.5, 0., 0.),
+            Direction::ONE,
+            Orientation::IDENTITY,
+        );
+        assert!(bottom.contact_obb(&far).is_none());
+
+        let ground = Plane::new(Direction::new(0., 1., 0.), Length::new(-0.9));
+        let c = bottom.contact_plane(&ground).unwrap();
+        assert_eq![4, c.points.len()];
+        assert_float_eq!(0.1, c.depth().m(), abs <= 1e-12);
+    }
+
+    /// Checks the contacts between capsules.
+    #[test]
+    fn contacts_capsule() {
+        let a = Capsule::new(
+            Position::new(-1., 0., 0.),
+            Position::new(1., 0., 0.),
+            Length::new(0.5),
+        );
+        let b = Capsule::new(
+            Position::new(0., 0.8, -1.),
+            Position::new(0., 0.8, 1.),
+            Length::new(0.5),
+        );
+        let c = a.contact_capsule(&b).unwrap();
+        assert_eq![Direction::new(0., 1., 0.), c.normal];
+        assert_float_eq!(0.2, c.depth().m(), abs <= 1e-12);
+        assert_float_eq!(0.4, c.points[0].position.y(), abs <= 1e-12);
+
+        let ground = Plane::new(Direction::new(0., 1., 0.), Length::new(-0.4));
+        assert_eq![2, a.contact_plane(&ground).unwrap().points.len()];
+    }
+}
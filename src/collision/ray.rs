@@ -0,0 +1,321 @@
+// fisica::collision::ray
+//
+// Ray casting.
+
+use super::{Aabb, Ball, Capsule, Obb, Plane};
+use crate::math::V3;
+use crate::units::Length;
+use crate::{Direction, Magnitude, Position};
+
+/// A half-line starting at `origin` towards `direction`.
+#[derive(Clone, Copy, Debug)]
+pub struct Ray {
+    pub origin: Position,
+    /// The unit direction.
+    pub direction: Direction,
+}
+
+impl Ray {
+    /// New Ray.
+    ///
+    /// The `direction` will be normalized.
+    pub fn new(origin: Position, direction: Direction) -> Self {
+        Self {
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    /// Returns the point at the given `distance` along the ray.
+    pub fn at(&self, distance: Length) -> Position {
+        self.origin + self.direction * distance.m()
+    }
+}
+
+/// The first intersection of a [`Ray`] with a shape.
+#[derive(Clone, Copy, Debug)]
+pub struct RayHit {
+    /// The distance from the origin of the ray.
+    pub distance: Length,
+    /// The point of intersection.
+    pub point: Position,
+    /// The unit normal of the surface at the point of intersection.
+    pub normal: Direction,
+}
+
+impl RayHit {
+    /// New RayHit.
+    #[inline]
+    pub const fn new(distance: Length, point: Position, normal: Direction) -> Self {
+        Self {
+            distance,
+            point,
+            normal,
+        }
+    }
+
+    /// New `RayHit` for a ray starting inside the shape.
+    fn inside(ray: &Ray) -> Self {
+        Self::new(Length::new(0.), ray.origin, -ray.direction)
+    }
+}
+
+/// Intersection with a [`Ray`].
+pub trait RayCast {
+    /// Returns the first intersection of the `ray` with the shape, if any,
+    /// within `max_distance` of its origin.
+    ///
+    /// A ray starting inside a solid shape hits it at distance zero,
+    /// with the normal opposite to the ray direction.
+    fn cast_ray(&self, ray: &Ray, max_distance: Length) -> Option<RayHit>;
+}
+
+/// Returns the distance along the ray to the first intersection with the
+/// sphere, or `None` if it misses or the sphere is behind.
+fn cast_sphere(ray: &Ray, center: Position, radius: Magnitude) -> Option<Magnitude> {
+    let m = ray.origin - center;
+    let b = m.dot(ray.direction);
+    let c = m.magnitude_squared() - radius * radius;
+    if c > 0. && b > 0. {
+        return None;
+    }
+    let discr = b * b - c;
+    if discr < 0. {
+        return None;
+    }
+    Some((-b - discr.sqrt()).max(0.))
+}
+
+/// Returns the entry distance and the entry axis of the ray within the box
+/// between `min` and `max`, using the slab method.
+///
+/// The axis is `None` if the origin is inside the box.
+fn cast_slabs(
+    origin: V3,
+    dir: V3,
+    min: V3,
+    max: V3,
+) -> Option<(Magnitude, Option<(usize, Magnitude)>)> {
+    let (mut t_min, mut t_max) = (Magnitude::NEG_INFINITY, Magnitude::INFINITY);
+    let mut axis = None;
+    for i in 0..3 {
+        if dir[i].abs() < Magnitude::EPSILON {
+            if origin[i] < min[i] || origin[i] > max[i] {
+                return None;
+            }
+            continue;
+        }
+        let inv = 1. / dir[i];
+        let (mut t1, mut t2) = ((min[i] - origin[i]) * inv, (max[i] - origin[i]) * inv);
+        // the sign of the normal of the face entered
+        let mut sign = -1.;
+        if t1 > t2 {
+            core::mem::swap(&mut t1, &mut t2);
+            sign = 1.;
+        }
+        if t1 > t_min {
+            t_min = t1;
+            axis = Some((i, sign));
+        }
+        t_max = t_max.min(t2);
+        if t_min > t_max {
+            return None;
+        }
+    }
+    if t_max < 0. {
+        None
+    } else if t_min < 0. {
+        Some((0., None))
+    } else {
+        Some((t_min, axis))
+    }
+}
+
+impl RayCast for Ball {
+    fn cast_ray(&self, ray: &Ray, max_distance: Length) -> Option<RayHit> {
+        let t = cast_sphere(ray, self.center, self.radius.m())?;
+        if t > max_distance.m() {
+            return None;
+        }
+        if t == 0. {
+            return Some(RayHit::inside(ray));
+        }
+        let point = ray.at(Length::new(t));
+        Some(RayHit::new(
+            Length::new(t),
+            point,
+            (point - self.center).normalize(),
+        ))
+    }
+}
+
+impl RayCast for Plane {
+    fn cast_ray(&self, ray: &Ray, max_distance: Length) -> Option<RayHit> {
+        let dist = self.calc_distance(ray.origin).m();
+        if dist <= 0. {
+            return Some(RayHit::inside(ray));
+        }
+        let denom = self.normal.dot(ray.direction);
+        if denom >= 0. {
+            return None;
+        }
+        let t = -dist / denom;
+        (t <= max_distance.m())
+            .then(|| RayHit::new(Length::new(t), ray.at(Length::new(t)), self.normal))
+    }
+}
+
+impl RayCast for Aabb {
+    fn cast_ray(&self, ray: &Ray, max_distance: Length) -> Option<RayHit> {
+        let (t, axis) = cast_slabs(
+            ray.origin.vector(),
+            ray.direction.vector(),
+            self.min.vector(),
+            self.max.vector(),
+        )?;
+        if t > max_distance.m() {
+            return None;
+        }
+        let Some((i, sign)) = axis else {
+            return Some(RayHit::inside(ray));
+        };
+        let mut normal = V3::ZERO;
+        normal[i] = sign;
+        Some(RayHit::new(
+            Length::new(t),
+            ray.at(Length::new(t)),
+            Direction::from_vector(normal),
+        ))
+    }
+}
+
+impl RayCast for Obb {
+    fn cast_ray(&self, ray: &Ray, max_distance: Length) -> Option<RayHit> {
+        let local = Ray {
+            origin: self.to_local(ray.origin),
+            direction: ray.direction.rotate(self.orientation.inverse()),
+        };
+        let h = self.half_extents;
+        let hit = Aabb::new(-h, h).cast_ray(&local, max_distance)?;
+        if hit.distance.m() == 0. {
+            return Some(RayHit::inside(ray));
+        }
+        Some(RayHit::new(
+            hit.distance,
+            ray.at(hit.distance),
+            hit.normal.rotate(self.orientation),
+        ))
+    }
+}
+
+impl RayCast for Capsule {
+    fn cast_ray(&self, ray: &Ray, max_distance: Length) -> Option<RayHit> {
+        let r = self.radius.m();
+        let axis = self.b - self.a;
+        let len2 = axis.magnitude_squared();
+        let mut best: Option<Magnitude> = None;
+
+        // the cylindrical body
+        if len2 > Magnitude::EPSILON {
+            let u = axis / len2.sqrt();
+            let m = ray.origin - self.a;
+            let (md, mu) = (ray.direction.dot(u), m.dot(u));
+            // components orthogonal to the axis
+            let (d, m) = (ray.direction - u * md, m - u * mu);
+            let a = d.magnitude_squared();
+            let b = m.dot(d);
+            let c = m.magnitude_squared() - r * r;
+            if a > Magnitude::EPSILON {
+                let discr = b * b - a * c;
+                if discr >= 0. {
+                    let t = ((-b - discr.sqrt()) / a).max(0.);
+                    let s = mu + md * t;
+                    if (0. ..=len2.sqrt()).contains(&s) && (c <= 0. || b < 0.) {
+                        best = Some(t);
+                    }
+                }
+            }
+        }
+        // the spherical caps
+        for end in [self.a, self.b] {
+            if let Some(t) = cast_sphere(ray, end, r) {
+                best = Some(best.map_or(t, |b| b.min(t)));
+            }
+        }
+
+        let t = best?;
+        if t > max_distance.m() {
+            return None;
+        }
+        if t == 0. {
+            return Some(RayHit::inside(ray));
+        }
+        let point = ray.at(Length::new(t));
+        let core = super::closest_point_segment(point, self.a, self.b);
+        Some(RayHit::new(
+            Length::new(t),
+            point,
+            (point - core).normalize(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Orientation;
+    use float_eq::assert_float_eq;
+
+    const FAR: Length = Length::new(1e3);
+
+    /// Checks rays against all the primitives.
+    #[test]
+    fn cast_rays() {
+        let ray = Ray::new(Position::new(-5., 0., 0.), Direction::new(2., 0., 0.));
+
+        let ball = Ball::new(Position::ZERO, Length::new(1.));
+        let hit = ball.cast_ray(&ray, FAR).unwrap();
+        assert_float_eq!(4., hit.distance.m(), abs <= 1e-12);
+        assert_eq![Direction::new(-1., 0., 0.), hit.normal];
+        assert!(ball.cast_ray(&ray, Length::new(3.)).is_none());
+
+        let wall = Plane::from_point_normal(Position::new(2., 0., 0.), Direction::new(-1., 0., 0.));
+        let hit = wall.cast_ray(&ray, FAR).unwrap();
+        assert_float_eq!(7., hit.distance.m(), abs <= 1e-12);
+        let away = Ray::new(ray.origin, Direction::new(-1., 0., 0.));
+        assert!(wall.cast_ray(&away, FAR).is_none());
+
+        let aabb = Aabb::new(Position::new(1., -1., -1.), Position::new(3., 1., 1.));
+        let hit = aabb.cast_ray(&ray, FAR).unwrap();
+        assert_float_eq!(6., hit.distance.m(), abs <= 1e-12);
+        assert_eq![Direction::new(-1., 0., 0.), hit.normal];
+        let inside = Ray::new(Position::new(2., 0., 0.), Direction::new(0., 1., 0.));
+        assert_float_eq!(
+            0.,
+            aabb.cast_ray(&inside, FAR).unwrap().distance.m(),
+            abs <= 0.
+        );
+
+        // a cube rotated 45º shows its edge at √2
+        let obb = Obb::new(
+            Position::ZERO,
+            Direction::ONE,
+            Orientation::from_rotation_y(core::f64::consts::FRAC_PI_4),
+        );
+        let hit = obb.cast_ray(&ray, FAR).unwrap();
+        assert_float_eq!(5. - 2f64.sqrt(), hit.distance.m(), abs <= 1e-12);
+
+        let capsule = Capsule::new(
+            Position::new(0., -1., 0.),
+            Position::new(0., 1., 0.),
+            Length::new(0.5),
+        );
+        let hit = capsule.cast_ray(&ray, FAR).unwrap();
+        assert_float_eq!(4.5, hit.distance.m(), abs <= 1e-12);
+        assert_eq![Direction::new(-1., 0., 0.), hit.normal];
+        let down = Ray::new(Position::new(0., 5., 0.), Direction::new(0., -1., 0.));
+        let hit = capsule.cast_ray(&down, FAR).unwrap();
+        assert_float_eq!(3.5, hit.distance.m(), abs <= 1e-12);
+        assert_float_eq!(1., hit.normal.y(), abs <= 1e-12);
+    }
+}
@@ -0,0 +1,258 @@
+// fisica::collision::shapes
+//
+
+use crate::units::Length;
+use crate::{Direction, Magnitude, Orientation, Position};
+
+/// A solid sphere with the given `radius` around its `center`.
+#[derive(Clone, Copy, Debug)]
+pub struct Ball {
+    pub center: Position,
+    pub radius: Length,
+}
+
+impl Ball {
+    /// New Ball.
+    #[inline]
+    pub const fn new(center: Position, radius: Length) -> Self {
+        Self { center, radius }
+    }
+
+    /// Returns the smallest [`Aabb`] containing the ball.
+    pub fn aabb(&self) -> Aabb {
+        let r = Direction::ONE * self.radius.m();
+        Aabb::new(self.center - r, self.center + r)
+    }
+}
+
+/// A half-space bounded by a plane.
+///
+/// The plane contains all the points `p` where `normal · p = offset`.
+/// The solid side is the one opposite to the `normal`.
+#[derive(Clone, Copy, Debug)]
+pub struct Plane {
+    /// The unit normal, pointing away from the solid side.
+    pub normal: Direction,
+    /// The signed distance from the origin along the normal.
+    pub offset: Length,
+}
+
+impl Plane {
+    /// New Plane.
+    ///
+    /// The `normal` will be normalized.
+    pub fn new(normal: Direction, offset: Length) -> Self {
+        Self {
+            normal: normal.normalize(),
+            offset,
+        }
+    }
+
+    /// New `Plane` passing through `point` with the given `normal`.
+    pub fn from_point_normal(point: Position, normal: Direction) -> Self {
+        let normal = normal.normalize();
+        Self::new(normal, Length::new(normal.dot(point)))
+    }
+
+    /// Returns the signed distance from the plane to `p`,
+    /// negative when `p` is on the solid side.
+    pub fn calc_distance(&self, p: Position) -> Length {
+        Length::new(self.normal.dot(p) - self.offset.m())
+    }
+
+    /// Returns the projection of `p` onto the plane.
+    pub fn calc_projection(&self, p: Position) -> Position {
+        p - self.normal * self.calc_distance(p).m()
+    }
+}
+
+/// An axis-aligned bounding box between its `min` and `max` corners.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb {
+    pub min: Position,
+    pub max: Position,
+}
+
+impl Aabb {
+    /// New Aabb.
+    #[inline]
+    pub const fn new(min: Position, max: Position) -> Self {
+        Self { min, max }
+    }
+
+    /// New `Aabb` from its `center` and `half_extents`.
+    pub fn from_center_half_extents(center: Position, half_extents: Direction) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// Returns the smallest `Aabb` containing all the `points`,
+    /// or `None` if there are no points.
+    pub fn from_points(points: &[Position]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        Some(rest.iter().fold(Self::new(*first, *first), |acc, p| {
+            Self::new(
+                Position::from_vector(acc.min.vector().min(p.vector())),
+                Position::from_vector(acc.max.vector().max(p.vector())),
+            )
+        }))
+    }
+
+    /// Returns the center.
+    pub fn center(&self) -> Position {
+        (self.min + self.max) / 2.
+    }
+
+    /// Returns the half extents along each axis.
+    pub fn half_extents(&self) -> Direction {
+        (self.max - self.min) / 2.
+    }
+
+    /// Returns the area of the surface of the box.
+    pub fn surface_area(&self) -> Magnitude {
+        let d = self.max - self.min;
+        2. * (d.x() * d.y() + d.y() * d.z() + d.z() * d.x())
+    }
+
+    /// Returns `true` if the boxes overlap.
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        self.min.vector().cmple(other.max.vector()).all()
+            && other.min.vector().cmple(self.max.vector()).all()
+    }
+
+    /// Returns `true` if `other` is fully inside this box.
+    pub fn contains(&self, other: &Aabb) -> bool {
+        self.min.vector().cmple(other.min.vector()).all()
+            && other.max.vector().cmple(self.max.vector()).all()
+    }
+
+    /// Returns `true` if the point is inside the box.
+    pub fn contains_point(&self, p: Position) -> bool {
+        self.min.vector().cmple(p.vector()).all() && p.vector().cmple(self.max.vector()).all()
+    }
+
+    /// Returns the point of the box closest to `p`.
+    pub fn calc_closest_point(&self, p: Position) -> Position {
+        Position::from_vector(p.vector().clamp(self.min.vector(), self.max.vector()))
+    }
+
+    /// Returns the smallest box containing both boxes.
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Self::new(
+            Position::from_vector(self.min.vector().min(other.min.vector())),
+            Position::from_vector(self.max.vector().max(other.max.vector())),
+        )
+    }
+
+    /// Returns the box grown by `margin` on every side.
+    pub fn expanded(&self, margin: Length) -> Aabb {
+        let m = Direction::ONE * margin.m();
+        Self::new(self.min - m, self.max + m)
+    }
+}
+
+/// An oriented bounding box.
+#[derive(Clone, Copy, Debug)]
+pub struct Obb {
+    pub center: Position,
+    /// The half lengths of the sides along the local axes.
+    pub half_extents: Direction,
+    pub orientation: Orientation,
+}
+
+impl Obb {
+    /// New Obb.
+    #[inline]
+    pub const fn new(center: Position, half_extents: Direction, orientation: Orientation) -> Self {
+        Self {
+            center,
+            half_extents,
+            orientation,
+        }
+    }
+
+    /// Returns the local unit axes, in world space.
+    pub fn axes(&self) -> [Direction; 3] {
+        [
+            Direction::new(1., 0., 0.).rotate(self.orientation),
+            Direction::new(0., 1., 0.).rotate(self.orientation),
+            Direction::new(0., 0., 1.).rotate(self.orientation),
+        ]
+    }
+
+    /// Returns the 8 corners, in world space.
+    pub fn corners(&self) -> [Position; 8] {
+        let [ax, ay, az] = self.axes();
+        let (hx, hy, hz) = (
+            ax * self.half_extents.x(),
+            ay * self.half_extents.y(),
+            az * self.half_extents.z(),
+        );
+        let c = self.center;
+        [
+            c - hx - hy - hz,
+            c + hx - hy - hz,
+            c + hx + hy - hz,
+            c - hx + hy - hz,
+            c - hx - hy + hz,
+            c + hx - hy + hz,
+            c + hx + hy + hz,
+            c - hx + hy + hz,
+        ]
+    }
+
+    /// Returns `p` expressed in the local frame of the box.
+    pub fn to_local(&self, p: Position) -> Position {
+        (p - self.center).rotate(self.orientation.inverse())
+    }
+
+    /// Returns the local point `p` expressed in world space.
+    pub fn to_world(&self, p: Position) -> Position {
+        p.rotate(self.orientation) + self.center
+    }
+
+    /// Returns the point of the box closest to `p`.
+    pub fn calc_closest_point(&self, p: Position) -> Position {
+        let h = self.half_extents.vector();
+        let local = self.to_local(p).vector().clamp(-h, h);
+        self.to_world(Position::from_vector(local))
+    }
+
+    /// Returns the smallest [`Aabb`] containing the box.
+    pub fn aabb(&self) -> Aabb {
+        let [ax, ay, az] = self.axes();
+        let h = self.half_extents;
+        let extent = Direction::from_vector(
+            ax.vector().abs() * h.x() + ay.vector().abs() * h.y() + az.vector().abs() * h.z(),
+        );
+        Aabb::new(self.center - extent, self.center + extent)
+    }
+}
+
+impl From<Aabb> for Obb {
+    fn from(b: Aabb) -> Obb {
+        Obb::new(b.center(), b.half_extents(), Orientation::IDENTITY)
+    }
+}
+
+/// A capsule: the set of points within `radius` of the segment from `a` to `b`.
+#[derive(Clone, Copy, Debug)]
+pub struct Capsule {
+    pub a: Position,
+    pub b: Position,
+    pub radius: Length,
+}
+
+impl Capsule {
+    /// New Capsule.
+    #[inline]
+    pub const fn new(a: Position, b: Position, radius: Length) -> Self {
+        Self { a, b, radius }
+    }
+
+    /// Returns the smallest [`Aabb`] containing the capsule.
+    pub fn aabb(&self) -> Aabb {
+        Ball::new(self.a, self.radius)
+            .aabb()
+            .union(&Ball::new(self.b, self.radius).aabb())
+    }
+}
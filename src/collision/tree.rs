@@ -0,0 +1,441 @@
+// fisica::collision::tree
+//
+// Dynamic bounding volume hierarchy broad phase.
+
+use alloc::vec::Vec;
+
+use super::{Aabb, Ray, RayCast, RayHit};
+use crate::units::Length;
+use crate::Position;
+
+/// A node of the tree: either a leaf with an object, or a branch.
+#[derive(Clone, Debug)]
+struct Node {
+    /// The fat box of a leaf, or the union of the children of a branch.
+    aabb: Aabb,
+    parent: Option<usize>,
+    children: Option<[usize; 2]>,
+    /// The id of the object, for leaves.
+    id: usize,
+    /// The height of the subtree, 0 for leaves.
+    height: usize,
+}
+
+/// A dynamic bounding volume hierarchy of [`Aabb`]s.
+///
+/// Each object is stored in a leaf with a *fat* box, grown by a `margin` so
+/// that objects moving a little don't need to be reinserted. New leaves are
+/// placed using the surface area heuristic, and the tree is kept balanced
+/// with rotations. It works well with objects of mixed sizes.
+///
+/// Objects are referred to by the proxy returned on insertion.
+///
+/// See: [*Dynamic Bounding Volume Hierarchies*][0] (Erin Catto, 2019).
+///
+/// [0]:https://box2d.org/publications/
+#[derive(Clone, Debug)]
+pub struct AabbTree {
+    nodes: Vec<Node>,
+    root: Option<usize>,
+    free: Vec<usize>,
+    margin: Length,
+    len: usize,
+}
+
+impl AabbTree {
+    /// New empty `AabbTree`, with the given fat `margin`.
+    pub fn new(margin: Length) -> Self {
+        Self {
+            nodes: Vec::new(),
+            root: None,
+            free: Vec::new(),
+            margin,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of objects.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no objects.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the height of the tree, 0 if it's empty or has a single object.
+    pub fn height(&self) -> usize {
+        self.root.map_or(0, |r| self.nodes[r].height)
+    }
+
+    /// Returns the fat box stored for the given `proxy`.
+    pub fn fat_aabb(&self, proxy: usize) -> Aabb {
+        self.nodes[proxy].aabb
+    }
+
+    /// Returns the id of the object of the given `proxy`.
+    pub fn id(&self, proxy: usize) -> usize {
+        self.nodes[proxy].id
+    }
+
+    /// Inserts an object with the given bounding box, returning its proxy.
+    pub fn insert(&mut self, id: usize, aabb: Aabb) -> usize {
+        let leaf = self.allocate(Node {
+            aabb: aabb.expanded(self.margin),
+            parent: None,
+            children: None,
+            id,
+            height: 0,
+        });
+        self.insert_leaf(leaf);
+        self.len += 1;
+        leaf
+    }
+
+    /// Removes the object of the given `proxy`.
+    ///
+    /// # Panics
+    /// Panics if `proxy` is not a live proxy, e.g. if it was already removed.
+    pub fn remove(&mut self, proxy: usize) {
+        assert!(
+            proxy < self.nodes.len()
+                && self.nodes[proxy].children.is_none()
+                && !self.free.contains(&proxy),
+            "proxy {proxy} is not in the tree"
+        );
+        self.remove_leaf(proxy);
+        self.free.push(proxy);
+        self.len -= 1;
+    }
+
+    /// Updates the bounding box of the object of the given `proxy`.
+    ///
+    /// The leaf is only reinserted if the box escapes its fat box,
+    /// in which case it returns `true`.
+    pub fn update(&mut self, proxy: usize, aabb: Aabb) -> bool {
+        if self.nodes[proxy].aabb.contains(&aabb) {
+            return false;
+        }
+        self.remove_leaf(proxy);
+        self.nodes[proxy].aabb = aabb.expanded(self.margin);
+        self.insert_leaf(proxy);
+        true
+    }
+
+    /// Returns the ids of the objects whose fat box overlaps `region`.
+    pub fn query_aabb(&self, region: &Aabb) -> Vec<usize> {
+        let mut found = Vec::new();
+        self.traverse(|aabb| aabb.intersects(region), |node| found.push(node.id));
+        found
+    }
+
+    /// Returns the ids of the objects whose fat box is within the `radius`
+    /// of the `center`.
+    pub fn query_radius(&self, center: Position, radius: Length) -> Vec<usize> {
+        let r2 = radius.m() * radius.m();
+        let mut found = Vec::new();
+        self.traverse(
+            |aabb| (aabb.calc_closest_point(center) - center).magnitude_squared() <= r2,
+            |node| found.push(node.id),
+        );
+        found
+    }
+
+    /// Returns the ids of the objects whose fat box is hit by the `ray`
+    /// within `max_distance`, with the hits sorted by distance.
+    pub fn query_ray(&self, ray: &Ray, max_distance: Length) -> Vec<(usize, RayHit)> {
+        let mut hits = Vec::new();
+        self.traverse(
+            |aabb| aabb.cast_ray(ray, max_distance).is_some(),
+            |node| {
+                if let Some(hit) = node.aabb.cast_ray(ray, max_distance) {
+                    hits.push((node.id, hit));
+                }
+            },
+        );
+        hits.sort_by(|a: &(usize, RayHit), b| a.1.distance.m().total_cmp(&b.1.distance.m()));
+        hits
+    }
+
+    /// Returns all the pairs of ids of objects whose fat boxes overlap.
+    ///
+    /// Each pair is returned once.
+    pub fn pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for (leaf, node) in self.nodes.iter().enumerate() {
+            if node.children.is_some() || !self.is_live(leaf) {
+                continue;
+            }
+            let mut stack: Vec<usize> = self.root.into_iter().collect();
+            while let Some(i) = stack.pop() {
+                let other = &self.nodes[i];
+                if !other.aabb.intersects(&node.aabb) {
+                    continue;
+                }
+                match other.children {
+                    Some(children) => stack.extend(children),
+                    // each pair is found from both leaves, keep one
+                    None if i > leaf => pairs.push((node.id, other.id)),
+                    None => (),
+                }
+            }
+        }
+        pairs
+    }
+
+    /// Visits the leaves of the subtrees whose box passes the `test`.
+    fn traverse(&self, test: impl Fn(&Aabb) -> bool, mut visit: impl FnMut(&Node)) {
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(i) = stack.pop() {
+            let node = &self.nodes[i];
+            if !test(&node.aabb) {
+                continue;
+            }
+            match node.children {
+                Some(children) => stack.extend(children),
+                None => visit(node),
+            }
+        }
+    }
+
+    /// Returns `true` if the node is part of the tree.
+    fn is_live(&self, index: usize) -> bool {
+        self.nodes[index].parent.is_some() || self.root == Some(index)
+    }
+
+    /// Stores a node, reusing a free slot if possible.
+    fn allocate(&mut self, node: Node) -> usize {
+        if let Some(i) = self.free.pop() {
+            self.nodes[i] = node;
+            i
+        } else {
+            self.nodes.push(node);
+            self.nodes.len() - 1
+        }
+    }
+
+    /// Links the leaf next to its best sibling, by the surface area heuristic.
+    fn insert_leaf(&mut self, leaf: usize) {
+        let Some(root) = self.root else {
+            self.root = Some(leaf);
+            self.nodes[leaf].parent = None;
+            return;
+        };
+        let leaf_aabb = self.nodes[leaf].aabb;
+
+        // find the best sibling
+        let mut index = root;
+        while let Some([c1, c2]) = self.nodes[index].children {
+            let area = self.nodes[index].aabb.surface_area();
+            let combined = self.nodes[index].aabb.union(&leaf_aabb).surface_area();
+            // the cost of creating a new parent for this node and the leaf
+            let cost = 2. * combined;
+            // the minimum cost of pushing the leaf further down the tree
+            let inheritance = 2. * (combined - area);
+            let child_cost = |c: usize| {
+                let node = &self.nodes[c];
+                let grown = node.aabb.union(&leaf_aabb).surface_area();
+                if node.children.is_some() {
+                    grown - node.aabb.surface_area() + inheritance
+                } else {
+                    grown + inheritance
+                }
+            };
+            let (cost1, cost2) = (child_cost(c1), child_cost(c2));
+            if cost < cost1 && cost < cost2 {
+                break;
+            }
+            index = if cost1 < cost2 { c1 } else { c2 };
+        }
+        let sibling = index;
+
+        // create a new parent
+        let old_parent = self.nodes[sibling].parent;
+        let parent = self.allocate(Node {
+            aabb: self.nodes[sibling].aabb.union(&leaf_aabb),
+            parent: old_parent,
+            children: Some([sibling, leaf]),
+            id: usize::MAX,
+            height: self.nodes[sibling].height + 1,
+        });
+        self.nodes[sibling].parent = Some(parent);
+        self.nodes[leaf].parent = Some(parent);
+        match old_parent {
+            Some(p) => self.replace_child(p, sibling, parent),
+            None => self.root = Some(parent),
+        }
+
+        self.refit(self.nodes[leaf].parent);
+    }
+
+    /// Unlinks the leaf from the tree, freeing its parent.
+    fn remove_leaf(&mut self, leaf: usize) {
+        if self.root == Some(leaf) {
+            self.root = None;
+            return;
+        }
+        let parent = self.nodes[leaf].parent.expect("the leaf is in the tree");
+        let grand_parent = self.nodes[parent].parent;
+        let [c1, c2] = self.nodes[parent].children.expect("the parent is a branch");
+        let sibling = if c1 == leaf { c2 } else { c1 };
+
+        self.nodes[sibling].parent = grand_parent;
+        match grand_parent {
+            Some(g) => {
+                self.replace_child(g, parent, sibling);
+                self.refit(Some(g));
+            }
+            None => self.root = Some(sibling),
+        }
+        self.nodes[parent].parent = None;
+        self.nodes[parent].children = None;
+        self.free.push(parent);
+        self.nodes[leaf].parent = None;
+    }
+
+    /// Replaces the child `old` of `parent` with `new`.
+    fn replace_child(&mut self, parent: usize, old: usize, new: usize) {
+        if let Some(children) = &mut self.nodes[parent].children {
+            if children[0] == old {
+                children[0] = new;
+            } else {
+                children[1] = new;
+            }
+        }
+    }
+
+    /// Rebalances and recomputes the boxes and heights up to the root.
+    fn refit(&mut self, mut index: Option<usize>) {
+        while let Some(i) = index {
+            let i = self.balance(i);
+            let [c1, c2] = self.nodes[i].children.expect("a branch");
+            self.nodes[i].height = 1 + self.nodes[c1].height.max(self.nodes[c2].height);
+            self.nodes[i].aabb = self.nodes[c1].aabb.union(&self.nodes[c2].aabb);
+            index = self.nodes[i].parent;
+        }
+    }
+
+    /// Performs a left or right rotation if the node `a` is imbalanced.
+    ///
+    /// Returns the new root of the subtree.
+    fn balance(&mut self, a: usize) -> usize {
+        let Some([b, c]) = self.nodes[a].children else {
+            return a;
+        };
+        if self.nodes[a].height < 2 {
+            return a;
+        }
+        let (hb, hc) = (self.nodes[b].height, self.nodes[c].height);
+        if hc > hb + 1 {
+            self.rotate(a, c, b, 1)
+        } else if hb > hc + 1 {
+            self.rotate(a, b, c, 0)
+        } else {
+            a
+        }
+    }
+
+    /// Raises the child `up` of `a` (at the `slot` 0 or 1) over `a`,
+    /// where `other` is the other child of `a`.
+    fn rotate(&mut self, a: usize, up: usize, other: usize, slot: usize) -> usize {
+        let [f, g] = self.nodes[up].children.expect("a taller child is a branch");
+
+        // swap a and up
+        let parent = self.nodes[a].parent;
+        self.nodes[up].parent = parent;
+        self.nodes[a].parent = Some(up);
+        match parent {
+            Some(p) => self.replace_child(p, a, up),
+            None => self.root = Some(up),
+        }
+
+        // keep the taller grandchild under up, and give the other one to a
+        let (keep, give) = if self.nodes[f].height > self.nodes[g].height {
+            (f, g)
+        } else {
+            (g, f)
+        };
+        self.nodes[up].children = Some([a, keep]);
+        let mut a_children = [other, other];
+        a_children[slot] = give;
+        self.nodes[a].children = Some(a_children);
+        self.nodes[give].parent = Some(a);
+
+        self.nodes[a].aabb = self.nodes[other].aabb.union(&self.nodes[give].aabb);
+        self.nodes[a].height = 1 + self.nodes[other].height.max(self.nodes[give].height);
+        self.nodes[up].aabb = self.nodes[a].aabb.union(&self.nodes[keep].aabb);
+        self.nodes[up].height = 1 + self.nodes[a].height.max(self.nodes[keep].height);
+        up
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Direction, Magnitude};
+    use alloc::vec;
+
+    /// Checks the tree stays balanced and its queries match a brute force search.
+    #[test]
+    fn aabb_tree() {
+        let mut tree = AabbTree::new(Length::new(0.1));
+        let boxes: Vec<Aabb> = (0..64)
+            .map(|i| {
+                let p = Position::new(i as Magnitude, 0., 0.);
+                // mixed sizes
+                let half = if i % 8 == 0 { 2. } else { 0.25 };
+                Aabb::from_center_half_extents(p, Direction::ONE * half)
+            })
+            .collect();
+        let proxies: Vec<usize> = boxes
+            .iter()
+            .enumerate()
+            .map(|(i, b)| tree.insert(i, *b))
+            .collect();
+        assert_eq![64, tree.len()];
+        assert!(tree.height() <= 10);
+
+        let mut near = tree.query_radius(Position::new(20., 0., 0.), Length::new(1.));
+        near.sort_unstable();
+        assert_eq![vec![19, 20, 21], near];
+
+        let brute: usize = (0..64)
+            .flat_map(|i| (i + 1..64).map(move |j| (i, j)))
+            .filter(|(i, j)| {
+                boxes[*i]
+                    .expanded(Length::new(0.1))
+                    .intersects(&boxes[*j].expanded(Length::new(0.1)))
+            })
+            .count();
+        assert_eq![brute, tree.pairs().len()];
+
+        let ray = Ray::new(Position::new(-10., 0., 0.), Direction::new(1., 0., 0.));
+        let hits = tree.query_ray(&ray, Length::new(11.));
+        assert_eq![vec![0, 1], hits.iter().map(|h| h.0).collect::<Vec<_>>()];
+
+        // small moves stay within the fat boxes
+        let moved =
+            Aabb::from_center_half_extents(Position::new(1.05, 0., 0.), Direction::ONE * 0.25);
+        assert!(!tree.update(proxies[1], moved));
+        let moved =
+            Aabb::from_center_half_extents(Position::new(100., 0., 0.), Direction::ONE * 0.25);
+        assert!(tree.update(proxies[1], moved));
+        assert_eq![
+            vec![1],
+            tree.query_radius(Position::new(100., 0., 0.), Length::new(1.))
+        ];
+
+        for p in proxies.iter().step_by(2) {
+            tree.remove(*p);
+        }
+        assert_eq![32, tree.len()];
+        assert_eq![
+            vec![1],
+            tree.query_radius(Position::new(100., 0., 0.), Length::new(1.))
+        ];
+        assert!(tree
+            .query_radius(Position::new(20., 0., 0.), Length::new(0.5))
+            .is_empty());
+    }
+}
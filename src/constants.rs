@@ -23,3 +23,18 @@ use crate::Magnitude;
 // https://www.johndcook.com/blog/2021/03/31/coulombs-constant/
 // https://physics.stackexchange.com/questions/93588/why-does-coulombs-constant-have-units
 pub const COULOMB_CONSTANT: Magnitude = 8_987_551_792.3;
+
+/// The [Newtonian constant of gravitation][0], or gravitational constant.
+///
+/// `6.674 30×10⁻¹¹ N×m²/kg²`
+///
+/// [0]:https://en.wikipedia.org/wiki/Gravitational_constant
+pub const GRAVITATIONAL_CONSTANT: Magnitude = 6.674_30e-11;
+
+/// The [standard acceleration of gravity][0], `g₀`, used to define the
+/// specific impulse and the standard weight.
+///
+/// `9.806 65 m/s²`
+///
+/// [0]:https://en.wikipedia.org/wiki/Standard_gravity
+pub const STANDARD_GRAVITY: Magnitude = 9.806_65;
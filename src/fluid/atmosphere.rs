@@ -0,0 +1,283 @@
+// fisica::fluid::atmosphere
+//
+// The International Standard Atmosphere, up to 86 km.
+//
+// Data from the U.S. Standard Atmosphere 1976, identical to the ISA up to
+// 86 km of geometric height.
+// - <https://en.wikipedia.org/wiki/International_Standard_Atmosphere>
+// - <https://ntrs.nasa.gov/citations/19770009539>
+
+use super::Fluid;
+use crate::constants::STANDARD_GRAVITY as G0;
+use crate::units::{Density, DynamicViscosity, Height, Length, Pressure, Speed, Temperature};
+use crate::Magnitude;
+
+/// The specific gas constant of dry air, in `J/(kg K)`.
+const R: Magnitude = 287.052_87;
+/// The heat capacity ratio of dry air.
+const GAMMA: Magnitude = 1.4;
+/// The effective radius of the Earth for the geopotential height, in `m`.
+const EARTH_RADIUS: Magnitude = 6_356_766.;
+/// The pressure at sea level, in `Pa`.
+const SEA_LEVEL_PRESSURE: Magnitude = 101_325.;
+/// The geopotential height of the top of the model, at 86 km, in `m`.
+const TOP: Magnitude = EARTH_RADIUS * 86_000. / (EARTH_RADIUS + 86_000.);
+/// The constants of Sutherland's formula for the viscosity of air.
+const SUTHERLAND: (Magnitude, Magnitude) = (1.458e-6, 110.4);
+
+/// A layer of the atmosphere, where the temperature varies linearly with the
+/// geopotential height.
+struct Layer {
+    /// The geopotential height of the base, in `m`.
+    base: Magnitude,
+    /// The temperature at the base, in `K`.
+    temperature: Magnitude,
+    /// The temperature lapse rate, in `K/m`.
+    lapse: Magnitude,
+}
+
+const fn layer(base: Magnitude, temperature: Magnitude, lapse: Magnitude) -> Layer {
+    Layer {
+        base,
+        temperature,
+        lapse,
+    }
+}
+
+/// The troposphere, the stratosphere and the mesosphere.
+const LAYERS: [Layer; 7] = [
+    layer(0., 288.15, -0.0065),
+    layer(11_000., 216.65, 0.),
+    layer(20_000., 216.65, 0.001),
+    layer(32_000., 228.65, 0.0028),
+    layer(47_000., 270.65, 0.),
+    layer(51_000., 270.65, -0.0028),
+    layer(71_000., 214.65, -0.002),
+];
+
+impl Layer {
+    /// Returns the temperature at the geopotential height `h`.
+    fn calc_temperature(&self, h: Magnitude) -> Magnitude {
+        self.temperature + self.lapse * (h - self.base)
+    }
+
+    /// Returns the pressure at the geopotential height `h`, from the pressure
+    /// `pb` at the base.
+    fn calc_pressure(&self, pb: Magnitude, h: Magnitude) -> Magnitude {
+        if self.lapse == 0. {
+            pb * (-G0 * (h - self.base) / (R * self.temperature)).exp()
+        } else {
+            pb * (self.temperature / self.calc_temperature(h)).powf(G0 / (R * self.lapse))
+        }
+    }
+
+    /// Returns the geopotential height with the pressure `p`, from the
+    /// pressure `pb` at the base.
+    fn calc_height(&self, pb: Magnitude, p: Magnitude) -> Magnitude {
+        if self.lapse == 0. {
+            self.base - R * self.temperature / G0 * (p / pb).ln()
+        } else {
+            let ratio = (p / pb).powf(-R * self.lapse / G0);
+            self.base + self.temperature / self.lapse * (ratio - 1.)
+        }
+    }
+}
+
+/// Returns each layer with its pressure at the base, and its top.
+fn layers() -> impl Iterator<Item = (&'static Layer, Magnitude, Magnitude)> {
+    let mut pb = SEA_LEVEL_PRESSURE;
+    LAYERS.iter().enumerate().map(move |(i, layer)| {
+        let top = LAYERS.get(i + 1).map_or(TOP, |l| l.base);
+        let base_pressure = pb;
+        pb = layer.calc_pressure(pb, top);
+        (layer, base_pressure, top)
+    })
+}
+
+/// The [International Standard Atmosphere][0] (ISA), from sea level up to
+/// 86 km, with an optional temperature offset.
+///
+/// The offset shifts the temperature at every height, like in the
+/// `ISA+15` hot day, while keeping the standard pressure at each height.
+///
+/// [0]:https://en.wikipedia.org/wiki/International_Standard_Atmosphere
+#[derive(Clone, Copy, Debug)]
+pub struct Atmosphere {
+    /// The offset from the standard temperature.
+    pub offset: Temperature,
+}
+
+/// The state of the [`Atmosphere`] at some height.
+#[derive(Clone, Copy, Debug)]
+pub struct AtmosphereState {
+    pub temperature: Temperature,
+    pub pressure: Pressure,
+    pub density: Density,
+    pub speed_of_sound: Speed,
+    pub viscosity: DynamicViscosity,
+}
+
+impl AtmosphereState {
+    /// Returns the air as a [`Fluid`].
+    #[inline]
+    pub fn fluid(&self) -> Fluid {
+        Fluid::new(self.density, self.viscosity)
+    }
+}
+
+impl Atmosphere {
+    /// The standard atmosphere, without temperature offset.
+    pub const STANDARD: Self = Self::new(Temperature::new(0.));
+
+    /// The highest geometric [`Height`] of the model.
+    pub const MAX_HEIGHT: Height = Length::new(86_000.);
+
+    /// The highest geopotential [`Height`] of the model, about `84.852 km`,
+    /// the geopotential of [`MAX_HEIGHT`][Self::MAX_HEIGHT].
+    pub const MAX_GEOPOTENTIAL_HEIGHT: Height = Length::new(TOP);
+
+    /// New Atmosphere with the given temperature `offset`.
+    #[inline]
+    pub const fn new(offset: Temperature) -> Self {
+        Self { offset }
+    }
+
+    /// Converts the geometric height `h` to geopotential height
+    /// (`H = r h / (r + h)`).
+    #[inline]
+    pub fn to_geopotential(h: Height) -> Height {
+        Length::new(EARTH_RADIUS * h.m() / (EARTH_RADIUS + h.m()))
+    }
+
+    /// Converts the geopotential height `h` to geometric height
+    /// (`h = r H / (r - H)`).
+    #[inline]
+    pub fn to_geometric(h: Height) -> Height {
+        Length::new(EARTH_RADIUS * h.m() / (EARTH_RADIUS - h.m()))
+    }
+
+    /// Returns the state of the atmosphere at the geometric [`Height`] `h`
+    /// above the mean sea level.
+    ///
+    /// Returns `None` below the sea level or above [`MAX_HEIGHT`][Self::MAX_HEIGHT].
+    pub fn at_geometric(&self, h: Height) -> Option<AtmosphereState> {
+        self.at_geopotential(Self::to_geopotential(h))
+    }
+
+    /// Returns the state of the atmosphere at the geopotential [`Height`] `h`.
+    ///
+    /// Returns `None` below the sea level or above
+    /// [`MAX_GEOPOTENTIAL_HEIGHT`][Self::MAX_GEOPOTENTIAL_HEIGHT].
+    pub fn at_geopotential(&self, h: Height) -> Option<AtmosphereState> {
+        let h = h.m();
+        if !(0. ..=TOP).contains(&h) {
+            return None;
+        }
+        let (layer, pb, _) = layers().find(|(_, _, top)| h <= *top)?;
+        let t = layer.calc_temperature(h) + self.offset.m();
+        let p = layer.calc_pressure(pb, h);
+        let (beta, s) = SUTHERLAND;
+        Some(AtmosphereState {
+            temperature: Temperature::new(t),
+            pressure: Pressure::new(p),
+            density: Density::new(p / (R * t)),
+            speed_of_sound: Speed::new((GAMMA * R * t).sqrt()),
+            viscosity: DynamicViscosity::new(beta * t.powf(1.5) / (t + s)),
+        })
+    }
+
+    /// Returns the pressure altitude, the geopotential [`Height`] of the
+    /// standard atmosphere with the given [`Pressure`].
+    ///
+    /// It's what an altimeter set to the standard sea level pressure reads.
+    /// Returns `None` if the pressure is higher than at sea level, or lower
+    /// than at the top of the model.
+    pub fn calc_pressure_altitude(p: Pressure) -> Option<Height> {
+        let p = p.m();
+        if p > SEA_LEVEL_PRESSURE {
+            return None;
+        }
+        layers()
+            .find(|(layer, pb, top)| p >= layer.calc_pressure(*pb, *top))
+            .map(|(layer, pb, _)| Length::new(layer.calc_height(pb, p)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the standard values, from the tables of the U.S. Standard
+    /// Atmosphere 1976.
+    #[test]
+    fn atmosphere_standard() {
+        let isa = Atmosphere::STANDARD;
+
+        let sea = isa.at_geometric(Length::new(0.)).unwrap();
+        assert_float_eq!(288.15, sea.temperature.m(), r2nd <= 1e-15);
+        assert_float_eq!(101_325., sea.pressure.m(), r2nd <= 1e-15);
+        assert_float_eq!(1.225, sea.density.m(), rmax <= 1e-4);
+        assert_float_eq!(Speed::SOUND.m(), sea.speed_of_sound.m(), rmax <= 1e-4);
+        assert_float_eq!(1.7894e-5, sea.viscosity.m(), rmax <= 1e-4);
+
+        // (geopotential height, temperature, pressure) at the layer bases
+        for (h, t, p) in [
+            (11_000., 216.65, 22_632.06),
+            (20_000., 216.65, 5_474.889),
+            (32_000., 228.65, 868.018_7),
+            (47_000., 270.65, 110.906_3),
+            (51_000., 270.65, 66.938_87),
+            (71_000., 214.65, 3.956_420),
+        ] {
+            let s = isa.at_geopotential(Length::new(h)).unwrap();
+            assert_float_eq!(t, s.temperature.m(), r2nd <= 1e-14);
+            assert_float_eq!(p, s.pressure.m(), rmax <= 1e-5);
+        }
+
+        // the top of the model, at 86 km of geometric height
+        let top = isa.at_geometric(Atmosphere::MAX_HEIGHT).unwrap();
+        assert_float_eq!(186.946, top.temperature.m(), rmax <= 1e-4);
+        assert_float_eq!(0.373_4, top.pressure.m(), rmax <= 1e-3);
+        assert!(isa.at_geometric(Length::new(86_100.)).is_none());
+        assert!(isa.at_geometric(Length::new(-1.)).is_none());
+        let top = Atmosphere::MAX_GEOPOTENTIAL_HEIGHT;
+        assert_float_eq!(84_852., top.m(), rmax <= 1e-5);
+        assert!(isa.at_geopotential(top).is_some());
+        assert!(isa.at_geopotential(Length::new(top.m() + 1.)).is_none());
+
+        let h = Length::new(30_000.);
+        let back = Atmosphere::to_geometric(Atmosphere::to_geopotential(h));
+        assert_float_eq!(h.m(), back.m(), r2nd <= 1e-14);
+        assert_float_eq!(29_859., Atmosphere::to_geopotential(h).m(), rmax <= 1e-4);
+    }
+
+    /// Checks the temperature offsets and the pressure altitude.
+    #[test]
+    fn atmosphere_offset_altitude() {
+        let (isa, hot) = (Atmosphere::STANDARD, Atmosphere::new(Temperature::new(15.)));
+        let h = Length::new(2_000.);
+        let (s, s15) = (
+            isa.at_geopotential(h).unwrap(),
+            hot.at_geopotential(h).unwrap(),
+        );
+        assert_float_eq!(s.temperature.m() + 15., s15.temperature.m(), r2nd <= 1e-15);
+        assert_float_eq!(s.pressure.m(), s15.pressure.m(), r2nd <= 1e-15);
+        assert!(s15.density.m() < s.density.m());
+        assert!(s15.speed_of_sound.m() > s.speed_of_sound.m());
+
+        // the pressure altitude inverts the pressure, in every layer
+        for h in [
+            0., 5_000., 15_000., 25_000., 40_000., 50_000., 60_000., 80_000., TOP,
+        ] {
+            let p = isa.at_geopotential(Length::new(h)).unwrap().pressure;
+            let back = Atmosphere::calc_pressure_altitude(p).unwrap();
+            assert_float_eq!(h, back.m(), abs <= 1e-6);
+        }
+        assert!(Atmosphere::calc_pressure_altitude(Pressure::new(102_000.)).is_none());
+        assert!(Atmosphere::calc_pressure_altitude(Pressure::new(0.1)).is_none());
+
+        // a standard altimeter at 700 hPa reads about 3 km
+        let h = Atmosphere::calc_pressure_altitude(Pressure::new(70_000.)).unwrap();
+        assert_float_eq!(3_012., h.m(), rmax <= 1e-3);
+    }
+}
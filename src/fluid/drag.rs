@@ -0,0 +1,208 @@
+// fisica::fluid::drag
+//
+// Drag and lift forces on bodies moving through a fluid.
+
+use core::f64::consts::PI;
+
+use super::Fluid;
+use crate::units::{Area, Force, Gfs, Length, Mass, Speed, Velocity};
+use crate::{Direction, Magnitude};
+
+/// The drag of a body moving through a [`Fluid`], opposing its motion.
+///
+/// The buoyancy of the body is not included.
+#[derive(Clone, Copy, Debug)]
+pub enum Drag {
+    /// The drag at high Reynolds numbers, proportional to the square of the
+    /// speed (`F = ½ ρ u² C_d A`).
+    Quadratic {
+        /// The dimensionless drag coefficient `C_d`.
+        coefficient: Magnitude,
+        /// The reference area, usually the frontal area.
+        area: Area,
+    },
+    /// The drag on a small sphere at low Reynolds numbers (`Re < 1`),
+    /// proportional to the speed and the viscosity (`F = 6π μ r u`).
+    Stokes {
+        /// The radius of the sphere.
+        radius: Length,
+    },
+}
+
+impl Drag {
+    /// The drag coefficient of a sphere at `10³ < Re < 10⁵`.
+    pub const SPHERE: Magnitude = 0.47;
+    /// The drag coefficient of a cube, facing the flow.
+    pub const CUBE: Magnitude = 1.05;
+    /// The drag coefficient of a long cylinder, across the flow.
+    pub const CYLINDER: Magnitude = 0.82;
+    /// The drag coefficient of a flat plate, facing the flow.
+    pub const FLAT_PLATE: Magnitude = 1.28;
+    /// The drag coefficient of a streamlined body.
+    pub const STREAMLINED: Magnitude = 0.04;
+    /// The drag coefficient of a skydiver, belly down.
+    pub const SKYDIVER: Magnitude = 1.0;
+
+    /// New quadratic drag with the given `coefficient` and reference [`Area`].
+    #[inline]
+    pub const fn quadratic(coefficient: Magnitude, area: Area) -> Self {
+        Self::Quadratic { coefficient, area }
+    }
+
+    /// New quadratic drag of a sphere of the given `radius`.
+    #[inline]
+    pub fn sphere(radius: Length) -> Self {
+        Self::quadratic(Self::SPHERE, Area::new(PI * radius.m() * radius.m()))
+    }
+
+    /// New Stokes drag of a small sphere of the given `radius`.
+    #[inline]
+    pub const fn stokes(radius: Length) -> Self {
+        Self::Stokes { radius }
+    }
+
+    /// Calculates the drag [`Force`] on a body moving with the given
+    /// [`Velocity`] relative to the `fluid`.
+    pub fn calc_force(&self, fluid: &Fluid, v: Velocity) -> Force {
+        // the magnitude of the drag divided by the speed
+        let k = match self {
+            Drag::Quadratic { coefficient, area } => {
+                0.5 * fluid.density.m() * v.m() * coefficient * area.m()
+            }
+            Drag::Stokes { radius } => 6. * PI * fluid.viscosity.m() * radius.m(),
+        };
+        Force::new(v.d * -k)
+    }
+
+    /// Calculates the terminal [`Speed`] of a body of the given [`Mass`]
+    /// falling through the `fluid` under the [`Gfs`], where the drag
+    /// balances its weight.
+    ///
+    /// - Quadratic: `u = √(2 m g / (ρ C_d A))`.
+    /// - Stokes: `u = m g / (6π μ r)`.
+    pub fn calc_terminal_speed(&self, m: Mass, fluid: &Fluid, g: Gfs) -> Speed {
+        let weight = m.m() * g.m();
+        Speed::new(match self {
+            Drag::Quadratic { coefficient, area } => {
+                (2. * weight / (fluid.density.m() * coefficient * area.m())).sqrt()
+            }
+            Drag::Stokes { radius } => weight / (6. * PI * fluid.viscosity.m() * radius.m()),
+        })
+    }
+}
+
+/// The lift of a body moving through a [`Fluid`], perpendicular to its motion
+/// (`F = ½ ρ u² C_l A`).
+#[derive(Clone, Copy, Debug)]
+pub struct Lift {
+    /// The dimensionless lift coefficient `C_l`, which depends on the shape
+    /// and the angle of attack.
+    pub coefficient: Magnitude,
+    /// The reference area, usually the planform area of the wing.
+    pub area: Area,
+}
+
+impl Lift {
+    /// New Lift.
+    #[inline]
+    pub const fn new(coefficient: Magnitude, area: Area) -> Self {
+        Self { coefficient, area }
+    }
+
+    /// Calculates the lift [`Force`] on a body moving with the given
+    /// [`Velocity`] relative to the `fluid`.
+    ///
+    /// The force is perpendicular to the velocity, towards the `up` side of
+    /// the body. It's zero if the body doesn't move, or moves along `up`.
+    pub fn calc_force(&self, fluid: &Fluid, v: Velocity, up: Direction) -> Force {
+        let speed = v.m();
+        if speed == 0. {
+            return Force::new(Direction::ZERO);
+        }
+        let forward = v.d * (1. / speed);
+        let side = up - forward * up.dot(forward);
+        let len = side.magnitude();
+        if len == 0. {
+            return Force::new(Direction::ZERO);
+        }
+        let q = fluid.calc_dynamic_pressure(Speed::new(speed));
+        Force::new(side * (q.m() * self.coefficient * self.area.m() / len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the quadratic drag and the terminal speed of a skydiver.
+    #[test]
+    fn drag_quadratic() {
+        let (m, air) = (Mass::new(80.), Fluid::AIR);
+        let g = Gfs::new(Direction::new(0., -9.81, 0.));
+        let drag = Drag::quadratic(Drag::SKYDIVER, Area::new(0.45));
+
+        let terminal = drag.calc_terminal_speed(m, &air, g);
+        assert_float_eq!(Speed::HUMAN_FREE_FALL_MAX.m(), terminal.m(), rmax <= 0.02);
+
+        // the drag opposes the motion, and balances the weight at terminal speed
+        let v = Velocity::new(Direction::new(0., -terminal.m(), 0.));
+        let f = drag.calc_force(&air, v);
+        assert_float_eq!(m.m() * g.m(), f.d.y(), r2nd <= 1e-14);
+
+        // falling from rest, the speed approaches the terminal speed
+        let (mut v, dt) = (Direction::ZERO, 0.01);
+        for _ in 0..3000 {
+            let f = drag.calc_force(&air, Velocity::new(v));
+            v += (g.d + f.d * (1. / m.m())) * dt;
+        }
+        assert_float_eq!(terminal.m(), -v.y(), rmax <= 1e-4);
+
+        // a sphere of 10 cm across
+        let Drag::Quadratic { area, .. } = Drag::sphere(Length::new(0.05)) else {
+            unreachable!();
+        };
+        assert_float_eq!(0.007_853_98, area.m(), rmax <= 1e-6);
+    }
+
+    /// Checks the Stokes drag of a small water droplet falling in air.
+    #[test]
+    fn drag_stokes() {
+        let (air, r) = (Fluid::AIR, 1e-5);
+        let m = Mass::new(Fluid::WATER.density.m() * 4. / 3. * PI * r * r * r);
+        let g = Gfs::new(Direction::new(0., -9.81, 0.));
+        let drag = Drag::stokes(Length::new(r));
+
+        // u = 2 ρ g r² / (9 μ)
+        let terminal = drag.calc_terminal_speed(m, &air, g);
+        let expected = 2. * 998. * 9.81 * r * r / (9. * 1.825e-5);
+        assert_float_eq!(expected, terminal.m(), r2nd <= 1e-14);
+        assert!(air.calc_reynolds_number(terminal, Length::new(2. * r)) < 1.);
+
+        let f = drag.calc_force(&air, Velocity::new(Direction::new(0., -terminal.m(), 0.)));
+        assert_float_eq!(m.m() * 9.81, f.d.y(), r2nd <= 1e-14);
+    }
+
+    /// Checks the lift of a wing.
+    #[test]
+    fn drag_lift() {
+        let (air, wing) = (Fluid::AIR, Lift::new(0.5, Area::new(16.)));
+        let up = Direction::new(0., 1., 0.);
+
+        // flying level, the lift points up
+        let f = wing.calc_force(&air, Velocity::new(Direction::new(50., 0., 0.)), up);
+        assert_float_eq!(0.5 * 1.204 * 2500. * 0.5 * 16., f.d.y(), r2nd <= 1e-15);
+        assert_float_eq!(0., f.d.x(), abs <= 0.);
+
+        // climbing, it's perpendicular to the velocity
+        let v = Velocity::new(Direction::new(40., 30., 0.));
+        let f = wing.calc_force(&air, v, up);
+        assert_float_eq!(0., f.d.dot(v.d), abs <= 1e-9);
+        assert!(f.d.y() > 0.);
+
+        assert_float_eq!(
+            0.,
+            wing.calc_force(&air, Velocity::new(up), up).m(),
+            abs <= 0.
+        );
+    }
+}
@@ -0,0 +1,95 @@
+// fisica::fluid
+//
+//! Fluid dynamics
+//!
+//! A [`Fluid`] is described by its [`Density`] and [`DynamicViscosity`],
+//! from which the [*Reynolds number*][0] of a flow is derived.
+//!
+//! ## Drag and lift
+//!
+//! A body moving through a fluid feels a [`Drag`] force opposing its motion,
+//! either quadratic with the speed at high Reynolds numbers, or linear
+//! following [*Stokes' law*][1] for small particles, and a [`Lift`] force
+//! perpendicular to its motion. They take the velocity of the body relative to
+//! the fluid, and return the [`Force`] to apply on it, so they can be used as
+//! force generators in any simulation.
+//!
+//! ## Pipe flow
+//!
+//! The [`FlowState`]s along a streamline are related by Bernoulli's equation,
+//! solved for any unknown with [`Fluid::solve_bernoulli`]. A [`Pipe`] gives
+//! the [`FlowRegime`], the Darcy friction factor and the pressure drop of a
+//! [`VolumetricFlowRate`] through it, and the Hagen–Poiseuille laminar flow.
+//!
+//! ## Atmosphere
+//!
+//! The [`Atmosphere`] models the [*International Standard Atmosphere*][2] up
+//! to 86 km, giving the [`AtmosphereState`] of the air at any height, and the
+//! pressure altitude read by an altimeter.
+//!
+//! ## Smoothed particle hydrodynamics
+//!
+//! The [`SphSolver`] simulates a weakly compressible fluid in 2D or 3D as a
+//! set of [`SphParticle`]s, whose density, pressure, viscosity and surface
+//! tension are interpolated with the [`SphKernel`]s over their neighbours.
+//!
+//! [0]:https://en.wikipedia.org/wiki/Reynolds_number
+//! [1]:https://en.wikipedia.org/wiki/Stokes%27_law
+//! [2]:https://en.wikipedia.org/wiki/International_Standard_Atmosphere
+//!
+//! # External links
+//! - <https://en.wikipedia.org/wiki/Fluid_dynamics>
+//! - <https://en.wikipedia.org/wiki/Drag_(physics)>
+//! - <https://en.wikipedia.org/wiki/Lift_(force)>
+//
+
+use crate::material::Material;
+use crate::units::{Density, DynamicViscosity, Length, Pressure, Speed};
+use crate::Magnitude;
+
+#[allow(unused_imports)] // doc links
+use crate::units::{Force, VolumetricFlowRate};
+
+mod atmosphere;
+mod drag;
+mod pipe;
+mod sph;
+
+pub use atmosphere::{Atmosphere, AtmosphereState};
+pub use drag::{Drag, Lift};
+pub use pipe::{FlowRegime, FlowState, FlowUnknown, Pipe};
+pub use sph::{EquationOfState, SphBoundary, SphDimension, SphKernel, SphParticle, SphSolver};
+
+/// A newtonian fluid.
+#[derive(Clone, Copy, Debug)]
+pub struct Fluid {
+    pub density: Density,
+    pub viscosity: DynamicViscosity,
+}
+
+impl Fluid {
+    /// Liquid water, at 20 °C.
+    pub const WATER: Self = Self::new(Material::WATER.density, DynamicViscosity::new(1.002e-3));
+    /// Dry air at sea level, at 20 °C.
+    pub const AIR: Self = Self::new(Material::AIR.density, DynamicViscosity::new(1.825e-5));
+
+    /// New Fluid.
+    #[inline]
+    pub const fn new(density: Density, viscosity: DynamicViscosity) -> Self {
+        Self { density, viscosity }
+    }
+
+    /// Returns the dynamic pressure of the flow at the given [`Speed`]
+    /// (`q = ½ ρ u²`).
+    #[inline]
+    pub fn calc_dynamic_pressure(&self, s: Speed) -> Pressure {
+        Pressure::new(0.5 * self.density.m() * s.m() * s.m())
+    }
+
+    /// Returns the Reynolds number of the flow at the given [`Speed`] past a
+    /// characteristic [`Length`] (`Re = ρ u L / μ`).
+    #[inline]
+    pub fn calc_reynolds_number(&self, s: Speed, l: Length) -> Magnitude {
+        self.viscosity.calc_reynolds_number(self.density, s, l)
+    }
+}
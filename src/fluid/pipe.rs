@@ -0,0 +1,374 @@
+// fisica::fluid::pipe
+//
+// Incompressible flow along streamlines and through pipes.
+
+use core::f64::consts::PI;
+
+use super::Fluid;
+use crate::units::{
+    Area, Gfs, Height, KinematicViscosity, Length, Pressure, Speed, VolumetricFlowRate,
+};
+use crate::Magnitude;
+
+/// The state of an incompressible flow at a point of a streamline.
+#[derive(Clone, Copy, Debug)]
+pub struct FlowState {
+    pub pressure: Pressure,
+    pub speed: Speed,
+    pub height: Height,
+}
+
+/// The quantity of a [`FlowState`] to solve with [`Fluid::solve_bernoulli`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlowUnknown {
+    Pressure,
+    Speed,
+    Height,
+}
+
+/// The regime of a flow, from its Reynolds number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlowRegime {
+    /// Smooth flow in parallel layers, below `Re = 2300` in a pipe.
+    Laminar,
+    /// Intermittently turbulent flow, between both limits.
+    Transitional,
+    /// Chaotic flow with eddies, above `Re = 4000` in a pipe.
+    Turbulent,
+}
+
+impl FlowState {
+    /// New FlowState.
+    #[inline]
+    pub const fn new(pressure: Pressure, speed: Speed, height: Height) -> Self {
+        Self {
+            pressure,
+            speed,
+            height,
+        }
+    }
+}
+
+impl FlowRegime {
+    /// The Reynolds number below which the flow in a pipe is laminar.
+    pub const LAMINAR_LIMIT: Magnitude = 2300.;
+    /// The Reynolds number above which the flow in a pipe is turbulent.
+    pub const TURBULENT_LIMIT: Magnitude = 4000.;
+
+    /// Returns the regime of a pipe flow with the given Reynolds number,
+    /// in either direction.
+    pub fn from_reynolds(re: Magnitude) -> Self {
+        let re = re.abs();
+        if re < Self::LAMINAR_LIMIT {
+            Self::Laminar
+        } else if re <= Self::TURBULENT_LIMIT {
+            Self::Transitional
+        } else {
+            Self::Turbulent
+        }
+    }
+}
+
+/// # Pipe flow
+impl Fluid {
+    /// Returns the [`KinematicViscosity`] of the fluid (`ν = μ / ρ`).
+    #[inline]
+    pub fn kinematic_viscosity(&self) -> KinematicViscosity {
+        KinematicViscosity::from_dynamic_density(self.viscosity, self.density)
+    }
+
+    /// Returns the total pressure of a [`FlowState`], the sum of the static,
+    /// dynamic and hydrostatic pressures (`p + ½ ρ u² + ρ g h`).
+    ///
+    /// By [*Bernoulli's principle*][0] it's constant along a streamline of a
+    /// steady, incompressible and inviscid flow.
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Bernoulli%27s_principle
+    pub fn calc_total_pressure(&self, state: &FlowState, g: Gfs) -> Pressure {
+        let rho = self.density.m();
+        Pressure::new(
+            state.pressure.m()
+                + 0.5 * rho * state.speed.m() * state.speed.m()
+                + rho * g.m() * state.height.m(),
+        )
+    }
+
+    /// Solves the `unknown` quantity of the state `to`, downstream of the
+    /// state `from` along the same streamline, using Bernoulli's equation.
+    ///
+    /// The value of the unknown quantity in `to` is ignored. Returns `None`
+    /// if there's no solution, like a speed for a pressure too high to reach.
+    pub fn solve_bernoulli(
+        &self,
+        g: Gfs,
+        from: &FlowState,
+        mut to: FlowState,
+        unknown: FlowUnknown,
+    ) -> Option<FlowState> {
+        let total = self.calc_total_pressure(from, g);
+        let (rho, g) = (self.density.m(), g.m());
+        let p = to.pressure.m();
+        let q = 0.5 * rho * to.speed.m() * to.speed.m();
+        let z = rho * g * to.height.m();
+        match unknown {
+            FlowUnknown::Pressure => to.pressure = Pressure::new(total.m() - q - z),
+            FlowUnknown::Speed => {
+                let q = total.m() - p - z;
+                if q < 0. {
+                    return None;
+                }
+                to.speed = Speed::new((2. * q / rho).sqrt());
+            }
+            FlowUnknown::Height => {
+                if g == 0. {
+                    return None;
+                }
+                to.height = Height::new((total.m() - p - q) / (rho * g));
+            }
+        }
+        Some(to)
+    }
+}
+
+/// A straight pipe of circular section, full of fluid.
+#[derive(Clone, Copy, Debug)]
+pub struct Pipe {
+    /// The inner diameter, or the hydraulic diameter of a non-circular duct.
+    pub diameter: Length,
+    pub length: Length,
+    /// The absolute roughness of the inner surface.
+    pub roughness: Length,
+}
+
+impl Pipe {
+    /// The roughness of drawn tubing, like copper or glass.
+    pub const DRAWN_TUBING: Length = Length::new(1.5e-6);
+    /// The roughness of PVC and plastic pipes.
+    pub const PLASTIC: Length = Length::new(1.5e-6);
+    /// The roughness of commercial steel pipes.
+    pub const COMMERCIAL_STEEL: Length = Length::new(4.5e-5);
+    /// The roughness of galvanized iron pipes.
+    pub const GALVANIZED_IRON: Length = Length::new(1.5e-4);
+    /// The roughness of cast iron pipes.
+    pub const CAST_IRON: Length = Length::new(2.6e-4);
+    /// The roughness of concrete pipes.
+    pub const CONCRETE: Length = Length::new(1e-3);
+
+    /// New Pipe.
+    #[inline]
+    pub const fn new(diameter: Length, length: Length, roughness: Length) -> Self {
+        Self {
+            diameter,
+            length,
+            roughness,
+        }
+    }
+
+    /// Returns the [hydraulic diameter][0] of a duct or open channel with the
+    /// given flow [`Area`] and wetted perimeter (`D = 4 A / P`).
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Hydraulic_diameter
+    #[inline]
+    pub fn hydraulic_diameter(area: Area, wetted_perimeter: Length) -> Length {
+        Length::new(4. * area.m() / wetted_perimeter.m())
+    }
+
+    /// Returns the cross-section [`Area`].
+    #[inline]
+    pub fn area(&self) -> Area {
+        Area::new(0.25 * PI * self.diameter.m() * self.diameter.m())
+    }
+
+    /// Returns the mean [`Speed`] of the given flow through the pipe.
+    #[inline]
+    pub fn calc_speed(&self, flow: VolumetricFlowRate) -> Speed {
+        flow.calc_speed(self.area())
+    }
+
+    /// Returns the Reynolds number of the given flow of `fluid` through the
+    /// pipe (`Re = ρ u D / μ`).
+    #[inline]
+    pub fn calc_reynolds_number(&self, fluid: &Fluid, flow: VolumetricFlowRate) -> Magnitude {
+        fluid.calc_reynolds_number(self.calc_speed(flow), self.diameter)
+    }
+
+    /// Returns the [*Darcy friction factor*][0] at the given Reynolds number,
+    /// in either direction.
+    ///
+    /// It's `f = 64 / Re` for laminar flow, and solves the Colebrook–White
+    /// equation otherwise, starting from the Swamee–Jain approximation.
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Darcy_friction_factor_formulae
+    pub fn calc_friction_factor(&self, re: Magnitude) -> Magnitude {
+        let re = re.abs();
+        if FlowRegime::from_reynolds(re) == FlowRegime::Laminar {
+            return 64. / re;
+        }
+        // 1/√f = -2 log₁₀(ε / 3.7 D + 2.51 / (Re √f)), by fixed-point iteration
+        let relative = self.roughness.m() / (3.7 * self.diameter.m());
+        let mut x = 1. / self.calc_swamee_jain(re).sqrt();
+        for _ in 0..50 {
+            let next = -2. * (relative + 2.51 * x / re).log10();
+            let done = (next - x).abs() <= 1e-12 * x;
+            x = next;
+            if done {
+                break;
+            }
+        }
+        1. / (x * x)
+    }
+
+    /// Returns the Swamee–Jain explicit approximation of the turbulent Darcy
+    /// friction factor, within 1% of Colebrook–White for `5000 < Re < 10⁸`.
+    pub fn calc_swamee_jain(&self, re: Magnitude) -> Magnitude {
+        let log = (self.roughness.m() / (3.7 * self.diameter.m()) + 5.74 / re.powf(0.9)).log10();
+        0.25 / (log * log)
+    }
+
+    /// Returns the pressure drop of the given flow of `fluid` along the pipe,
+    /// from the [*Darcy–Weisbach equation*][0] (`Δp = f (L / D) ½ ρ u²`).
+    ///
+    /// The drop has the sign of the flow, negative when it runs backwards.
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Darcy%E2%80%93Weisbach_equation
+    pub fn calc_pressure_drop(&self, fluid: &Fluid, flow: VolumetricFlowRate) -> Pressure {
+        let re = self.calc_reynolds_number(fluid, flow);
+        if re == 0. {
+            return Pressure::new(0.);
+        }
+        let f = self.calc_friction_factor(re);
+        let q = fluid.calc_dynamic_pressure(self.calc_speed(flow));
+        Pressure::new(re.signum() * f * self.length.m() / self.diameter.m() * q.m())
+    }
+
+    /// Returns the laminar flow of `fluid` driven through the pipe by the
+    /// given pressure drop, from the [*Hagen–Poiseuille equation*][0]
+    /// (`Q = π r⁴ Δp / (8 μ L)`).
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Hagen%E2%80%93Poiseuille_equation
+    pub fn calc_poiseuille_flow(&self, fluid: &Fluid, drop: Pressure) -> VolumetricFlowRate {
+        let r = 0.5 * self.diameter.m();
+        VolumetricFlowRate::new(
+            PI * r.powi(4) * drop.m() / (8. * fluid.viscosity.m() * self.length.m()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::units::{Density, DynamicViscosity},
+        crate::Direction,
+        float_eq::assert_float_eq,
+    };
+
+    /// Checks Bernoulli's equation with a draining tank and a venturi.
+    #[test]
+    fn pipe_bernoulli() {
+        let (water, g) = (Fluid::WATER, Gfs::new(Direction::new(0., -9.81, 0.)));
+        let atm = Pressure::new(101_325.);
+
+        // Torricelli's law: draining 5 m below the surface, u = √(2 g h)
+        let surface = FlowState::new(atm, Speed::new(0.), Height::new(5.));
+        let outlet = FlowState::new(atm, Speed::new(0.), Height::new(0.));
+        let out = water
+            .solve_bernoulli(g, &surface, outlet, FlowUnknown::Speed)
+            .unwrap();
+        assert_float_eq!((2. * 9.81 * 5_f64).sqrt(), out.speed.m(), r2nd <= 1e-15);
+
+        // the jet rises back to the surface
+        let top = FlowState::new(atm, Speed::new(0.), Height::new(0.));
+        let top = water
+            .solve_bernoulli(g, &out, top, FlowUnknown::Height)
+            .unwrap();
+        assert_float_eq!(5., top.height.m(), r2nd <= 1e-14);
+
+        // a horizontal venturi halving the area doubles the speed, and drops
+        // the pressure by 3/2 ρ u²
+        let flow = VolumetricFlowRate::from_area_speed(Area::new(0.02), Speed::new(2.));
+        let inlet = FlowState::new(Pressure::new(200_000.), Speed::new(2.), Height::new(0.));
+        let throat = FlowState::new(atm, flow.calc_speed(Area::new(0.01)), Height::new(0.));
+        let throat = water
+            .solve_bernoulli(g, &inlet, throat, FlowUnknown::Pressure)
+            .unwrap();
+        assert_float_eq!(
+            200_000. - 1.5 * 998. * 4.,
+            throat.pressure.m(),
+            r2nd <= 1e-15
+        );
+        assert_float_eq!(
+            water.calc_total_pressure(&inlet, g).m(),
+            water.calc_total_pressure(&throat, g).m(),
+            r2nd <= 1e-15
+        );
+
+        // no flow can reach a higher total pressure
+        let high = FlowState::new(Pressure::new(300_000.), Speed::new(0.), Height::new(0.));
+        assert!(water
+            .solve_bernoulli(g, &inlet, high, FlowUnknown::Speed)
+            .is_none());
+    }
+
+    /// Checks the friction factors and the pressure drops.
+    #[test]
+    fn pipe_friction() {
+        let water = Fluid::WATER;
+        assert_eq![FlowRegime::Laminar, FlowRegime::from_reynolds(1000.)];
+        assert_eq![FlowRegime::Transitional, FlowRegime::from_reynolds(3000.)];
+        assert_eq![FlowRegime::Turbulent, FlowRegime::from_reynolds(1e5)];
+
+        // water at 2 m/s through 100 m of 10 cm commercial steel pipe
+        let pipe = Pipe::new(Length::new(0.1), Length::new(100.), Pipe::COMMERCIAL_STEEL);
+        let flow = VolumetricFlowRate::from_area_speed(pipe.area(), Speed::new(2.));
+        let re = pipe.calc_reynolds_number(&water, flow);
+        assert_float_eq!(199_202., re, rmax <= 1e-5);
+
+        // the friction factor solves Colebrook–White
+        let f = pipe.calc_friction_factor(re);
+        let rhs = -2. * (4.5e-4 / 3.7 + 2.51 / (re * f.sqrt())).log10();
+        assert_float_eq!(1. / f.sqrt(), rhs, r2nd <= 1e-12);
+        assert_float_eq!(f, pipe.calc_swamee_jain(re), rmax <= 0.01);
+        assert_float_eq!(0.0186, f, rmax <= 0.01);
+
+        let drop = pipe.calc_pressure_drop(&water, flow);
+        assert_float_eq!(f * 1000. * 0.5 * 998. * 4., drop.m(), r2nd <= 1e-14);
+
+        // a backwards flow has the same friction, against it
+        let back = VolumetricFlowRate::new(-flow.m());
+        let re_back = pipe.calc_reynolds_number(&water, back);
+        assert_eq![FlowRegime::Turbulent, FlowRegime::from_reynolds(re_back)];
+        assert_float_eq!(f, pipe.calc_friction_factor(re_back), r2nd <= 1e-15);
+        assert_float_eq!(
+            -drop.m(),
+            pipe.calc_pressure_drop(&water, back).m(),
+            r2nd <= 1e-15
+        );
+
+        // a laminar flow of oil through a thin tube follows Hagen–Poiseuille,
+        // consistent with the laminar friction factor
+        let oil = Fluid::new(Density::new(900.), DynamicViscosity::new(0.1));
+        let tube = Pipe::new(Length::new(0.01), Length::new(2.), Pipe::DRAWN_TUBING);
+        let flow = tube.calc_poiseuille_flow(&oil, Pressure::new(10_000.));
+        assert_eq![
+            FlowRegime::Laminar,
+            FlowRegime::from_reynolds(tube.calc_reynolds_number(&oil, flow))
+        ];
+        assert_float_eq!(
+            10_000.,
+            tube.calc_pressure_drop(&oil, flow).m(),
+            r2nd <= 1e-13
+        );
+        let back = VolumetricFlowRate::new(-flow.m());
+        assert_float_eq!(
+            -10_000.,
+            tube.calc_pressure_drop(&oil, back).m(),
+            r2nd <= 1e-13
+        );
+
+        // a square duct of side 20 cm
+        let d = Pipe::hydraulic_diameter(Area::new(0.04), Length::new(0.8));
+        assert_float_eq!(0.2, d.m(), r2nd <= 1e-15);
+        assert_float_eq!(1e-6, water.kinematic_viscosity().m(), rmax <= 0.01);
+    }
+}
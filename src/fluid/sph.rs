@@ -0,0 +1,673 @@
+// fisica::fluid::sph
+//
+// Smoothed particle hydrodynamics.
+
+use alloc::{vec, vec::Vec};
+use core::f64::consts::PI;
+
+use crate::collision::{Aabb, Plane, SpatialHash};
+use crate::math::V3;
+use crate::units::{
+    Acceleration, Density, DynamicViscosity, Length, Mass, Pressure, Speed, Time, Velocity,
+};
+use crate::{Direction, Magnitude, Position};
+
+/// The number of dimensions of an SPH simulation.
+///
+/// In 2D the particles move in the XY plane, and the masses and densities
+/// are per unit of thickness, in `kg/m` and `kg/m²`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SphDimension {
+    Two,
+    Three,
+}
+
+impl SphDimension {
+    /// Returns the number of dimensions.
+    #[inline]
+    pub const fn count(&self) -> usize {
+        match self {
+            SphDimension::Two => 2,
+            SphDimension::Three => 3,
+        }
+    }
+}
+
+/// The smoothing kernels of radius `h`, from [*Particle-Based Fluid
+/// Simulation for Interactive Applications*][0] (Müller et al. 2003).
+///
+/// - `poly6` for the densities and the surface tension.
+/// - `spiky` for the pressure forces, since its gradient doesn't vanish
+///   when the particles get close.
+/// - `viscosity` for the viscous forces, since its laplacian is positive.
+///
+/// They are normalized to integrate to 1 over the plane or the space.
+///
+/// [0]:https://matthias-research.github.io/pages/publications/sca03.pdf
+#[derive(Clone, Copy, Debug)]
+pub struct SphKernel {
+    /// The smoothing radius `h`, beyond which the kernels are zero.
+    pub radius: Length,
+    pub dimension: SphDimension,
+}
+
+impl SphKernel {
+    /// New SphKernel.
+    #[inline]
+    pub const fn new(radius: Length, dimension: SphDimension) -> Self {
+        Self { radius, dimension }
+    }
+
+    /// Returns the poly6 kernel at the distance `r` (`W = K (h² - r²)³`).
+    pub fn poly6(&self, r: Magnitude) -> Magnitude {
+        let h = self.radius.m();
+        if r >= h {
+            return 0.;
+        }
+        self.poly6_constant() * (h * h - r * r).powi(3)
+    }
+
+    /// Returns the gradient of the poly6 kernel at the offset `r`.
+    pub fn poly6_gradient(&self, r: Direction) -> Direction {
+        let (h, r2) = (self.radius.m(), r.magnitude_squared());
+        if r2 >= h * h {
+            return Direction::ZERO;
+        }
+        r * (-6. * self.poly6_constant() * (h * h - r2).powi(2))
+    }
+
+    /// Returns the laplacian of the poly6 kernel at the distance `r`.
+    pub fn poly6_laplacian(&self, r: Magnitude) -> Magnitude {
+        let h = self.radius.m();
+        if r >= h {
+            return 0.;
+        }
+        let (d, x) = (self.dimension.count() as Magnitude, h * h - r * r);
+        self.poly6_constant() * x * (24. * r * r - 6. * d * x)
+    }
+
+    /// Returns the spiky kernel at the distance `r` (`W = K (h - r)³`).
+    pub fn spiky(&self, r: Magnitude) -> Magnitude {
+        let h = self.radius.m();
+        if r >= h {
+            return 0.;
+        }
+        self.spiky_constant() * (h - r).powi(3)
+    }
+
+    /// Returns the gradient of the spiky kernel at the offset `r`.
+    ///
+    /// It's zero at the origin, where the direction is undefined.
+    pub fn spiky_gradient(&self, r: Direction) -> Direction {
+        let (h, len) = (self.radius.m(), r.magnitude());
+        if len >= h || len == 0. {
+            return Direction::ZERO;
+        }
+        r * (-3. * self.spiky_constant() * (h - len).powi(2) / len)
+    }
+
+    /// Returns the laplacian of the viscosity kernel at the distance `r`.
+    pub fn viscosity_laplacian(&self, r: Magnitude) -> Magnitude {
+        let h = self.radius.m();
+        if r >= h {
+            return 0.;
+        }
+        let k = match self.dimension {
+            SphDimension::Two => 40. / (PI * h.powi(5)),
+            SphDimension::Three => 45. / (PI * h.powi(6)),
+        };
+        k * (h - r)
+    }
+
+    fn poly6_constant(&self) -> Magnitude {
+        let h = self.radius.m();
+        match self.dimension {
+            SphDimension::Two => 4. / (PI * h.powi(8)),
+            SphDimension::Three => 315. / (64. * PI * h.powi(9)),
+        }
+    }
+
+    fn spiky_constant(&self) -> Magnitude {
+        let h = self.radius.m();
+        match self.dimension {
+            SphDimension::Two => 10. / (PI * h.powi(5)),
+            SphDimension::Three => 15. / (PI * h.powi(6)),
+        }
+    }
+}
+
+/// The equation of state that gives the [`Pressure`] of a weakly
+/// compressible fluid from its [`Density`].
+///
+/// The `speed_of_sound` sets the stiffness. For a density variation under 1%
+/// it should be about 10 times the fastest speed of the flow.
+#[derive(Clone, Copy, Debug)]
+pub enum EquationOfState {
+    /// A linear relation (`p = c² (ρ - ρ₀)`).
+    Linear { speed_of_sound: Speed },
+    /// The [*Tait equation*][0] (`p = ρ₀ c² / γ ((ρ / ρ₀)^γ - 1)`), usually
+    /// with an `exponent` `γ = 7` for water.
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Tait_equation
+    Tait {
+        speed_of_sound: Speed,
+        exponent: Magnitude,
+    },
+}
+
+impl EquationOfState {
+    /// Returns the speed of sound of the fluid.
+    #[inline]
+    pub fn speed_of_sound(&self) -> Speed {
+        match *self {
+            EquationOfState::Linear { speed_of_sound } => speed_of_sound,
+            EquationOfState::Tait { speed_of_sound, .. } => speed_of_sound,
+        }
+    }
+
+    /// Calculates the [`Pressure`] at the given [`Density`], relative to the
+    /// `rest` density.
+    pub fn calc_pressure(&self, density: Density, rest: Density) -> Pressure {
+        let c = self.speed_of_sound().m();
+        Pressure::new(match *self {
+            EquationOfState::Linear { .. } => c * c * (density.m() - rest.m()),
+            EquationOfState::Tait { exponent, .. } => {
+                rest.m() * c * c / exponent * ((density.m() / rest.m()).powf(exponent) - 1.)
+            }
+        })
+    }
+}
+
+/// A boundary of an [`SphSolver`].
+#[derive(Clone, Copy, Debug)]
+pub enum SphBoundary {
+    /// Keeps the particles on the free side of the [`Plane`].
+    Plane(Plane),
+    /// Keeps the particles inside the [`Aabb`].
+    Container(Aabb),
+}
+
+/// A particle of fluid of an [`SphSolver`].
+#[derive(Clone, Copy, Debug)]
+pub struct SphParticle {
+    pub position: Position,
+    pub velocity: Velocity,
+    /// The density, from the last [`SphSolver::update_densities`].
+    pub density: Density,
+    /// The pressure, from the last [`SphSolver::update_densities`].
+    pub pressure: Pressure,
+}
+
+/// A weakly compressible smoothed particle hydrodynamics solver.
+///
+/// Each particle carries the same [`Mass`] of fluid, and the fields of the
+/// fluid are interpolated by summing over the neighbours within the radius of
+/// the [`SphKernel`], which are found with a [`SpatialHash`].
+///
+/// The walls of the [`SphBoundary`]s mirror the nearby particles, so the
+/// fluid next to them keeps its density and pressure, and the particles are
+/// kept a quarter of the kernel radius away from them.
+///
+/// The negative pressures are clamped to zero, to avoid the clumping of
+/// the particles at the free surface.
+#[derive(Clone, Debug)]
+pub struct SphSolver {
+    pub particles: Vec<SphParticle>,
+    /// The mass of each particle.
+    pub mass: Mass,
+    pub kernel: SphKernel,
+    pub rest_density: Density,
+    pub equation: EquationOfState,
+    pub viscosity: DynamicViscosity,
+    /// The surface tension coefficient, in `N/m`.
+    pub surface_tension: Magnitude,
+    pub boundaries: Vec<SphBoundary>,
+    /// The fraction of the normal velocity kept when bouncing off the
+    /// boundaries.
+    pub restitution: Magnitude,
+    /// The Courant number, the fraction of the kernel radius that the
+    /// fastest signal can travel in a time step.
+    pub courant: Magnitude,
+}
+
+impl SphSolver {
+    /// New `SphSolver` without particles nor boundaries.
+    pub fn new(
+        kernel: SphKernel,
+        mass: Mass,
+        rest_density: Density,
+        equation: EquationOfState,
+    ) -> Self {
+        Self {
+            particles: Vec::new(),
+            mass,
+            kernel,
+            rest_density,
+            equation,
+            viscosity: DynamicViscosity::new(0.),
+            surface_tension: 0.,
+            boundaries: Vec::new(),
+            restitution: 0.,
+            courant: 0.4,
+        }
+    }
+
+    /// New `SphSolver` for particles laid out in a lattice with the given
+    /// `spacing`.
+    ///
+    /// The kernel radius is twice the spacing, and the mass of the particles
+    /// is chosen so that the lattice is at the `rest_density`.
+    pub fn with_spacing(
+        dimension: SphDimension,
+        spacing: Length,
+        rest_density: Density,
+        equation: EquationOfState,
+    ) -> Self {
+        let kernel = SphKernel::new(Length::new(2. * spacing.m()), dimension);
+        let (s, n) = (spacing.m(), 2_i32);
+        let depth = if dimension == SphDimension::Three {
+            n
+        } else {
+            0
+        };
+        let mut sum = 0.;
+        for i in -n..=n {
+            for j in -n..=n {
+                for k in -depth..=depth {
+                    let r = V3::new(i.into(), j.into(), k.into()).length() * s;
+                    sum += kernel.poly6(r);
+                }
+            }
+        }
+        let mass = Mass::new(rest_density.m() / sum);
+        Self::new(kernel, mass, rest_density, equation)
+    }
+
+    /// Adds a particle with the given [`Velocity`].
+    pub fn add_particle(&mut self, position: Position, velocity: Velocity) {
+        self.particles.push(SphParticle {
+            position,
+            velocity,
+            density: self.rest_density,
+            pressure: Pressure::new(0.),
+        });
+    }
+
+    /// Fills the `region` with particles at rest, in a lattice with the given
+    /// `spacing` starting at its `min` corner.
+    ///
+    /// In 2D only the XY plane at the `min` corner is filled.
+    pub fn fill(&mut self, region: &Aabb, spacing: Length) {
+        let s = spacing.m();
+        let count = |d: Magnitude| (d / s + 1e-9).floor() as usize + 1;
+        let size = region.max - region.min;
+        let nz = match self.kernel.dimension {
+            SphDimension::Two => 1,
+            SphDimension::Three => count(size.z()),
+        };
+        for k in 0..nz {
+            for j in 0..count(size.y()) {
+                for i in 0..count(size.x()) {
+                    let offset = Direction::new(i as Magnitude, j as Magnitude, k as Magnitude);
+                    let p = region.min + offset * s;
+                    self.add_particle(p, Velocity::new(Direction::ZERO));
+                }
+            }
+        }
+    }
+
+    /// Updates the [`Density`] and the [`Pressure`] of every particle.
+    pub fn update_densities(&mut self) {
+        let (points, neighbours) = self.find_neighbours();
+        self.calc_densities(&points, &neighbours);
+    }
+
+    /// Returns the largest stable time step, from the Courant–Friedrichs–Lewy
+    /// condition on the speed of sound and the fastest particle
+    /// (`Δt = C h / (c + |v|)`), and the viscous diffusion
+    /// (`Δt = 0.125 h² ρ₀ / μ`).
+    pub fn calc_time_step(&self) -> Time {
+        let h = self.kernel.radius.m();
+        let fastest = self
+            .particles
+            .iter()
+            .map(|p| p.velocity.m())
+            .fold(0., Magnitude::max);
+        let mut dt = self.courant * h / (self.equation.speed_of_sound().m() + fastest);
+        if self.viscosity.m() > 0. {
+            dt = dt.min(0.125 * h * h * self.rest_density.m() / self.viscosity.m());
+        }
+        Time::new(dt)
+    }
+
+    /// Advances the simulation by `dt`, under the given `gravity`, in as many
+    /// substeps as needed to respect the [time step limit][Self::calc_time_step].
+    ///
+    /// Returns the number of substeps. It stops without stepping further if
+    /// `dt` is not finite, or if the time step limit is not positive and
+    /// finite, e.g. with a zero `courant` number or an infinite velocity.
+    pub fn step(&mut self, dt: Time, gravity: Acceleration) -> usize {
+        if !dt.m().is_finite() {
+            return 0;
+        }
+        let (mut remaining, mut substeps) = (dt.m(), 0);
+        while remaining > 0. {
+            let limit = self.calc_time_step().m();
+            if !(limit > 0. && limit.is_finite()) {
+                break;
+            }
+            let h = limit.min(remaining);
+            remaining -= h;
+            substeps += 1;
+
+            let (points, neighbours) = self.find_neighbours();
+            self.calc_densities(&points, &neighbours);
+            let accelerations = self.calc_accelerations(&points, &neighbours, gravity);
+            for (p, a) in self.particles.iter_mut().zip(accelerations) {
+                p.velocity.d += Direction::from_vector(a * h);
+                if self.kernel.dimension == SphDimension::Two {
+                    p.velocity.d = Direction::new(p.velocity.d.x(), p.velocity.d.y(), 0.);
+                }
+                p.position += p.velocity.d * h;
+            }
+            self.enforce_boundaries();
+        }
+        substeps
+    }
+
+    /// Returns the walls of the boundaries, as planes facing the fluid.
+    fn walls(&self) -> Vec<Plane> {
+        let axes = self.kernel.dimension.count();
+        let mut walls = Vec::new();
+        for boundary in &self.boundaries {
+            match boundary {
+                SphBoundary::Plane(plane) => walls.push(*plane),
+                SphBoundary::Container(aabb) => {
+                    for axis in 0..axes {
+                        let mut n = [0.; 3];
+                        n[axis] = 1.;
+                        let n = Direction::new(n[0], n[1], n[2]);
+                        walls.push(Plane::from_point_normal(aabb.min, n));
+                        walls.push(Plane::from_point_normal(aabb.max, -n));
+                    }
+                }
+            }
+        }
+        walls
+    }
+
+    /// Returns the points that interact with the particles, and the indices
+    /// of the neighbour points of each particle, including itself, using a
+    /// uniform grid with cells of the kernel radius.
+    ///
+    /// The points are the particles, followed by the ghosts of the particles
+    /// near the walls, mirrored behind them to make up for the missing fluid.
+    /// Each point has the index of its particle.
+    fn find_neighbours(&self) -> (Vec<(Position, usize)>, Vec<Vec<usize>>) {
+        let radius = self.kernel.radius;
+        let mut points: Vec<_> = self
+            .particles
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (p.position, i))
+            .collect();
+        for wall in self.walls() {
+            for (i, p) in self.particles.iter().enumerate() {
+                let d = wall.calc_distance(p.position).m();
+                if d > 0. && d < radius.m() {
+                    points.push((p.position - wall.normal * (2. * d), i));
+                }
+            }
+        }
+        let mut grid = SpatialHash::new(radius, 2 * points.len().max(1));
+        for (j, (p, _)) in points.iter().enumerate() {
+            grid.insert_point(j, *p);
+        }
+        let neighbours = self
+            .particles
+            .iter()
+            .map(|p| grid.query_radius(p.position, radius))
+            .collect();
+        (points, neighbours)
+    }
+
+    /// Sums the densities, and applies the equation of state.
+    fn calc_densities(&mut self, points: &[(Position, usize)], neighbours: &[Vec<usize>]) {
+        for (p, list) in self.particles.iter_mut().zip(neighbours) {
+            let sum: Magnitude = list
+                .iter()
+                .map(|&j| self.kernel.poly6((p.position - points[j].0).magnitude()))
+                .sum();
+            p.density = Density::new(self.mass.m() * sum);
+            let pressure = self.equation.calc_pressure(p.density, self.rest_density);
+            p.pressure = Pressure::new(pressure.m().max(0.));
+        }
+    }
+
+    /// Returns the acceleration of each particle, from the pressure,
+    /// viscosity and surface tension forces, and the gravity.
+    ///
+    /// The ghosts push with the pressure of their particles, and slip freely.
+    fn calc_accelerations(
+        &self,
+        points: &[(Position, usize)],
+        neighbours: &[Vec<usize>],
+        gravity: Acceleration,
+    ) -> Vec<V3> {
+        let (k, m, mu) = (&self.kernel, self.mass.m(), self.viscosity.m());
+        let (count, threshold) = (self.particles.len(), 0.1 / k.radius.m());
+        let mut accelerations = vec![gravity.d.vector(); count];
+        for (i, (pi, a)) in self.particles.iter().zip(&mut accelerations).enumerate() {
+            let (mut force, mut normal, mut curvature) = (V3::ZERO, V3::ZERO, 0.);
+            for &j in &neighbours[i] {
+                if i == j {
+                    continue;
+                }
+                let (position, source) = points[j];
+                let pj = &self.particles[source];
+                let r = pi.position - position;
+                let len = r.magnitude();
+                let volume = m / pj.density.m();
+
+                let pressure = (pi.pressure.m() + pj.pressure.m()) * 0.5 * volume;
+                force -= k.spiky_gradient(r).vector() * pressure;
+                if j < count {
+                    let dv = (pj.velocity.d - pi.velocity.d).vector();
+                    force += dv * (mu * volume * k.viscosity_laplacian(len));
+                }
+
+                // the gradient and laplacian of the color field
+                normal += k.poly6_gradient(r).vector() * volume;
+                curvature += k.poly6_laplacian(len) * volume;
+            }
+            let length = normal.length();
+            if self.surface_tension > 0. && length > threshold {
+                force -= normal * (self.surface_tension * curvature / length);
+            }
+            *a += force / pi.density.m();
+        }
+        accelerations
+    }
+
+    /// Moves the particles back inside the boundaries, a quarter of the
+    /// kernel radius away from the walls, and reflects their velocity.
+    fn enforce_boundaries(&mut self) {
+        let (e, margin) = (self.restitution, 0.25 * self.kernel.radius.m());
+        for wall in self.walls() {
+            for p in &mut self.particles {
+                let d = wall.calc_distance(p.position).m();
+                if d < margin {
+                    p.position += wall.normal * (margin - d);
+                    let vn = p.velocity.d.dot(wall.normal);
+                    if vn < 0. {
+                        p.velocity.d -= wall.normal * ((1. + e) * vn);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the kernels integrate to 1.
+    #[test]
+    fn sph_kernels() {
+        let h = 0.1;
+        for dimension in [SphDimension::Two, SphDimension::Three] {
+            let k = SphKernel::new(Length::new(h), dimension);
+            let (n, step) = (40_i32, h / 40.);
+            let depth = if dimension == SphDimension::Three {
+                n
+            } else {
+                0
+            };
+            let cell = step.powi(dimension.count() as i32);
+            let (mut poly6, mut spiky) = (0., 0.);
+            for i in -n..=n {
+                for j in -n..=n {
+                    for l in -depth..=depth {
+                        let r = V3::new(i.into(), j.into(), l.into()).length() * step;
+                        poly6 += k.poly6(r) * cell;
+                        spiky += k.spiky(r) * cell;
+                    }
+                }
+            }
+            assert_float_eq!(1., poly6, abs <= 0.01);
+            assert_float_eq!(1., spiky, abs <= 0.02);
+        }
+
+        // the gradients point towards the center, and vanish at the radius
+        let k = SphKernel::new(Length::new(h), SphDimension::Three);
+        let r = Direction::new(0.05, 0., 0.);
+        assert!(k.spiky_gradient(r).x() < 0.);
+        assert!(k.poly6_gradient(r).x() < 0.);
+        assert_float_eq!(0., k.spiky_gradient(Direction::ZERO).x(), abs <= 0.);
+        assert_float_eq!(0., k.viscosity_laplacian(h), abs <= 0.);
+        assert!(k.viscosity_laplacian(0.05) > 0.);
+
+        // the pressure rises with the density
+        let eos = EquationOfState::Tait {
+            speed_of_sound: Speed::new(20.),
+            exponent: 7.,
+        };
+        let rest = Density::new(1000.);
+        assert_float_eq!(0., eos.calc_pressure(rest, rest).m(), abs <= 0.);
+        assert!(eos.calc_pressure(Density::new(1010.), rest).m() > 0.);
+    }
+
+    /// Checks a 2D column of water collapsing in a tank.
+    #[test]
+    fn sph_dam_break() {
+        let spacing = Length::new(0.05);
+        let eos = EquationOfState::Linear {
+            speed_of_sound: Speed::new(20.),
+        };
+        let mut sph = SphSolver::with_spacing(SphDimension::Two, spacing, Density::new(1000.), eos);
+        sph.viscosity = DynamicViscosity::new(5.);
+        let tank = Aabb::new(Position::ZERO, Position::new(1.5, 2., 0.));
+        sph.boundaries.push(SphBoundary::Container(tank));
+        let column = Aabb::new(Position::new(0.025, 0.025, 0.), Position::new(0.5, 0.5, 0.));
+        sph.fill(&column, spacing);
+        assert_eq![100, sph.particles.len()];
+
+        // the lattice starts at the rest density
+        sph.update_densities();
+        let center = sph.particles[44].density.m();
+        assert_float_eq!(1000., center, rmax <= 1e-12);
+
+        let gravity = Acceleration::new(Direction::new(0., -9.8, 0.));
+        let dt = sph.calc_time_step();
+        assert_float_eq!(0.4 * 0.1 / 20., dt.m(), rmax <= 1e-12);
+        let substeps = sph.step(Time::new(0.1), gravity);
+        assert!(substeps >= 20);
+        for _ in 0..14 {
+            sph.step(Time::new(0.1), gravity);
+        }
+
+        // the water spreads along the floor, and stays in the tank
+        let front = sph
+            .particles
+            .iter()
+            .map(|p| p.position.x())
+            .fold(0., Magnitude::max);
+        assert!(front > 0.8);
+        for p in &sph.particles {
+            assert!(tank.contains_point(p.position));
+            assert_float_eq!(0., p.position.z(), abs <= 0.);
+        }
+        // and it's barely compressed
+        let densest = sph
+            .particles
+            .iter()
+            .map(|p| p.density.m())
+            .fold(0., Magnitude::max);
+        assert!(densest < 1100.);
+    }
+
+    /// Checks the particles are left as they are when the time step limit
+    /// isn't positive and finite.
+    #[test]
+    fn sph_invalid_step() {
+        let eos = EquationOfState::Linear {
+            speed_of_sound: Speed::new(20.),
+        };
+        let mut sph = SphSolver::with_spacing(
+            SphDimension::Two,
+            Length::new(0.05),
+            Density::new(1000.),
+            eos,
+        );
+        sph.add_particle(Position::ZERO, Velocity::new(Direction::ZERO));
+        let gravity = Acceleration::new(Direction::new(0., -9.8, 0.));
+
+        // a zero substep
+        sph.courant = 0.;
+        assert_eq![0, sph.step(Time::new(0.1), gravity)];
+        sph.courant = Magnitude::NAN;
+        assert_eq![0, sph.step(Time::new(0.1), gravity)];
+
+        // a non-finite substep
+        sph.courant = Magnitude::INFINITY;
+        assert_eq![0, sph.step(Time::new(0.1), gravity)];
+        sph.courant = 0.4;
+        assert_eq![0, sph.step(Time::new(Magnitude::INFINITY), gravity)];
+        sph.particles[0].velocity = Velocity::new(Direction::new(Magnitude::INFINITY, 0., 0.));
+        assert_eq![0, sph.step(Time::new(0.1), gravity)];
+        assert_eq![Position::ZERO, sph.particles[0].position];
+    }
+
+    /// Checks a 3D block of water falling onto a floor plane.
+    #[test]
+    fn sph_floor() {
+        let spacing = Length::new(0.05);
+        let eos = EquationOfState::Tait {
+            speed_of_sound: Speed::new(20.),
+            exponent: 7.,
+        };
+        let mut sph =
+            SphSolver::with_spacing(SphDimension::Three, spacing, Density::new(1000.), eos);
+        sph.viscosity = DynamicViscosity::new(5.);
+        sph.surface_tension = 0.07;
+        sph.restitution = 0.2;
+        let floor = Plane::new(Direction::new(0., 1., 0.), Length::new(0.));
+        sph.boundaries.push(SphBoundary::Plane(floor));
+        let block = Aabb::new(Position::new(0., 0.2, 0.), Position::new(0.2, 0.4, 0.2));
+        sph.fill(&block, spacing);
+        assert_eq![125, sph.particles.len()];
+
+        let gravity = Acceleration::new(Direction::new(0., -9.8, 0.));
+        for _ in 0..5 {
+            sph.step(Time::new(0.1), gravity);
+        }
+        let lowest = sph
+            .particles
+            .iter()
+            .map(|p| p.position.y())
+            .fold(Magnitude::INFINITY, Magnitude::min);
+        assert!((0. ..0.05).contains(&lowest));
+        assert!(sph.particles.iter().all(|p| p.position.y() >= 0.));
+    }
+}
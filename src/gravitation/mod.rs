@@ -0,0 +1,332 @@
+// fisica::gravitation
+//
+//! Newtonian gravitation
+//!
+//! Every [`Mass`] attracts every other mass with a [`Force`][crate::units::Force]
+//! proportional to both masses and inversely proportional to the square of
+//! their distance (see [`Force::from_gravitation`][crate::units::Force::from_gravitation]
+//! and [`Gfs::from_masses`]).
+//!
+//! ## N-body simulation
+//!
+//! An [`NBody`] system evolves a set of [`PointMass`]es under their mutual
+//! attraction, summing the accelerations either:
+//! - directly over every pair, in `O(n²)`.
+//! - with the [*Barnes–Hut*][0] approximation in `O(n log n)`, which groups
+//!   the distant masses in the cells of an octree.
+//!
+//! A *softening* [`Length`] `ε` limits the acceleration of close encounters,
+//! using `r² + ε²` instead of `r²`. The total [`Energy`] and [`Momentum`] of
+//! the system can be monitored to estimate the accumulated error.
+//!
+//! [0]:https://en.wikipedia.org/wiki/Barnes%E2%80%93Hut_simulation
+//!
+//! # External links
+//! - <https://en.wikipedia.org/wiki/Newton%27s_law_of_universal_gravitation>
+//! - <https://en.wikipedia.org/wiki/N-body_simulation>
+//
+
+use alloc::vec::Vec;
+
+use crate::constants::GRAVITATIONAL_CONSTANT as G;
+use crate::math::V3;
+use crate::units::{Acceleration, Energy, Gfs, Length, Mass, Momentum, Time, Velocity};
+use crate::{Direction, Magnitude, Position};
+
+mod octree;
+
+use octree::Octree;
+
+/// A point [`Mass`] moving freely.
+#[derive(Clone, Copy, Debug)]
+pub struct PointMass {
+    pub mass: Mass,
+    pub position: Position,
+    pub velocity: Velocity,
+}
+
+impl PointMass {
+    /// New PointMass.
+    #[inline]
+    pub const fn new(mass: Mass, position: Position, velocity: Velocity) -> Self {
+        Self {
+            mass,
+            position,
+            velocity,
+        }
+    }
+
+    /// Returns the linear [`Momentum`] (`p = m v`).
+    pub fn momentum(&self) -> Momentum {
+        Momentum::new(self.velocity.d * self.mass.m())
+    }
+
+    /// Returns the kinetic [`Energy`] (`K = ½ m v²`).
+    pub fn kinetic_energy(&self) -> Energy {
+        Energy::new(0.5 * self.mass.m() * self.velocity.d.magnitude_squared())
+    }
+}
+
+/// The method used to sum the accelerations of an [`NBody`] system.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NBodyMethod {
+    /// Sums the attraction of every pair of masses, in `O(n²)`.
+    Direct,
+    /// Approximates the masses of a distant cell by their center of mass,
+    /// when the ratio between the cell size and its distance is smaller than
+    /// the opening angle `theta`, in `O(n log n)`.
+    ///
+    /// A `theta` of `0` is exact, and `0.5` is a common compromise.
+    BarnesHut { theta: Magnitude },
+}
+
+/// A system of [`PointMass`]es under their mutual gravitational attraction.
+#[derive(Clone, Debug)]
+pub struct NBody {
+    pub bodies: Vec<PointMass>,
+    pub method: NBodyMethod,
+    /// The softening length `ε`.
+    pub softening: Length,
+}
+
+impl NBody {
+    /// New NBody.
+    pub fn new(bodies: Vec<PointMass>, method: NBodyMethod, softening: Length) -> Self {
+        Self {
+            bodies,
+            method,
+            softening,
+        }
+    }
+
+    /// Returns the gravitational [`Acceleration`] of each body.
+    pub fn accelerations(&self) -> Vec<Acceleration> {
+        let eps2 = self.softening.m() * self.softening.m();
+        let accelerations: Vec<V3> = match self.method {
+            NBodyMethod::Direct => {
+                let mut acc = alloc::vec![V3::ZERO; self.bodies.len()];
+                for (i, a) in self.bodies.iter().enumerate() {
+                    for (j, b) in self.bodies.iter().enumerate().skip(i + 1) {
+                        let f = calc_pull((b.position - a.position).vector(), eps2);
+                        acc[i] += f * b.mass.m();
+                        acc[j] -= f * a.mass.m();
+                    }
+                }
+                acc
+            }
+            NBodyMethod::BarnesHut { theta } => {
+                let tree = Octree::new(&self.bodies);
+                (0..self.bodies.len())
+                    .map(|i| tree.calc_acceleration(&self.bodies, i, theta, eps2))
+                    .collect()
+            }
+        };
+        accelerations
+            .into_iter()
+            .map(|a| Acceleration::new(Direction::from_vector(a)))
+            .collect()
+    }
+
+    /// Advances the system in [`Time`], using the symplectic leapfrog
+    /// (kick-drift-kick) integrator, which keeps the energy error bounded.
+    pub fn step(&mut self, dt: Time) {
+        let half = dt.m() / 2.;
+        let accelerations = self.accelerations();
+        for (b, a) in self.bodies.iter_mut().zip(accelerations) {
+            b.velocity.d += a.d * half;
+            b.position += b.velocity.d * dt.m();
+        }
+        let accelerations = self.accelerations();
+        for (b, a) in self.bodies.iter_mut().zip(accelerations) {
+            b.velocity.d += a.d * half;
+        }
+    }
+
+    /// Returns the total kinetic [`Energy`].
+    pub fn kinetic_energy(&self) -> Energy {
+        Energy::new(self.bodies.iter().map(|b| b.kinetic_energy().m()).sum())
+    }
+
+    /// Returns the total gravitational potential [`Energy`], summed exactly
+    /// over every pair (`U = -Σ G mᵢ mⱼ / √(r² + ε²)`).
+    pub fn potential_energy(&self) -> Energy {
+        let eps2 = self.softening.m() * self.softening.m();
+        let mut u = 0.;
+        for (i, a) in self.bodies.iter().enumerate() {
+            for b in &self.bodies[i + 1..] {
+                let r2 = (b.position - a.position).magnitude_squared();
+                u -= G * a.mass.m() * b.mass.m() / (r2 + eps2).sqrt();
+            }
+        }
+        Energy::new(u)
+    }
+
+    /// Returns the total mechanical [`Energy`], kinetic plus potential.
+    pub fn total_energy(&self) -> Energy {
+        Energy::new(self.kinetic_energy().m() + self.potential_energy().m())
+    }
+
+    /// Returns the total linear [`Momentum`].
+    pub fn momentum(&self) -> Momentum {
+        Momentum::new(
+            self.bodies
+                .iter()
+                .fold(Direction::ZERO, |p, b| p + b.momentum().d),
+        )
+    }
+
+    /// Returns the relative drift of the total energy from an `initial` one
+    /// (`|E - E₀| / |E₀|`).
+    ///
+    /// When the initial energy is nearly zero compared to its kinetic and
+    /// potential parts, as in a marginally bound system, it returns the
+    /// absolute drift in joules instead (`|E - E₀|`).
+    pub fn calc_energy_drift(&self, initial: Energy) -> Magnitude {
+        let (k, u) = (self.kinetic_energy().m(), self.potential_energy().m());
+        let drift = (k + u - initial.m()).abs();
+        if initial.m().abs() <= Magnitude::EPSILON * (k + u.abs()) {
+            drift
+        } else {
+            drift / initial.m().abs()
+        }
+    }
+
+    /// Returns the [`Gfs`] at the given [`Position`] due to all the bodies.
+    pub fn calc_gfs(&self, at: Position) -> Gfs {
+        let eps2 = self.softening.m() * self.softening.m();
+        Gfs::new(Direction::from_vector(
+            self.bodies.iter().fold(V3::ZERO, |g, b| {
+                g + calc_pull((b.position - at).vector(), eps2) * b.mass.m()
+            }),
+        ))
+    }
+}
+
+/// Returns the acceleration towards a unit mass at the offset `r`,
+/// with the squared softening length `eps2` (`G r / (r² + ε²)^(3/2)`).
+fn calc_pull(r: V3, eps2: Magnitude) -> V3 {
+    let d2 = r.length_squared() + eps2;
+    if d2 == 0. {
+        return V3::ZERO;
+    }
+    r * (G / (d2 * d2.sqrt()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::Force;
+    use float_eq::assert_float_eq;
+
+    /// Checks the universal gravitation formulas.
+    #[test]
+    fn gravitation_formulas() {
+        let earth = (Mass::new(5.972e24), Position::ZERO);
+        let surface = Position::new(0., 6.371e6, 0.);
+        let g = Gfs::from_mass_position(earth.0, earth.1, surface);
+        assert_float_eq!(9.82, g.m(), abs <= 0.005);
+        assert!(g.d.y() < 0.);
+
+        let f = Force::from_gravitation(Mass::new(1.), surface, earth.0, earth.1);
+        assert_float_eq!(g.m(), f.m(), r2nd <= Magnitude::EPSILON);
+
+        // between two equal masses the field cancels out
+        let g = Gfs::from_masses(&[earth, (earth.0, surface * 2.)], surface);
+        assert_float_eq!(0., g.m(), abs <= 1e-12);
+    }
+
+    /// A deterministic cloud of bodies.
+    fn cloud(n: usize) -> Vec<PointMass> {
+        let mut seed = 12345_u64;
+        let mut rand = move || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 11) as Magnitude / (1_u64 << 53) as Magnitude - 0.5
+        };
+        (0..n)
+            .map(|_| {
+                PointMass::new(
+                    Mass::new(1e10 * (1. + rand())),
+                    Position::new(rand(), rand(), rand()) * 100.,
+                    Velocity::new(Direction::ZERO),
+                )
+            })
+            .collect()
+    }
+
+    /// Checks Barnes–Hut approximates the direct summation.
+    #[test]
+    fn nbody_barnes_hut() {
+        let direct = NBody::new(cloud(200), NBodyMethod::Direct, Length::new(1.));
+        let mut approx = direct.clone();
+        approx.method = NBodyMethod::BarnesHut { theta: 0. };
+        for (a, b) in direct.accelerations().iter().zip(approx.accelerations()) {
+            assert_float_eq!(0., (a.d - b.d).magnitude(), abs <= 1e-9 * a.m());
+        }
+        // the mean error is small compared to the mean acceleration
+        approx.method = NBodyMethod::BarnesHut { theta: 0.5 };
+        let (mut error, mut total) = (0., 0.);
+        for (a, b) in direct.accelerations().iter().zip(approx.accelerations()) {
+            error += (a.d - b.d).magnitude_squared();
+            total += a.d.magnitude_squared();
+        }
+        assert!((error / total).sqrt() < 0.01);
+    }
+
+    /// Checks a circular orbit conserves energy and momentum.
+    #[test]
+    fn nbody_orbit() {
+        let (m_sun, r) = (1.989e30, 1.496e11);
+        let v = (G * m_sun / r).sqrt();
+        let bodies = alloc::vec![
+            PointMass::new(
+                Mass::new(m_sun),
+                Position::ZERO,
+                Velocity::new(Direction::ZERO)
+            ),
+            PointMass::new(
+                Mass::new(5.972e24),
+                Position::new(r, 0., 0.),
+                Velocity::new(Direction::new(0., v, 0.)),
+            ),
+        ];
+        let mut system = NBody::new(bodies, NBodyMethod::Direct, Length::new(0.));
+        let (e0, p0) = (system.total_energy(), system.momentum());
+        let dt = Time::new(3_600.);
+        for _ in 0..(365 * 24) {
+            system.step(dt);
+        }
+        assert!(system.calc_energy_drift(e0) < 1e-6);
+        assert_float_eq!(0., (system.momentum().d - p0.d).magnitude(), abs <= 1e18);
+        // back near the starting point after a year
+        let p = system.bodies[1].position - system.bodies[0].position;
+        assert!((p - Position::new(r, 0., 0.)).magnitude() < 0.01 * r);
+    }
+
+    /// Checks the energy drift of a marginally bound system is absolute.
+    #[test]
+    fn nbody_energy_drift() {
+        // two equal masses on a parabolic escape, with zero total energy
+        let m = 1e10;
+        let v = (G * m / 2.).sqrt();
+        let body = |x: Magnitude, vy: Magnitude| {
+            PointMass::new(
+                Mass::new(m),
+                Position::new(x, 0., 0.),
+                Velocity::new(Direction::new(0., vy, 0.)),
+            )
+        };
+        let system = NBody::new(
+            alloc::vec![body(-1., -v), body(1., v)],
+            NBodyMethod::Direct,
+            Length::new(0.),
+        );
+        let e0 = system.total_energy();
+        assert_float_eq!(0., e0.m(), abs <= 1e-6);
+        let drift = system.calc_energy_drift(Energy::new(0.));
+        assert!(drift.is_finite());
+        assert_float_eq!(e0.m().abs(), drift, abs <= 0.);
+        // relative to any other energy
+        let k = system.kinetic_energy();
+        assert_float_eq!(1., system.calc_energy_drift(k), r2nd <= 1e-9);
+    }
+}
@@ -0,0 +1,136 @@
+// fisica::gravitation::octree
+//
+// Barnes–Hut octree.
+
+use alloc::vec::Vec;
+
+use super::{calc_pull, PointMass};
+use crate::math::V3;
+use crate::Magnitude;
+
+/// The maximum depth, which bounds the tree when several bodies coincide.
+const MAX_DEPTH: usize = 32;
+
+/// A cubic cell of the octree.
+struct Cell {
+    center: V3,
+    half: Magnitude,
+    /// The total mass inside the cell.
+    mass: Magnitude,
+    /// The center of mass of the cell.
+    com: V3,
+    /// The indices of the child cells, empty for leaves.
+    children: Vec<usize>,
+    /// The indices of the bodies of a leaf.
+    bodies: Vec<usize>,
+}
+
+/// An octree summarizing the mass distribution of a set of bodies.
+pub(super) struct Octree {
+    cells: Vec<Cell>,
+}
+
+impl Octree {
+    /// Builds the octree of the given bodies.
+    pub(super) fn new(bodies: &[PointMass]) -> Self {
+        let mut tree = Self { cells: Vec::new() };
+        if bodies.is_empty() {
+            return tree;
+        }
+        let (lo, hi) = bodies.iter().fold(
+            (
+                V3::splat(Magnitude::INFINITY),
+                V3::splat(Magnitude::NEG_INFINITY),
+            ),
+            |(lo, hi), b| (lo.min(b.position.vector()), hi.max(b.position.vector())),
+        );
+        let half = ((hi - lo).max_element() / 2.).max(Magnitude::EPSILON);
+        let indices: Vec<usize> = (0..bodies.len()).collect();
+        tree.build(bodies, indices, (lo + hi) / 2., half, 0);
+        tree
+    }
+
+    /// Builds the cell containing the bodies at `indices`, returning its index.
+    fn build(
+        &mut self,
+        bodies: &[PointMass],
+        indices: Vec<usize>,
+        center: V3,
+        half: Magnitude,
+        depth: usize,
+    ) -> usize {
+        let (mass, moment) = indices.iter().fold((0., V3::ZERO), |(m, mr), i| {
+            let b = &bodies[*i];
+            (m + b.mass.m(), mr + b.position.vector() * b.mass.m())
+        });
+        let com = if mass > 0. { moment / mass } else { center };
+        let index = self.cells.len();
+        self.cells.push(Cell {
+            center,
+            half,
+            mass,
+            com,
+            children: Vec::new(),
+            bodies: Vec::new(),
+        });
+
+        if indices.len() <= 1 || depth >= MAX_DEPTH {
+            self.cells[index].bodies = indices;
+            return index;
+        }
+        let mut octants: [Vec<usize>; 8] = Default::default();
+        for i in indices {
+            let p = bodies[i].position.vector();
+            let octant = usize::from(p.x >= center.x)
+                | usize::from(p.y >= center.y) << 1
+                | usize::from(p.z >= center.z) << 2;
+            octants[octant].push(i);
+        }
+        for (octant, children) in octants.into_iter().enumerate() {
+            if children.is_empty() {
+                continue;
+            }
+            let sign = |bit: usize| if octant & bit != 0 { 1. } else { -1. };
+            let offset = V3::new(sign(1), sign(2), sign(4)) * (half / 2.);
+            let child = self.build(bodies, children, center + offset, half / 2., depth + 1);
+            self.cells[index].children.push(child);
+        }
+        index
+    }
+
+    /// Returns the acceleration of the body `target`, approximating the cells
+    /// seen under an angle smaller than `theta`.
+    pub(super) fn calc_acceleration(
+        &self,
+        bodies: &[PointMass],
+        target: usize,
+        theta: Magnitude,
+        eps2: Magnitude,
+    ) -> V3 {
+        let p = bodies[target].position.vector();
+        let mut acc = V3::ZERO;
+        let mut stack: Vec<usize> = if self.cells.is_empty() {
+            Vec::new()
+        } else {
+            alloc::vec![0]
+        };
+        while let Some(c) = stack.pop() {
+            let cell = &self.cells[c];
+            if cell.children.is_empty() {
+                for &i in cell.bodies.iter().filter(|i| **i != target) {
+                    let b = &bodies[i];
+                    acc += calc_pull(b.position.vector() - p, eps2) * b.mass.m();
+                }
+                continue;
+            }
+            let r = cell.com - p;
+            let inside = (p - cell.center).abs().max_element() <= cell.half;
+            if !inside && 2. * cell.half < theta * r.length() {
+                acc += calc_pull(r, eps2) * cell.mass;
+            } else {
+                stack.extend(&cell.children);
+            }
+        }
+        acc
+    }
+}
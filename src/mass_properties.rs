@@ -0,0 +1,432 @@
+// fisica::mass_properties
+//
+//! Mass properties of rigid bodies.
+//!
+//! The [`MassProperties`] of a body are its total [`Mass`], the [`Position`]
+//! of its center of mass, and its [`InertiaTensor`] about that center.
+//!
+//! They can be computed for primitive shapes and closed triangle meshes,
+//! moved and rotated, and combined into compound bodies.
+//!
+//! Axially symmetric shapes (cylinders, capsules and cones) are aligned with
+//! the local Y axis.
+//!
+//! # External links
+//! - <https://en.wikipedia.org/wiki/List_of_moments_of_inertia>
+//! - <https://en.wikipedia.org/wiki/Parallel_axis_theorem>
+//
+
+use core::{f64::consts::PI, ops::Add};
+
+use crate::math::Matrix;
+use crate::units::{Density, InertiaTensor, Length, Mass, MomentOfInertia, Volume};
+use crate::{Direction, Magnitude, Orientation, Position};
+
+/// The source of the [`Mass`] of a shape: either its [`Density`],
+/// or its total `Mass`.
+///
+/// Both quantities convert into it, so they can be passed directly to
+/// the [`MassProperties`] constructors.
+#[derive(Clone, Copy, Debug)]
+pub enum MassSource {
+    /// A uniform [`Density`] over the volume of the shape.
+    Density(Density),
+    /// The total [`Mass`] of the shape, uniformly distributed.
+    Mass(Mass),
+}
+
+impl MassSource {
+    /// Returns the [`Mass`] of a shape with the given [`Volume`].
+    pub fn calc_mass(&self, v: Volume) -> Mass {
+        match self {
+            MassSource::Density(d) => Mass::new(d.m() * v.m()),
+            MassSource::Mass(m) => *m,
+        }
+    }
+}
+
+impl From<Density> for MassSource {
+    fn from(d: Density) -> Self {
+        MassSource::Density(d)
+    }
+}
+impl From<Mass> for MassSource {
+    fn from(m: Mass) -> Self {
+        MassSource::Mass(m)
+    }
+}
+
+/// The [`Mass`], center of mass and [`InertiaTensor`] of a rigid body.
+///
+/// The `inertia` is expressed about the `center` of mass,
+/// with axes parallel to the ones of the body.
+#[derive(Clone, Copy, Debug)]
+pub struct MassProperties {
+    pub mass: Mass,
+    pub center: Position,
+    pub inertia: InertiaTensor,
+}
+
+impl MassProperties {
+    /// New `MassProperties`.
+    #[inline]
+    pub const fn new(mass: Mass, center: Position, inertia: InertiaTensor) -> Self {
+        Self {
+            mass,
+            center,
+            inertia,
+        }
+    }
+
+    /// No mass.
+    pub const ZERO: Self = Self::new(Mass::new(0.), Position::ZERO, InertiaTensor::ZERO);
+
+    /// New `MassProperties` of a point [`Mass`] at the given [`Position`].
+    pub fn point(mass: Mass, center: Position) -> Self {
+        Self::new(mass, center, InertiaTensor::ZERO)
+    }
+}
+
+/// # Primitive shapes
+impl MassProperties {
+    /// Returns the mass properties of a solid sphere centered at the origin.
+    ///
+    /// `I = ⅖ m r²`
+    pub fn sphere(radius: Length, source: impl Into<MassSource>) -> Self {
+        let r = radius.m();
+        let mass = source
+            .into()
+            .calc_mass(Volume::new(4. / 3. * PI * r * r * r));
+        let i = MomentOfInertia::new(2. / 5. * mass.m() * r * r);
+        Self::new(mass, Position::ZERO, InertiaTensor::from_principal(i, i, i))
+    }
+
+    /// Returns the mass properties of a hollow sphere with the given `outer`
+    /// and `inner` radii, centered at the origin.
+    ///
+    /// `I = ⅖ m (R⁵ - r⁵) / (R³ - r³)`
+    ///
+    /// When both radii are equal it's considered a thin spherical shell
+    /// (`I = ⅔ m R²`), which has no volume, so its `Mass` must be provided.
+    pub fn hollow_sphere(outer: Length, inner: Length, source: impl Into<MassSource>) -> Self {
+        let (ro, ri) = (outer.m(), inner.m());
+        let (ro3, ri3) = (ro * ro * ro, ri * ri * ri);
+        let mass = source
+            .into()
+            .calc_mass(Volume::new(4. / 3. * PI * (ro3 - ri3)));
+        let i = if (ro - ri).abs() <= Magnitude::EPSILON * ro {
+            2. / 3. * mass.m() * ro * ro
+        } else {
+            2. / 5. * mass.m() * (ro3 * ro * ro - ri3 * ri * ri) / (ro3 - ri3)
+        };
+        let i = MomentOfInertia::new(i);
+        Self::new(mass, Position::ZERO, InertiaTensor::from_principal(i, i, i))
+    }
+
+    /// Returns the mass properties of a solid rectangular cuboid with the
+    /// given side lengths along the X, Y and Z axes, centered at the origin.
+    ///
+    /// `Ix = m (y² + z²) / 12`
+    pub fn cuboid(x: Length, y: Length, z: Length, source: impl Into<MassSource>) -> Self {
+        let (x, y, z) = (x.m(), y.m(), z.m());
+        let mass = source.into().calc_mass(Volume::new(x * y * z));
+        let k = mass.m() / 12.;
+        Self::new(
+            mass,
+            Position::ZERO,
+            InertiaTensor::from_principal(
+                MomentOfInertia::new(k * (y * y + z * z)),
+                MomentOfInertia::new(k * (x * x + z * z)),
+                MomentOfInertia::new(k * (x * x + y * y)),
+            ),
+        )
+    }
+
+    /// Returns the mass properties of a solid cylinder along the Y axis,
+    /// centered at the origin.
+    ///
+    /// `Iy = ½ m r²`, `Ix = Iz = m (3r² + h²) / 12`
+    pub fn cylinder(radius: Length, height: Length, source: impl Into<MassSource>) -> Self {
+        let (r, h) = (radius.m(), height.m());
+        let mass = source.into().calc_mass(Volume::new(PI * r * r * h));
+        let ix = MomentOfInertia::new(mass.m() * (3. * r * r + h * h) / 12.);
+        let iy = MomentOfInertia::new(mass.m() * r * r / 2.);
+        Self::new(
+            mass,
+            Position::ZERO,
+            InertiaTensor::from_principal(ix, iy, ix),
+        )
+    }
+
+    /// Returns the mass properties of a solid capsule along the Y axis,
+    /// centered at the origin.
+    ///
+    /// The `height` is the length of the cylindrical section, excluding the
+    /// two hemispherical caps of the given `radius`.
+    pub fn capsule(radius: Length, height: Length, source: impl Into<MassSource>) -> Self {
+        let (r, h) = (radius.m(), height.m());
+        let v_cyl = PI * r * r * h;
+        let v_hemi = 2. / 3. * PI * r * r * r;
+        let mass = source.into().calc_mass(Volume::new(v_cyl + 2. * v_hemi));
+        let (m_cyl, m_hemi) = if mass.m() == 0. {
+            (0., 0.)
+        } else {
+            let k = mass.m() / (v_cyl + 2. * v_hemi);
+            (k * v_cyl, k * v_hemi)
+        };
+        // each cap, about its own center of mass (83/320 m r²), translated
+        // by the distance to the capsule center (h/2 + 3r/8)
+        let ix = m_cyl * (3. * r * r + h * h) / 12.
+            + 2. * m_hemi * (2. / 5. * r * r + h * h / 4. + 3. / 8. * h * r);
+        let iy = m_cyl * r * r / 2. + 2. * m_hemi * 2. / 5. * r * r;
+        let (ix, iy) = (MomentOfInertia::new(ix), MomentOfInertia::new(iy));
+        Self::new(
+            mass,
+            Position::ZERO,
+            InertiaTensor::from_principal(ix, iy, ix),
+        )
+    }
+
+    /// Returns the mass properties of a solid cone along the Y axis, with the
+    /// center of its base at the origin and its apex at `height`.
+    ///
+    /// The center of mass lies at `h / 4` over the base.
+    ///
+    /// `Iy = 3/10 m r²`, `Ix = Iz = m (3/20 r² + 3/80 h²)`
+    pub fn cone(radius: Length, height: Length, source: impl Into<MassSource>) -> Self {
+        let (r, h) = (radius.m(), height.m());
+        let mass = source.into().calc_mass(Volume::new(PI * r * r * h / 3.));
+        let ix = MomentOfInertia::new(mass.m() * (3. / 20. * r * r + 3. / 80. * h * h));
+        let iy = MomentOfInertia::new(mass.m() * 3. / 10. * r * r);
+        Self::new(
+            mass,
+            Position::new(0., h / 4., 0.),
+            InertiaTensor::from_principal(ix, iy, ix),
+        )
+    }
+
+    /// Returns the mass properties of the solid enclosed by a closed triangle
+    /// mesh.
+    ///
+    /// Each triangle indexes 3 `vertices`, which should be wound consistently.
+    /// The winding may be either clockwise or counterclockwise.
+    ///
+    /// The mesh is decomposed into tetrahedra having the origin as their
+    /// common apex, whose signed volumes and covariances are accumulated.
+    ///
+    /// # Panics
+    /// Panics if any index is out of bounds.
+    ///
+    /// # External links
+    /// - <https://doi.org/10.1080/2151237X.2006.10129220>
+    pub fn triangle_mesh(
+        vertices: &[Position],
+        triangles: &[[usize; 3]],
+        source: impl Into<MassSource>,
+    ) -> Self {
+        // covariance of the canonical tetrahedron
+        let canonical = Matrix::from_cols_array(&[
+            2., 1., 1., //
+            1., 2., 1., //
+            1., 1., 2.,
+        ]) * (1. / 120.);
+
+        let mut volume = 0.;
+        let mut first_moment = glam::DVec3::ZERO;
+        let mut covariance = Matrix::ZERO;
+        for [a, b, c] in triangles.iter().copied() {
+            let (a, b, c) = (
+                vertices[a].vector(),
+                vertices[b].vector(),
+                vertices[c].vector(),
+            );
+            let basis = Matrix::from_cols(a, b, c);
+            let det = basis.determinant();
+            volume += det / 6.;
+            first_moment += (a + b + c) * det / 24.;
+            covariance += basis * canonical * basis.transpose() * det;
+        }
+        if volume < 0. {
+            volume = -volume;
+            first_moment = -first_moment;
+            covariance = -covariance;
+        }
+        if volume <= Magnitude::EPSILON {
+            return Self::ZERO;
+        }
+
+        let mass = source.into().calc_mass(Volume::new(volume));
+        let density = mass.m() / volume;
+        let center = first_moment / volume;
+
+        // move the covariance to the center of mass, and convert it to inertia
+        let covariance = (covariance * density)
+            - Matrix::from_cols(center * center.x, center * center.y, center * center.z) * mass.m();
+        let trace = covariance.x_axis.x + covariance.y_axis.y + covariance.z_axis.z;
+        let inertia = Matrix::IDENTITY * trace - covariance;
+
+        Self::new(
+            mass,
+            Position::from_vector(center),
+            InertiaTensor::new(inertia),
+        )
+    }
+}
+
+/// # Transformations and composition
+impl MassProperties {
+    /// Returns the mass properties after rotating the body about its local
+    /// origin by the given [`Orientation`], and then moving it by `offset`.
+    pub fn transformed(&self, offset: Direction, rotation: Orientation) -> Self {
+        Self::new(
+            self.mass,
+            Position::from_vector(rotation * self.center.vector()) + offset,
+            self.inertia.rotated(rotation),
+        )
+    }
+
+    /// Returns the [`InertiaTensor`] about the given `point`,
+    /// using the [*parallel axis theorem*][0].
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Parallel_axis_theorem
+    pub fn calc_inertia_about(&self, point: Position) -> InertiaTensor {
+        self.inertia.translated(self.mass, self.center - point)
+    }
+
+    /// Combines several bodies into a single compound body.
+    ///
+    /// The parts must be expressed in the same frame of reference
+    /// (see [`transformed`][Self::transformed]).
+    pub fn compound(parts: &[MassProperties]) -> Self {
+        let mass: Magnitude = parts.iter().map(|p| p.mass.m()).sum();
+        if mass <= 0. {
+            return Self::ZERO;
+        }
+        let center = parts
+            .iter()
+            .fold(Position::ZERO, |acc, p| acc + p.center * p.mass.m())
+            / mass;
+        let inertia = parts.iter().fold(InertiaTensor::ZERO, |acc, p| {
+            acc + p.calc_inertia_about(center)
+        });
+        Self::new(Mass::new(mass), center, inertia)
+    }
+}
+
+impl Add for MassProperties {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::compound(&[self, other])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the primitive shapes behave as expected.
+    #[test]
+    fn mass_properties_shapes() {
+        let sphere = MassProperties::sphere(Length::new(2.), Mass::new(5.));
+        assert_float_eq!(5., sphere.mass.m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(8., sphere.inertia.diagonal()[0].m(), abs <= 1e-12);
+
+        let sphere = MassProperties::sphere(Length::new(1.), Density::new(1000.));
+        assert_float_eq!(4188.790204786391, sphere.mass.m(), abs <= 1e-9);
+
+        let shell = MassProperties::hollow_sphere(Length::new(3.), Length::new(3.), Mass::new(2.));
+        assert_float_eq!(12., shell.inertia.diagonal()[1].m(), abs <= 1e-12);
+
+        let cuboid = MassProperties::cuboid(
+            Length::new(1.),
+            Length::new(2.),
+            Length::new(3.),
+            Mass::new(12.),
+        );
+        let [x, y, z] = cuboid.inertia.diagonal();
+        assert_float_eq!(13., x.m(), abs <= 1e-12);
+        assert_float_eq!(10., y.m(), abs <= 1e-12);
+        assert_float_eq!(5., z.m(), abs <= 1e-12);
+
+        // a capsule without cylindrical section is a sphere
+        let capsule = MassProperties::capsule(Length::new(2.), Length::new(0.), Mass::new(5.));
+        assert_float_eq!(8., capsule.inertia.diagonal()[0].m(), abs <= 1e-12);
+        assert_float_eq!(8., capsule.inertia.diagonal()[1].m(), abs <= 1e-12);
+
+        let cone = MassProperties::cone(Length::new(1.), Length::new(4.), Mass::new(10.));
+        assert_float_eq!(1., cone.center.y(), abs <= 1e-12);
+        assert_float_eq!(3., cone.inertia.diagonal()[1].m(), abs <= 1e-12);
+    }
+
+    /// Checks a triangle mesh matches the equivalent primitive.
+    #[test]
+    fn mass_properties_mesh() {
+        let vertices = [
+            Position::new(0., 0., 0.),
+            Position::new(1., 0., 0.),
+            Position::new(1., 1., 0.),
+            Position::new(0., 1., 0.),
+            Position::new(0., 0., 1.),
+            Position::new(1., 0., 1.),
+            Position::new(1., 1., 1.),
+            Position::new(0., 1., 1.),
+        ];
+        let triangles = [
+            [0, 2, 1],
+            [0, 3, 2],
+            [4, 5, 6],
+            [4, 6, 7],
+            [0, 1, 5],
+            [0, 5, 4],
+            [3, 7, 6],
+            [3, 6, 2],
+            [0, 4, 7],
+            [0, 7, 3],
+            [1, 2, 6],
+            [1, 6, 5],
+        ];
+        let mesh = MassProperties::triangle_mesh(&vertices, &triangles, Density::new(6.));
+        let cuboid = MassProperties::cuboid(
+            Length::new(1.),
+            Length::new(1.),
+            Length::new(1.),
+            Mass::new(6.),
+        );
+        assert_float_eq!(6., mesh.mass.m(), abs <= 1e-12);
+        assert_float_eq!(0.5, mesh.center.x(), abs <= 1e-12);
+        assert_float_eq!(0.5, mesh.center.z(), abs <= 1e-12);
+        assert!(mesh.inertia.t.abs_diff_eq(cuboid.inertia.t, 1e-12));
+    }
+
+    /// Checks the composition of bodies behaves as expected.
+    #[test]
+    fn mass_properties_compound() {
+        let a = MassProperties::point(Mass::new(1.), Position::new(-1., 0., 0.));
+        let b = MassProperties::point(Mass::new(3.), Position::new(1., 0., 0.));
+        let c = a + b;
+        assert_float_eq!(4., c.mass.m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(0.5, c.center.x(), abs <= 1e-12);
+        // 1 × 1.5² + 3 × 0.5²
+        assert_float_eq!(3., c.inertia.diagonal()[1].m(), abs <= 1e-12);
+        assert_float_eq!(0., c.inertia.diagonal()[0].m(), abs <= 1e-12);
+
+        // two half cuboids make a whole one
+        let half = MassProperties::cuboid(
+            Length::new(1.),
+            Length::new(1.),
+            Length::new(1.),
+            Mass::new(1.),
+        );
+        let whole = MassProperties::compound(&[
+            half.transformed(Direction::new(-0.5, 0., 0.), Orientation::IDENTITY),
+            half.transformed(Direction::new(0.5, 0., 0.), Orientation::IDENTITY),
+        ]);
+        let expected = MassProperties::cuboid(
+            Length::new(2.),
+            Length::new(1.),
+            Length::new(1.),
+            Mass::new(2.),
+        );
+        assert!(whole.inertia.t.abs_diff_eq(expected.inertia.t, 1e-12));
+    }
+}
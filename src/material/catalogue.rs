@@ -0,0 +1,97 @@
+// fisica::material::catalogue
+//
+// Common materials, and the friction between them.
+//
+// Typical values at room temperature, for dry and clean surfaces.
+// - <https://www.engineeringtoolbox.com/friction-coefficients-d_778.html>
+// - <https://www.engineeringtoolbox.com/young-modulus-d_417.html>
+// - <https://en.wikipedia.org/wiki/Table_of_specific_heat_capacities>
+// - <https://en.wikipedia.org/wiki/List_of_thermal_conductivities>
+
+use super::{Friction, Material};
+use crate::units::{Density, Pressure, SpecificHeatCapacity, ThermalConductivity};
+use crate::Magnitude;
+
+/// Gigapascals to pascals.
+const GPA: Magnitude = 1e9;
+
+const fn material(
+    name: &'static str,
+    density: Magnitude,
+    young_modulus: Magnitude,
+    restitution: Magnitude,
+    specific_heat: Magnitude,
+    thermal_conductivity: Magnitude,
+) -> Material {
+    Material {
+        name,
+        density: Density::new(density),
+        young_modulus: Pressure::new(young_modulus),
+        restitution,
+        specific_heat: SpecificHeatCapacity::new(specific_heat),
+        thermal_conductivity: ThermalConductivity::new(thermal_conductivity),
+    }
+}
+
+/// # Metals
+impl Material {
+    /// Structural [steel](https://en.wikipedia.org/wiki/Steel).
+    pub const STEEL: Self = material("Steel", 7850., 200. * GPA, 0.8, 490., 50.);
+    /// [Aluminium](https://en.wikipedia.org/wiki/Aluminium).
+    pub const ALUMINIUM: Self = material("Aluminium", 2700., 69. * GPA, 0.7, 897., 237.);
+    /// [Copper](https://en.wikipedia.org/wiki/Copper).
+    pub const COPPER: Self = material("Copper", 8960., 117. * GPA, 0.6, 385., 401.);
+}
+
+/// # Non-metals
+impl Material {
+    /// Oak [wood](https://en.wikipedia.org/wiki/Wood), along the grain.
+    pub const WOOD: Self = material("Wood", 750., 11. * GPA, 0.5, 2000., 0.17);
+    /// Natural [rubber](https://en.wikipedia.org/wiki/Natural_rubber).
+    pub const RUBBER: Self = material("Rubber", 1100., 0.05 * GPA, 0.75, 2010., 0.16);
+    /// Water [ice](https://en.wikipedia.org/wiki/Ice), near its melting point.
+    pub const ICE: Self = material("Ice", 917., 9. * GPA, 0.3, 2090., 2.22);
+    /// [Concrete](https://en.wikipedia.org/wiki/Concrete).
+    pub const CONCRETE: Self = material("Concrete", 2400., 30. * GPA, 0.4, 880., 1.7);
+    /// Soda-lime [glass](https://en.wikipedia.org/wiki/Glass).
+    pub const GLASS: Self = material("Glass", 2500., 70. * GPA, 0.9, 840., 1.);
+    /// [PTFE](https://en.wikipedia.org/wiki/Polytetrafluoroethylene) (teflon).
+    pub const PTFE: Self = material("PTFE", 2200., 0.5 * GPA, 0.5, 1000., 0.25);
+}
+
+/// # Fluids
+impl Material {
+    /// Liquid [water](https://en.wikipedia.org/wiki/Water), at 20 °C.
+    pub const WATER: Self = material("Water", 998., 0., 0., 4182., 0.598);
+    /// Dry [air](https://en.wikipedia.org/wiki/Atmosphere_of_Earth), at 20 °C.
+    pub const AIR: Self = material("Air", 1.204, 0., 0., 1005., 0.0257);
+}
+
+/// All the materials of the catalogue.
+pub const CATALOGUE: &[&Material] = &[
+    &Material::STEEL,
+    &Material::ALUMINIUM,
+    &Material::COPPER,
+    &Material::WOOD,
+    &Material::RUBBER,
+    &Material::ICE,
+    &Material::CONCRETE,
+    &Material::GLASS,
+    &Material::PTFE,
+    &Material::WATER,
+    &Material::AIR,
+];
+
+/// The friction between the pairs of materials, by name.
+pub(super) const FRICTION: &[(&str, &str, Friction)] = &[
+    ("Steel", "Steel", Friction::new(0.74, 0.57)),
+    ("Aluminium", "Steel", Friction::new(0.61, 0.47)),
+    ("Copper", "Steel", Friction::new(0.53, 0.36)),
+    ("Ice", "Steel", Friction::new(0.03, 0.01)),
+    ("PTFE", "Steel", Friction::new(0.04, 0.04)),
+    ("PTFE", "PTFE", Friction::new(0.04, 0.04)),
+    ("Wood", "Wood", Friction::new(0.4, 0.2)),
+    ("Rubber", "Concrete", Friction::new(1.0, 0.8)),
+    ("Glass", "Glass", Friction::new(0.94, 0.4)),
+    ("Ice", "Ice", Friction::new(0.1, 0.03)),
+];
@@ -0,0 +1,280 @@
+// fisica::material
+//
+//! Materials and friction
+//!
+//! A [`Material`] gathers the bulk properties of a substance: its [`Density`],
+//! [*Young's modulus*][0], coefficient of restitution, [`SpecificHeatCapacity`]
+//! and [`ThermalConductivity`]. A catalogue of common engineering materials is
+//! provided as constants, like [`Material::STEEL`].
+//!
+//! The [`Friction`] between two surfaces follows the [*Coulomb model*][1]:
+//! they stick while the tangential force stays below `μs N`, and slide against
+//! a kinetic friction of `μk N` otherwise. The coefficients of the common
+//! pairs of the catalogue are found with [`Friction::between`].
+//!
+//! The [`RollingResistance`] opposes a wheel rolling over a surface with a
+//! force proportional to the normal load (`F = Crr N`).
+//!
+//! [0]:https://en.wikipedia.org/wiki/Young%27s_modulus
+//! [1]:https://en.wikipedia.org/wiki/Friction#Dry_friction
+//!
+//! # External links
+//! - <https://en.wikipedia.org/wiki/Friction>
+//! - <https://en.wikipedia.org/wiki/Rolling_resistance>
+//! - <https://www.engineeringtoolbox.com/friction-coefficients-d_778.html>
+//
+
+use crate::rigid::ContactMaterial;
+use crate::units::{
+    Density, Force, Length, Mass, Pressure, SpecificHeatCapacity, ThermalConductivity, Velocity,
+    Volume,
+};
+use crate::{Direction, Magnitude};
+
+mod catalogue;
+
+pub use catalogue::CATALOGUE;
+
+/// The bulk properties of a substance, at room temperature and sea level
+/// pressure.
+#[derive(Clone, Copy, Debug)]
+pub struct Material {
+    pub name: &'static str,
+    pub density: Density,
+    /// The Young's modulus, the tensile stiffness. Zero for fluids.
+    pub young_modulus: Pressure,
+    /// The coefficient of restitution of two bodies of this material colliding,
+    /// from 0 (perfectly inelastic) to 1 (perfectly elastic). Zero for fluids.
+    pub restitution: Magnitude,
+    pub specific_heat: SpecificHeatCapacity,
+    pub thermal_conductivity: ThermalConductivity,
+}
+
+impl Material {
+    /// Returns the material of the [`CATALOGUE`] with the given `name`,
+    /// ignoring the case.
+    pub fn find(name: &str) -> Option<&'static Material> {
+        CATALOGUE
+            .iter()
+            .copied()
+            .find(|m| m.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the [`Mass`] of the given [`Volume`] of this material.
+    #[inline]
+    pub fn calc_mass(&self, v: Volume) -> Mass {
+        v.calc_mass(self.density)
+    }
+
+    /// Returns the [`Friction`] against the `other` material, if the pair
+    /// is in the catalogue.
+    #[inline]
+    pub fn friction_with(&self, other: &Material) -> Option<Friction> {
+        Friction::between(self, other)
+    }
+
+    /// Returns the [`ContactMaterial`] of a contact against the `other`
+    /// material, for the [`ContactSolver`][crate::rigid::ContactSolver],
+    /// if their friction is in the catalogue.
+    ///
+    /// The restitution of the pair is the geometric mean of both.
+    pub fn contact_with(&self, other: &Material) -> Option<ContactMaterial> {
+        let friction = self.friction_with(other)?;
+        Some(ContactMaterial::new(
+            (self.restitution * other.restitution).sqrt(),
+            friction.static_coefficient,
+            friction.kinetic_coefficient,
+        ))
+    }
+}
+
+/// The coefficients of dry friction between two surfaces.
+#[derive(Clone, Copy, Debug)]
+pub struct Friction {
+    /// The coefficient of static friction `μs`, while the surfaces stick.
+    pub static_coefficient: Magnitude,
+    /// The coefficient of kinetic friction `μk`, while the surfaces slide.
+    pub kinetic_coefficient: Magnitude,
+}
+
+impl Friction {
+    /// New Friction.
+    #[inline]
+    pub const fn new(static_coefficient: Magnitude, kinetic_coefficient: Magnitude) -> Self {
+        Self {
+            static_coefficient,
+            kinetic_coefficient,
+        }
+    }
+
+    /// Returns the friction between the materials `a` and `b`, in any order,
+    /// if the pair is in the catalogue.
+    pub fn between(a: &Material, b: &Material) -> Option<Self> {
+        catalogue::FRICTION
+            .iter()
+            .find(|(x, y, _)| (*x == a.name && *y == b.name) || (*x == b.name && *y == a.name))
+            .map(|(_, _, f)| *f)
+    }
+
+    /// Returns the steepest slope angle, in radians, on which a body rests
+    /// without sliding (`θ = atan μs`).
+    #[inline]
+    pub fn angle_of_repose(&self) -> Magnitude {
+        self.static_coefficient.atan()
+    }
+
+    /// Calculates the maximum magnitude of the static friction for the
+    /// given `normal` [`Force`] (`Fs ≤ μs N`).
+    #[inline]
+    pub fn calc_static_limit(&self, normal: Force) -> Magnitude {
+        self.static_coefficient * normal.m()
+    }
+
+    /// Calculates the static friction [`Force`] that holds a body against the
+    /// `tangential` force applied along the surface.
+    ///
+    /// Returns `None` if the applied force exceeds the static limit, and the
+    /// body starts sliding.
+    pub fn calc_static_force(&self, normal: Force, tangential: Force) -> Option<Force> {
+        if tangential.m() <= self.calc_static_limit(normal) {
+            Some(Force::new(-tangential.d))
+        } else {
+            None
+        }
+    }
+
+    /// Calculates the kinetic friction [`Force`] on a body `sliding` with the
+    /// given [`Velocity`] relative to the surface (`F = -μk N v̂`).
+    pub fn calc_kinetic_force(&self, normal: Force, sliding: Velocity) -> Force {
+        opposing(self.kinetic_coefficient * normal.m(), sliding)
+    }
+}
+
+/// The resistance to rolling of a wheel over a surface.
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Rolling_resistance>
+#[derive(Clone, Copy, Debug)]
+pub struct RollingResistance {
+    /// The dimensionless rolling resistance coefficient `Crr`.
+    pub coefficient: Magnitude,
+}
+
+impl RollingResistance {
+    /// A steel railway wheel on a steel rail.
+    pub const RAILWAY_WHEEL: Self = Self::new(0.001);
+    /// A racing bicycle tyre on asphalt.
+    pub const BICYCLE_TYRE: Self = Self::new(0.004);
+    /// A car tyre on concrete.
+    pub const CAR_TYRE: Self = Self::new(0.010);
+    /// A car tyre on sand.
+    pub const CAR_TYRE_SAND: Self = Self::new(0.3);
+
+    /// New RollingResistance.
+    #[inline]
+    pub const fn new(coefficient: Magnitude) -> Self {
+        Self { coefficient }
+    }
+
+    /// Derives the `RollingResistance` of a wheel of the given `radius` from
+    /// the `lever` arm of the normal force ahead of its axle (`Crr = b / r`).
+    #[inline]
+    pub fn from_lever_radius(lever: Length, radius: Length) -> Self {
+        Self::new(lever.m() / radius.m())
+    }
+
+    /// Calculates the [`Force`] opposing a wheel rolling with the given
+    /// [`Velocity`] under a `normal` load (`F = -Crr N v̂`).
+    pub fn calc_force(&self, normal: Force, rolling: Velocity) -> Force {
+        opposing(self.coefficient * normal.m(), rolling)
+    }
+}
+
+/// Returns a [`Force`] of the given magnitude opposing the velocity `v`,
+/// or zero if it's not moving.
+fn opposing(magnitude: Magnitude, v: Velocity) -> Force {
+    let speed = v.m();
+    if speed == 0. {
+        return Force::new(Direction::ZERO);
+    }
+    Force::new(v.d * (-magnitude / speed))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the catalogue lookups.
+    #[test]
+    fn material_catalogue() {
+        let steel = Material::find("steel").unwrap();
+        assert_eq!["Steel", steel.name];
+        assert_float_eq!(
+            7850.,
+            steel.calc_mass(Volume::new(1.)).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+
+        // the pairs are symmetric
+        let ice = &Material::ICE;
+        let a = Friction::between(steel, ice).unwrap();
+        let b = Friction::between(ice, steel).unwrap();
+        assert_float_eq!(a.static_coefficient, b.static_coefficient, abs <= 0.);
+        assert!(Friction::between(&Material::AIR, steel).is_none());
+
+        // kinetic friction never exceeds static friction
+        for a in CATALOGUE {
+            for b in CATALOGUE {
+                if let Some(f) = a.friction_with(b) {
+                    assert!(f.kinetic_coefficient <= f.static_coefficient);
+                }
+            }
+        }
+
+        let contact = Material::RUBBER.contact_with(&Material::CONCRETE).unwrap();
+        assert_float_eq!(1.0, contact.static_friction, r2nd <= Magnitude::EPSILON);
+        assert!(contact.restitution > 0. && contact.restitution < 1.);
+    }
+
+    /// Checks a block on an incline, and rolling resistance.
+    #[test]
+    fn material_friction() {
+        let f = Friction::between(&Material::WOOD, &Material::WOOD).unwrap();
+        let (m, g) = (10., 9.81);
+
+        // below the angle of repose the block holds
+        for (angle, holds) in [
+            (f.angle_of_repose() - 0.01, true),
+            (f.angle_of_repose() + 0.01, false),
+        ] {
+            let normal = Force::new(Direction::new(0., m * g * angle.cos(), 0.));
+            let tangential = Force::new(Direction::new(m * g * angle.sin(), 0., 0.));
+            let held = f.calc_static_force(normal, tangential);
+            assert_eq![holds, held.is_some()];
+            if let Some(held) = held {
+                assert_float_eq!(-tangential.d.x(), held.d.x(), r2nd <= Magnitude::EPSILON);
+            }
+        }
+
+        // sliding, the friction opposes the motion
+        let normal = Force::new(Direction::new(0., m * g, 0.));
+        let slide = Velocity::new(Direction::new(3., 0., -4.));
+        let k = f.calc_kinetic_force(normal, slide);
+        assert_float_eq!(f.kinetic_coefficient * m * g, k.m(), r2nd <= 1e-15);
+        assert!(k.d.dot(slide.d) < 0.);
+        assert_float_eq!(
+            0.,
+            f.calc_kinetic_force(normal, Velocity::new(Direction::ZERO))
+                .m(),
+            abs <= 0.
+        );
+
+        // a 1000 kg car rolling on concrete
+        let normal = Force::new(Direction::new(0., 1000. * g, 0.));
+        let r = RollingResistance::CAR_TYRE
+            .calc_force(normal, Velocity::new(Direction::new(20., 0., 0.)));
+        assert_float_eq!(-98.1, r.d.x(), r2nd <= 1e-15);
+        let crr = RollingResistance::from_lever_radius(Length::new(0.003), Length::new(0.3));
+        assert_float_eq!(0.01, crr.coefficient, r2nd <= 1e-15);
+    }
+}
@@ -0,0 +1,22 @@
+// fisica::math::integrate
+//
+// Numerical integration.
+
+use super::Magnitude;
+
+/// Advances the state `y` by `dt` with the classic Runge–Kutta method,
+/// where `f` returns the derivative of a state.
+pub(crate) fn rk4<const N: usize>(
+    y: [Magnitude; N],
+    dt: Magnitude,
+    f: impl Fn([Magnitude; N]) -> [Magnitude; N],
+) -> [Magnitude; N] {
+    let add = |y: [Magnitude; N], k: [Magnitude; N], h: Magnitude| -> [Magnitude; N] {
+        core::array::from_fn(|i| y[i] + k[i] * h)
+    };
+    let k1 = f(y);
+    let k2 = f(add(y, k1, dt / 2.));
+    let k3 = f(add(y, k2, dt / 2.));
+    let k4 = f(add(y, k3, dt));
+    core::array::from_fn(|i| y[i] + (k1[i] + 2. * k2[i] + 2. * k3[i] + k4[i]) * dt / 6.)
+}
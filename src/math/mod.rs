@@ -82,6 +82,11 @@
 mod vectors;
 pub use vectors::{Direction, Position};
 
+#[cfg(feature = "alloc")]
+mod integrate;
+#[cfg(feature = "alloc")]
+pub(crate) use integrate::rk4;
+
 /// The floating point type used for magnitudes
 pub type Magnitude = f64;
 
@@ -4,10 +4,10 @@
 
 use core::{
     fmt,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
-use super::{Magnitude, V3};
+use super::{Magnitude, Orientation, V3};
 
 /// `Direction` is a vector quantity that represents a change in [`Position`].
 ///
@@ -122,10 +122,21 @@ impl Direction {
         self.v.dot(other.v)
     }
 
+    /// Returns the `Direction` rotated by the given [`Orientation`].
+    pub fn rotate(&self, o: Orientation) -> Direction {
+        Self { v: o * self.v }
+    }
+
     /// *Returns the underlying vector type.*
     pub fn vector(&self) -> V3 {
         self.v
     }
+
+    /// *New `Direction` from the underlying vector type.*
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn from_vector(v: V3) -> Self {
+        Self { v }
+    }
 }
 
 /// # Constants
@@ -172,6 +183,15 @@ impl SubAssign for Direction {
     }
 }
 
+// Vector negation
+impl Neg for Direction {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { v: -self.v }
+    }
+}
+
 // Scalar multiplication
 impl Mul<Magnitude> for Direction {
     type Output = Self;
@@ -0,0 +1,154 @@
+// fisica::orbit::lambert
+//
+// Lambert's problem.
+
+use core::f64::consts::{PI, TAU};
+
+use crate::units::{Time, Velocity};
+use crate::{Magnitude, Position};
+
+/// The solution to [*Lambert's problem*][0]: the orbit connecting two
+/// [`Position`]s in a given time of flight, around a central body.
+///
+/// It's solved with the universal variable formulation, for transfers of
+/// less than one revolution.
+///
+/// [0]:https://en.wikipedia.org/wiki/Lambert%27s_problem
+#[derive(Clone, Copy, Debug)]
+pub struct Lambert {
+    /// The [`Velocity`] needed at the departure position.
+    pub departure: Velocity,
+    /// The [`Velocity`] reached at the arrival position.
+    pub arrival: Velocity,
+}
+
+impl Lambert {
+    /// Solves the transfer from `r1` to `r2` in the time of flight `tof`,
+    /// around a central body of gravitational parameter `mu`.
+    ///
+    /// A `prograde` transfer moves counterclockwise seen from `+z`.
+    ///
+    /// Returns `None` if the time of flight is not positive, or the positions
+    /// are collinear with the central body, which doesn't define a plane.
+    pub fn solve(
+        mu: Magnitude,
+        r1: Position,
+        r2: Position,
+        tof: Time,
+        prograde: bool,
+    ) -> Option<Self> {
+        let (r1m, r2m) = (r1.magnitude(), r2.magnitude());
+        if tof.m() <= 0. || r1.cross(r2).magnitude() <= 1e-9 * r1m * r2m {
+            return None;
+        }
+        let mut angle = (r1.dot(r2) / (r1m * r2m)).clamp(-1., 1.).acos();
+        if (r1.cross(r2).z() < 0.) == prograde {
+            angle = TAU - angle;
+        }
+        let a = angle.sin() * (r1m * r2m / (1. - angle.cos())).sqrt();
+
+        let y = |z: Magnitude| r1m + r2m + a * (z * stumpff_s(z) - 1.) / stumpff_c(z).sqrt();
+        let target = mu.sqrt() * tof.m();
+        // the time of flight for `z`, minus the target, increasing with `z`
+        let f = |z: Magnitude| {
+            let y = y(z);
+            if y < 0. {
+                return -target;
+            }
+            (y / stumpff_c(z)).powf(1.5) * stumpff_s(z) + a * y.sqrt() - target
+        };
+
+        // bisection, between a hyperbolic and a one revolution elliptic bound
+        let (mut lo, mut hi) = (-4. * PI * PI, 4. * PI * PI * (1. - 1e-9));
+        while f(lo) > 0. {
+            lo *= 2.;
+            if lo < -1e12 {
+                return None;
+            }
+        }
+        if f(hi) < 0. {
+            return None;
+        }
+        let mut z = 0.;
+        for _ in 0..200 {
+            z = (lo + hi) / 2.;
+            if f(z) < 0. {
+                lo = z;
+            } else {
+                hi = z;
+            }
+            if hi - lo < 1e-14 * z.abs().max(1.) {
+                break;
+            }
+        }
+
+        // Lagrange coefficients
+        let y = y(z);
+        let f = 1. - y / r1m;
+        let g = a * (y / mu).sqrt();
+        let g_dot = 1. - y / r2m;
+        Some(Self {
+            departure: Velocity::new((r2 - r1 * f) / g),
+            arrival: Velocity::new((r2 * g_dot - r1) / g),
+        })
+    }
+}
+
+/// Returns the Stumpff function `C(z)`.
+fn stumpff_c(z: Magnitude) -> Magnitude {
+    if z.abs() < 1e-3 {
+        0.5 - z / 24. + z * z / 720.
+    } else if z > 0. {
+        (1. - z.sqrt().cos()) / z
+    } else {
+        ((-z).sqrt().cosh() - 1.) / -z
+    }
+}
+
+/// Returns the Stumpff function `S(z)`.
+fn stumpff_s(z: Magnitude) -> Magnitude {
+    if z.abs() < 1e-3 {
+        1. / 6. - z / 120. + z * z / 5_040.
+    } else if z > 0. {
+        let s = z.sqrt();
+        (s - s.sin()) / (s * s * s)
+    } else {
+        let s = (-z).sqrt();
+        (s.sinh() - s) / (s * s * s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::celestial::CelestialBody;
+    use crate::orbit::{KeplerElements, Orbit};
+    use crate::units::Length;
+    use crate::Direction;
+    use float_eq::assert_float_eq;
+
+    /// Checks the solution against a published example and propagated orbits.
+    #[test]
+    fn lambert() {
+        // Curtis, Orbital Mechanics for Engineering Students, example 5.2
+        let mu = 398_600e9;
+        let r1 = Position::new(5_000e3, 10_000e3, 2_100e3);
+        let r2 = Position::new(-14_600e3, 2_500e3, 7_000e3);
+        let s = Lambert::solve(mu, r1, r2, Time::new(3_600.), true).unwrap();
+        let expected = Direction::new(-5_992.5, 1_925.4, 3_245.6);
+        assert_float_eq!(0., (s.departure.d - expected).magnitude(), abs <= 1.);
+
+        let mu = CelestialBody::EARTH.gravitational_parameter();
+        for (e, dt) in [(0.3, 4_000.), (0., 2_000.), (1.4, 3_000.)] {
+            let elements = KeplerElements::from_periapsis(Length::new(7e6), e, 0.4, 0.3, 0.2, 0.1);
+            let orbit = Orbit::new(mu, elements);
+            let (p1, v1) = orbit.state();
+            let (p2, v2) = orbit.calc_state_after(Time::new(dt));
+            let s = Lambert::solve(mu, p1, p2, Time::new(dt), true).unwrap();
+            assert_float_eq!(0., (s.departure.d - v1.d).magnitude(), abs <= 1e-4 * v1.m());
+            assert_float_eq!(0., (s.arrival.d - v2.d).magnitude(), abs <= 1e-4 * v2.m());
+        }
+
+        assert!(Lambert::solve(mu, r1, r1 * -2., Time::new(3_600.), true).is_none());
+    }
+}
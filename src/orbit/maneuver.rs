@@ -0,0 +1,190 @@
+// fisica::orbit::maneuver
+//
+// Impulsive orbital transfers.
+
+use alloc::{vec, vec::Vec};
+use core::f64::consts::PI;
+
+use crate::units::{Length, Speed, Time};
+use crate::Magnitude;
+
+/// An orbital maneuver made of instantaneous burns.
+///
+/// Each burn is the change in velocity (*delta-v*) needed at that point.
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Orbital_maneuver>
+/// - <https://en.wikipedia.org/wiki/Delta-v_budget>
+#[derive(Clone, Debug)]
+pub struct Transfer {
+    /// The delta-v of each burn, in order.
+    pub burns: Vec<Speed>,
+    /// The time of flight from the first burn to the last one.
+    pub duration: Time,
+}
+
+impl Transfer {
+    /// New Transfer.
+    pub fn new(burns: Vec<Speed>, duration: Time) -> Self {
+        Self { burns, duration }
+    }
+
+    /// Returns the total delta-v of all the burns.
+    pub fn delta_v(&self) -> Speed {
+        Speed::new(self.burns.iter().map(|b| b.m()).sum())
+    }
+}
+
+/// # Coplanar transfers
+impl Transfer {
+    /// Returns the [*Hohmann transfer*][0] between two coplanar circular orbits
+    /// of radius `r1` and `r2`, around a central body of gravitational
+    /// parameter `mu`.
+    ///
+    /// It follows half an elliptic orbit tangent to both circles, with a burn
+    /// at each end. It's the most efficient two-burn transfer.
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Hohmann_transfer_orbit
+    pub fn hohmann(mu: Magnitude, r1: Length, r2: Length) -> Self {
+        let (r1, r2) = (r1.m(), r2.m());
+        let a = (r1 + r2) / 2.;
+        let burns = vec![
+            Speed::new((calc_vis_viva(mu, r1, a) - calc_circular(mu, r1)).abs()),
+            Speed::new((calc_circular(mu, r2) - calc_vis_viva(mu, r2, a)).abs()),
+        ];
+        Self::new(burns, calc_half_period(mu, a))
+    }
+
+    /// Returns the [*bi-elliptic transfer*][0] between two coplanar circular
+    /// orbits of radius `r1` and `r2`, through an intermediate apoapsis of
+    /// radius `rb`, around a central body of gravitational parameter `mu`.
+    ///
+    /// It follows two half ellipses with three burns. It's more efficient than
+    /// the [Hohmann transfer][Self::hohmann] when `r2 / r1` is over `15.58`,
+    /// and `rb` is big enough, but takes longer.
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Bi-elliptic_transfer
+    pub fn bi_elliptic(mu: Magnitude, r1: Length, r2: Length, rb: Length) -> Self {
+        let (r1, r2, rb) = (r1.m(), r2.m(), rb.m());
+        let (a1, a2) = ((r1 + rb) / 2., (r2 + rb) / 2.);
+        let burns = vec![
+            Speed::new((calc_vis_viva(mu, r1, a1) - calc_circular(mu, r1)).abs()),
+            Speed::new((calc_vis_viva(mu, rb, a2) - calc_vis_viva(mu, rb, a1)).abs()),
+            Speed::new((calc_vis_viva(mu, r2, a2) - calc_circular(mu, r2)).abs()),
+        ];
+        let duration = calc_half_period(mu, a1).m() + calc_half_period(mu, a2).m();
+        Self::new(burns, Time::new(duration))
+    }
+}
+
+/// # Plane changes
+impl Transfer {
+    /// Returns the [*plane change*][0] that rotates the orbital plane by the
+    /// given `angle` (in radians), at a point where the orbital speed is `speed`
+    /// (`Δv = 2 v sin(Δi/2)`).
+    ///
+    /// It's cheaper where the speed is lower, at the apoapsis.
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Orbital_inclination_change
+    pub fn plane_change(speed: Speed, angle: Magnitude) -> Self {
+        let dv = 2. * speed.m() * (angle / 2.).sin();
+        Self::new(vec![Speed::new(dv.abs())], Time::new(0.))
+    }
+
+    /// Returns the single burn that changes the orbital speed from `v1` to `v2`
+    /// while rotating the orbital plane by the given `angle` (in radians)
+    /// (`Δv = √(v₁² + v₂² - 2 v₁ v₂ cos Δi)`).
+    ///
+    /// It's cheaper than changing the speed and the plane separately.
+    pub fn combined(v1: Speed, v2: Speed, angle: Magnitude) -> Self {
+        let (v1, v2) = (v1.m(), v2.m());
+        let dv = (v1 * v1 + v2 * v2 - 2. * v1 * v2 * angle.cos())
+            .max(0.)
+            .sqrt();
+        Self::new(vec![Speed::new(dv)], Time::new(0.))
+    }
+
+    /// Returns the Hohmann transfer between two circular orbits of radius
+    /// `r1` and `r2`, inclined by the given `angle` (in radians), with the
+    /// plane change combined with the burn at the highest orbit.
+    pub fn hohmann_with_plane_change(
+        mu: Magnitude,
+        r1: Length,
+        r2: Length,
+        angle: Magnitude,
+    ) -> Self {
+        let (r1, r2) = (r1.m(), r2.m());
+        let (low, high) = (r1.min(r2), r1.max(r2));
+        let a = (r1 + r2) / 2.;
+        let low_dv = Speed::new((calc_vis_viva(mu, low, a) - calc_circular(mu, low)).abs());
+        let high_dv = Self::combined(
+            Speed::new(calc_vis_viva(mu, high, a)),
+            Speed::new(calc_circular(mu, high)),
+            angle,
+        )
+        .burns[0];
+        let burns = if r1 <= r2 {
+            vec![low_dv, high_dv]
+        } else {
+            vec![high_dv, low_dv]
+        };
+        Self::new(burns, calc_half_period(mu, a))
+    }
+}
+
+/// Returns the speed of a circular orbit of radius `r`.
+fn calc_circular(mu: Magnitude, r: Magnitude) -> Magnitude {
+    (mu / r).sqrt()
+}
+
+/// Returns the speed at radius `r` of an orbit of semi-major axis `a`.
+fn calc_vis_viva(mu: Magnitude, r: Magnitude, a: Magnitude) -> Magnitude {
+    (mu * (2. / r - 1. / a)).sqrt()
+}
+
+/// Returns half the period of an orbit of semi-major axis `a`.
+fn calc_half_period(mu: Magnitude, a: Magnitude) -> Time {
+    Time::new(PI * (a * a * a / mu).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::celestial::CelestialBody;
+    use float_eq::assert_float_eq;
+
+    /// Checks the delta-v budgets against published values.
+    #[test]
+    fn transfers() {
+        let mu = CelestialBody::EARTH.gravitational_parameter();
+        let (leo, geo) = (Length::new(6_678e3), Length::new(42_164e3));
+
+        let hohmann = Transfer::hohmann(mu, leo, geo);
+        assert_float_eq!(2_426., hohmann.burns[0].m(), abs <= 5.);
+        assert_float_eq!(1_467., hohmann.burns[1].m(), abs <= 5.);
+        assert_float_eq!(5.27, hohmann.duration.m() / 3_600., abs <= 0.01);
+        // the way back costs the same
+        let back = Transfer::hohmann(mu, geo, leo);
+        assert_float_eq!(hohmann.delta_v().m(), back.delta_v().m(), r2nd <= 1e-12);
+
+        // a bi-elliptic transfer with the apoapsis at the target is a Hohmann one
+        let bi = Transfer::bi_elliptic(mu, leo, geo, geo);
+        assert_float_eq!(hohmann.delta_v().m(), bi.delta_v().m(), r2nd <= 1e-12);
+        // and is cheaper for very distant targets
+        let far = Length::new(leo.m() * 20.);
+        let rb = Length::new(leo.m() * 60.);
+        let (h, b) = (
+            Transfer::hohmann(mu, leo, far),
+            Transfer::bi_elliptic(mu, leo, far, rb),
+        );
+        assert!(b.delta_v().m() < h.delta_v().m() && b.duration.m() > h.duration.m());
+
+        // the geostationary transfer from Cape Canaveral (28.5º)
+        let v = Speed::new(7_726.);
+        let plane = Transfer::plane_change(v, 28.5_f64.to_radians());
+        assert_float_eq!(3_803., plane.delta_v().m(), abs <= 5.);
+        let combined = Transfer::hohmann_with_plane_change(mu, leo, geo, 28.5_f64.to_radians());
+        assert_float_eq!(1_831., combined.burns[1].m(), abs <= 5.);
+        assert!(combined.delta_v().m() < hohmann.delta_v().m() + plane.delta_v().m());
+    }
+}
@@ -0,0 +1,541 @@
+// fisica::orbit
+//
+//! Two-body orbital mechanics
+//!
+//! An [`Orbit`] describes the motion of a small body around a central one,
+//! given the gravitational parameter `μ = G M` of the central body and the
+//! classical [`KeplerElements`]. The orbit is a conic section with the
+//! central body at a focus: an ellipse (`e < 1`), a parabola (`e = 1`) or an
+//! hyperbola (`e > 1`).
+//!
+//! The reference frame is inertial and centered on the central body, with
+//! the reference plane (the ecliptic or the equator) in `xy`, and `+z`
+//! towards its north pole. Angles are in radians.
+//!
+//! ## Maneuvers
+//!
+//! A [`Transfer`] plans the impulsive burns needed to change between orbits,
+//! and [`Lambert`] finds the orbit connecting two positions in a given time.
+//!
+//! # External links
+//! - <https://en.wikipedia.org/wiki/Orbital_elements>
+//! - <https://en.wikipedia.org/wiki/Kepler%27s_equation>
+//! - <https://en.wikipedia.org/wiki/Orbital_state_vectors>
+//
+
+use core::f64::consts::{PI, TAU};
+
+use crate::celestial::CelestialBody;
+use crate::units::{Length, Speed, Time, Velocity};
+use crate::{Direction, Magnitude, Orientation, Position};
+
+mod lambert;
+mod maneuver;
+
+pub use {lambert::Lambert, maneuver::Transfer};
+
+/// The tolerance under which an eccentricity or an inclination is considered
+/// zero, and an eccentricity is considered parabolic.
+const TOLERANCE: Magnitude = 1e-10;
+
+/// The classical orbital elements.
+///
+/// The size of the orbit is given by the semi-latus rectum `p`, which is
+/// defined for all the conics, parabolas included. The semi-major axis can be
+/// derived from it (`a = p / (1 - e²)`).
+#[derive(Clone, Copy, Debug)]
+pub struct KeplerElements {
+    /// The semi-latus rectum `p`.
+    pub semi_latus_rectum: Length,
+    /// The eccentricity `e`.
+    pub eccentricity: Magnitude,
+    /// The inclination `i` of the orbital plane over the reference plane.
+    pub inclination: Magnitude,
+    /// The longitude of the ascending node `Ω`.
+    pub ascending_node: Magnitude,
+    /// The argument of periapsis `ω`, from the ascending node.
+    pub argument_of_periapsis: Magnitude,
+    /// The true anomaly `ν`, the angle from the periapsis to the body.
+    pub true_anomaly: Magnitude,
+}
+
+impl KeplerElements {
+    /// New `KeplerElements` from the semi-major axis `a`.
+    ///
+    /// The semi-major axis must be negative for hyperbolic orbits.
+    /// For parabolic orbits use [`from_periapsis`][Self::from_periapsis].
+    pub fn new(
+        semi_major_axis: Length,
+        eccentricity: Magnitude,
+        inclination: Magnitude,
+        ascending_node: Magnitude,
+        argument_of_periapsis: Magnitude,
+        true_anomaly: Magnitude,
+    ) -> Self {
+        let e = eccentricity;
+        Self {
+            semi_latus_rectum: Length::new(semi_major_axis.m() * (1. - e * e)),
+            eccentricity,
+            inclination,
+            ascending_node,
+            argument_of_periapsis,
+            true_anomaly,
+        }
+    }
+
+    /// New `KeplerElements` from the periapsis distance `q`, valid for all
+    /// the conics (`p = q (1 + e)`).
+    pub fn from_periapsis(
+        periapsis: Length,
+        eccentricity: Magnitude,
+        inclination: Magnitude,
+        ascending_node: Magnitude,
+        argument_of_periapsis: Magnitude,
+        true_anomaly: Magnitude,
+    ) -> Self {
+        Self {
+            semi_latus_rectum: Length::new(periapsis.m() * (1. + eccentricity)),
+            eccentricity,
+            inclination,
+            ascending_node,
+            argument_of_periapsis,
+            true_anomaly,
+        }
+    }
+}
+
+/// A two-body Keplerian orbit.
+#[derive(Clone, Copy, Debug)]
+pub struct Orbit {
+    /// The standard gravitational parameter `μ` of the central body, in `m³/s²`.
+    pub mu: Magnitude,
+    pub elements: KeplerElements,
+}
+
+/// The kind of conic section described by an [`Orbit`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Conic {
+    Circle,
+    Ellipse,
+    Parabola,
+    Hyperbola,
+}
+
+impl Orbit {
+    /// New Orbit.
+    #[inline]
+    pub const fn new(mu: Magnitude, elements: KeplerElements) -> Self {
+        Self { mu, elements }
+    }
+
+    /// New `Orbit` around the given [`CelestialBody`].
+    pub fn around(body: &CelestialBody, elements: KeplerElements) -> Self {
+        Self::new(body.gravitational_parameter(), elements)
+    }
+
+    /// New `Orbit` from the state vectors relative to the central body.
+    ///
+    /// For circular orbits the argument of periapsis is zero and the true
+    /// anomaly is measured from the ascending node. For equatorial orbits
+    /// the longitude of the ascending node is zero, and the angles are
+    /// measured from the `+x` axis.
+    pub fn from_state(mu: Magnitude, position: Position, velocity: Velocity) -> Self {
+        let (r, v) = (position, velocity.d);
+        let rm = r.magnitude();
+        let h = r.cross(v);
+        let hm = h.magnitude();
+        let n = Direction::new(-h.y(), h.x(), 0.);
+        let nm = n.magnitude();
+        let e_vec = (r * (v.magnitude_squared() - mu / rm) - v * r.dot(v)) / mu;
+        let e = e_vec.magnitude();
+
+        let inclination = acos(h.z() / hm);
+        let equatorial = nm <= TOLERANCE * hm;
+        let circular = e <= TOLERANCE;
+        let retrograde = h.z() < 0.;
+
+        let ascending_node = if equatorial {
+            0.
+        } else {
+            n.y().atan2(n.x()).rem_euclid(TAU)
+        };
+        // the direction from which the argument of periapsis is measured
+        let node = if equatorial {
+            Direction::new(1., 0., 0.)
+        } else {
+            n / nm
+        };
+        // the angle from `node` to `d` in the orbital plane
+        let angle = |d: Direction| {
+            let a = acos(node.dot(d) / d.magnitude());
+            let past = if equatorial {
+                (node.cross(d).z() < 0.) != retrograde
+            } else {
+                d.z() < 0.
+            };
+            if past {
+                TAU - a
+            } else {
+                a
+            }
+        };
+        let (argument_of_periapsis, true_anomaly) = if circular {
+            (0., angle(r))
+        } else {
+            let w = angle(e_vec);
+            let mut nu = acos(e_vec.dot(r) / (e * rm));
+            if r.dot(v) < 0. {
+                nu = TAU - nu;
+            }
+            (w, nu)
+        };
+
+        Self::new(
+            mu,
+            KeplerElements {
+                semi_latus_rectum: Length::new(hm * hm / mu),
+                eccentricity: e,
+                inclination,
+                ascending_node,
+                argument_of_periapsis,
+                true_anomaly,
+            },
+        )
+    }
+
+    /// Returns the state vectors: the [`Position`] and [`Velocity`] relative
+    /// to the central body.
+    pub fn state(&self) -> (Position, Velocity) {
+        let el = &self.elements;
+        let (p, e, nu) = (el.semi_latus_rectum.m(), el.eccentricity, el.true_anomaly);
+        let (sin, cos) = nu.sin_cos();
+        let r = p / (1. + e * cos);
+        let position = Direction::new(r * cos, r * sin, 0.);
+        let velocity = Direction::new(-sin, e + cos, 0.) * (self.mu / p).sqrt();
+        let rotation = self.perifocal_orientation();
+        (
+            position.rotate(rotation),
+            Velocity::new(velocity.rotate(rotation)),
+        )
+    }
+
+    /// Returns the [`Orientation`] from the perifocal frame, with `+x`
+    /// towards the periapsis and `+z` along the angular momentum, to the
+    /// reference frame.
+    pub fn perifocal_orientation(&self) -> Orientation {
+        let el = &self.elements;
+        Orientation::from_rotation_z(el.ascending_node)
+            * Orientation::from_rotation_x(el.inclination)
+            * Orientation::from_rotation_z(el.argument_of_periapsis)
+    }
+
+    /// Returns the kind of conic section.
+    pub fn conic(&self) -> Conic {
+        let e = self.elements.eccentricity;
+        if e <= TOLERANCE {
+            Conic::Circle
+        } else if (e - 1.).abs() <= TOLERANCE {
+            Conic::Parabola
+        } else if e < 1. {
+            Conic::Ellipse
+        } else {
+            Conic::Hyperbola
+        }
+    }
+
+    /// Returns the semi-major axis `a`, negative for hyperbolic orbits
+    /// and infinite for parabolic ones.
+    pub fn semi_major_axis(&self) -> Length {
+        Length::new(1. / self.inverse_semi_major_axis())
+    }
+
+    /// Returns the periapsis distance, the closest to the central body
+    /// (`q = p / (1 + e)`).
+    pub fn periapsis(&self) -> Length {
+        Length::new(self.elements.semi_latus_rectum.m() / (1. + self.elements.eccentricity))
+    }
+
+    /// Returns the apoapsis distance, the farthest from the central body
+    /// (`Q = p / (1 - e)`), or `None` for open orbits.
+    pub fn apoapsis(&self) -> Option<Length> {
+        let e = self.elements.eccentricity;
+        self.is_closed()
+            .then(|| Length::new(self.elements.semi_latus_rectum.m() / (1. - e)))
+    }
+
+    /// Returns `true` for circular and elliptic orbits.
+    pub fn is_closed(&self) -> bool {
+        matches![self.conic(), Conic::Circle | Conic::Ellipse]
+    }
+
+    /// Returns the orbital period (`T = 2π √(a³ / μ)`), or `None` for open orbits.
+    pub fn period(&self) -> Option<Time> {
+        self.is_closed().then(|| {
+            let a = self.semi_major_axis().m();
+            Time::new(TAU * (a * a * a / self.mu).sqrt())
+        })
+    }
+
+    /// Returns the specific orbital energy, in `J/kg` (`ε = -μ / 2a`).
+    ///
+    /// It's negative for closed orbits, zero for parabolic ones and positive
+    /// for hyperbolic ones.
+    pub fn specific_energy(&self) -> Magnitude {
+        -self.mu * self.inverse_semi_major_axis() / 2.
+    }
+
+    /// Returns the magnitude of the specific angular momentum, in `m²/s`
+    /// (`h = √(μ p)`).
+    pub fn specific_angular_momentum(&self) -> Magnitude {
+        (self.mu * self.elements.semi_latus_rectum.m()).sqrt()
+    }
+
+    /// Returns the distance to the central body at the current true anomaly.
+    pub fn radius(&self) -> Length {
+        self.calc_radius_at(self.elements.true_anomaly)
+    }
+
+    /// Returns the distance to the central body at the given true anomaly
+    /// (`r = p / (1 + e cos ν)`).
+    pub fn calc_radius_at(&self, true_anomaly: Magnitude) -> Length {
+        let el = &self.elements;
+        Length::new(el.semi_latus_rectum.m() / (1. + el.eccentricity * true_anomaly.cos()))
+    }
+
+    /// Returns the orbital [`Speed`] at the given distance from the central
+    /// body, by the [*vis-viva equation*][0] (`v² = μ (2/r - 1/a)`).
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Vis-viva_equation
+    pub fn calc_speed_at(&self, r: Length) -> Speed {
+        Speed::new((self.mu * (2. / r.m() - self.inverse_semi_major_axis())).sqrt())
+    }
+
+    /// Returns the current orbital [`Speed`].
+    pub fn speed(&self) -> Speed {
+        self.calc_speed_at(self.radius())
+    }
+
+    /// Returns the time elapsed since the passage through periapsis,
+    /// negative before it.
+    pub fn time_since_periapsis(&self) -> Time {
+        let el = &self.elements;
+        let (p, e) = (el.semi_latus_rectum.m(), el.eccentricity);
+        // wrapped to (-π, π]
+        let nu = PI - (PI - el.true_anomaly).rem_euclid(TAU);
+        let half_tan = (nu / 2.).tan();
+        Time::new(match self.conic() {
+            Conic::Circle | Conic::Ellipse => {
+                let ea = 2. * (((1. - e) / (1. + e)).sqrt() * half_tan).atan();
+                (ea - e * ea.sin()) / self.mean_motion()
+            }
+            Conic::Hyperbola => {
+                let ha = 2. * (((e - 1.) / (e + 1.)).sqrt() * half_tan).atanh();
+                (e * ha.sinh() - ha) / self.mean_motion()
+            }
+            Conic::Parabola => {
+                0.5 * (p * p * p / self.mu).sqrt() * (half_tan + half_tan.powi(3) / 3.)
+            }
+        })
+    }
+
+    /// Returns the mean motion `n` in `rad/s`, the mean angular speed
+    /// (`n = √(μ / |a|³)`), or `2 √(μ / p³)` for parabolic orbits.
+    pub fn mean_motion(&self) -> Magnitude {
+        if self.conic() == Conic::Parabola {
+            let p = self.elements.semi_latus_rectum.m();
+            2. * (self.mu / (p * p * p)).sqrt()
+        } else {
+            let a = self.semi_major_axis().m().abs();
+            (self.mu / (a * a * a)).sqrt()
+        }
+    }
+
+    /// Returns the orbit after the given [`Time`], by solving Kepler's equation.
+    ///
+    /// Only the true anomaly changes. The time can be negative.
+    pub fn propagate(&self, dt: Time) -> Self {
+        let el = &self.elements;
+        let e = el.eccentricity;
+        let t = self.time_since_periapsis().m() + dt.m();
+        let nu = match self.conic() {
+            Conic::Circle | Conic::Ellipse => {
+                let m = (self.mean_motion() * t + PI).rem_euclid(TAU) - PI;
+                let ea = solve_kepler_elliptic(m, e);
+                2. * ((1. + e).sqrt() * (ea / 2.).sin()).atan2((1. - e).sqrt() * (ea / 2.).cos())
+            }
+            Conic::Hyperbola => {
+                let ha = solve_kepler_hyperbolic(self.mean_motion() * t, e);
+                2. * (((e + 1.) / (e - 1.)).sqrt() * (ha / 2.).tanh()).atan()
+            }
+            Conic::Parabola => {
+                // Barker's equation, `D + D³/3 = n t`, solved with Cardano's formula
+                let b = 1.5 * self.mean_motion() * t;
+                let a = (b + (b * b + 1.).sqrt()).cbrt();
+                2. * (a - 1. / a).atan()
+            }
+        };
+        let mut orbit = *self;
+        orbit.elements.true_anomaly = nu.rem_euclid(TAU);
+        orbit
+    }
+
+    /// Returns the state vectors after the given [`Time`].
+    pub fn calc_state_after(&self, dt: Time) -> (Position, Velocity) {
+        self.propagate(dt).state()
+    }
+
+    /// Returns `1/a`, which is zero for parabolic orbits.
+    fn inverse_semi_major_axis(&self) -> Magnitude {
+        let e = self.elements.eccentricity;
+        (1. - e * e) / self.elements.semi_latus_rectum.m()
+    }
+}
+
+/// Returns the eccentric anomaly `E` for the mean anomaly `M`,
+/// solving `M = E - e sin E` with Newton's method.
+fn solve_kepler_elliptic(m: Magnitude, e: Magnitude) -> Magnitude {
+    let mut ea = if e < 0.8 { m } else { PI.copysign(m) };
+    for _ in 0..50 {
+        let delta = (ea - e * ea.sin() - m) / (1. - e * ea.cos());
+        ea -= delta;
+        if delta.abs() < 1e-14 {
+            break;
+        }
+    }
+    ea
+}
+
+/// Returns the hyperbolic anomaly `H` for the mean anomaly `M`,
+/// solving `M = e sinh H - H` with Newton's method.
+fn solve_kepler_hyperbolic(m: Magnitude, e: Magnitude) -> Magnitude {
+    let mut ha = (m / e).asinh();
+    for _ in 0..50 {
+        let delta = (e * ha.sinh() - ha - m) / (e * ha.cosh() - 1.);
+        ha -= delta;
+        if delta.abs() < 1e-14 * ha.abs().max(1.) {
+            break;
+        }
+    }
+    ha
+}
+
+/// Returns the arc cosine, clamping the argument to avoid rounding errors.
+fn acos(x: Magnitude) -> Magnitude {
+    x.clamp(-1., 1.).acos()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_eq::assert_float_eq;
+
+    fn assert_state_eq(a: (Position, Velocity), b: (Position, Velocity)) {
+        let scale = a.0.magnitude();
+        assert_float_eq!(0., (a.0 - b.0).magnitude() / scale, abs <= 1e-9);
+        let scale = a.1.m();
+        assert_float_eq!(0., (a.1.d - b.1.d).magnitude() / scale, abs <= 1e-9);
+    }
+
+    /// Checks the conversions between elements and state vectors.
+    #[test]
+    fn orbit_state_vectors() {
+        let mu = CelestialBody::EARTH.gravitational_parameter();
+        for (a, e) in [(7e6, 0.), (2.4e7, 0.7), (-1.2e7, 1.8)] {
+            let elements = KeplerElements::new(Length::new(a), e, 0.9, 1.2, 2.1, 0.4);
+            let orbit = Orbit::new(mu, elements);
+            let back = Orbit::from_state(mu, orbit.state().0, orbit.state().1);
+            assert_state_eq(orbit.state(), back.state());
+            if e > 0. {
+                let el = back.elements;
+                assert_float_eq!(e, el.eccentricity, abs <= 1e-9);
+                assert_float_eq!(0.9, el.inclination, abs <= 1e-9);
+                assert_float_eq!(1.2, el.ascending_node, abs <= 1e-9);
+                assert_float_eq!(2.1, el.argument_of_periapsis, abs <= 1e-9);
+                assert_float_eq!(0.4, el.true_anomaly, abs <= 1e-9);
+            }
+        }
+
+        // equatorial and parabolic
+        let elements = KeplerElements::from_periapsis(Length::new(7e6), 1., 0., 0., 0.5, 1.);
+        let orbit = Orbit::new(mu, elements);
+        assert_eq![Conic::Parabola, orbit.conic()];
+        let back = Orbit::from_state(mu, orbit.state().0, orbit.state().1);
+        assert_state_eq(orbit.state(), back.state());
+        assert_float_eq!(0., orbit.specific_energy(), abs <= 1e-9);
+        assert_float_eq!(
+            orbit.calc_speed_at(orbit.radius()).m(),
+            orbit.state().1.m(),
+            rmax <= 1e-12
+        );
+    }
+
+    /// Checks the orbital quantities.
+    #[test]
+    fn orbit_quantities() {
+        // the Earth around the Sun
+        let sun = CelestialBody::SUN;
+        let elements = KeplerElements::new(Length::ASTRONOMICAL_UNIT, 0.0167, 0., 0., 0., 0.);
+        let orbit = Orbit::around(&sun, elements);
+        let year = orbit.period().unwrap();
+        assert_float_eq!(1., year.m() / Time::JULIAN_YEAR.m(), abs <= 1e-3);
+        assert_float_eq!(
+            0.9833,
+            orbit.periapsis().m() / Length::ASTRONOMICAL_UNIT.m(),
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            1.0167,
+            orbit.apoapsis().unwrap().m() / Length::ASTRONOMICAL_UNIT.m(),
+            abs <= 1e-4
+        );
+        assert_float_eq!(30_290., orbit.speed().m(), abs <= 10.);
+
+        // a hyperbolic flyby has no period nor apoapsis
+        let flyby = Orbit::around(
+            &sun,
+            KeplerElements::new(Length::new(-1e11), 1.5, 0., 0., 0., 0.),
+        );
+        assert!(flyby.period().is_none() && flyby.apoapsis().is_none());
+        assert!(flyby.specific_energy() > 0.);
+    }
+
+    /// Checks the propagation for all the conics.
+    #[test]
+    fn orbit_propagation() {
+        let mu = CelestialBody::EARTH.gravitational_parameter();
+
+        // half a period from periapsis reaches the apoapsis
+        let orbit = Orbit::new(
+            mu,
+            KeplerElements::new(Length::new(2.4e7), 0.7, 0.3, 0., 0., 0.),
+        );
+        let half = Time::new(orbit.period().unwrap().m() / 2.);
+        let at = orbit.propagate(half);
+        assert_float_eq!(PI, at.elements.true_anomaly, abs <= 1e-9);
+        assert_float_eq!(orbit.apoapsis().unwrap().m(), at.radius().m(), rmax <= 1e-9);
+        // a full period comes back
+        assert_state_eq(
+            orbit.state(),
+            orbit.propagate(Time::new(half.m() * 2.)).state(),
+        );
+
+        for (e, p) in [(0.7, 1e7), (1., 1e7), (2.5, 1e7)] {
+            let orbit = Orbit::new(
+                mu,
+                KeplerElements::from_periapsis(Length::new(p), e, 0.5, 0.1, 0.2, -0.3),
+            );
+            let dt = Time::new(5_000.);
+            let later = orbit.propagate(dt);
+            assert_float_eq!(
+                orbit.time_since_periapsis().m() + dt.m(),
+                later.time_since_periapsis().m(),
+                abs <= 1e-6
+            );
+            assert_state_eq(orbit.state(), later.propagate(Time::new(-dt.m())).state());
+            // the energy is conserved
+            let (r, v) = later.state();
+            let energy = v.d.magnitude_squared() / 2. - mu / r.magnitude();
+            assert_float_eq!(orbit.specific_energy(), energy, abs <= 1e-3 * mu / p);
+        }
+    }
+}
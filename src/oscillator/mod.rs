@@ -0,0 +1,368 @@
+// fisica::oscillator
+//
+//! Harmonic oscillators
+//!
+//! A [`HarmonicOscillator`] is a [`Mass`] attached to a linear spring of some
+//! [`SpringConstant`] `k`, with an optional viscous damper of some
+//! [`DampingCoefficient`] `c` and an optional sinusoidal driving force
+//! (`m ẍ + c ẋ + k x = F₀ cos(ω t)`).
+//!
+//! Its motion has a closed-form solution at any [`Time`], which depends on
+//! the [`Damping`] regime, given by the *damping ratio* `ζ = c / 2√(k m)`.
+//!
+//! ## Pendulums
+//!
+//! A [`Pendulum`] behaves like a harmonic oscillator only for small
+//! amplitudes. Its exact period and nonlinear motion are computed as well,
+//! together with those of a [`PhysicalPendulum`] and the chaotic motion of a
+//! [`DoublePendulum`].
+//!
+//! # External links
+//! - <https://en.wikipedia.org/wiki/Harmonic_oscillator>
+//! - <https://en.wikipedia.org/wiki/Damping#Damped_sine_wave>
+//! - <https://en.wikipedia.org/wiki/Q_factor>
+//
+
+use core::f64::consts::TAU;
+
+use crate::units::{
+    DampingCoefficient, Energy, Force, Frequency, Length, Mass, Speed, SpringConstant, Time,
+};
+use crate::Magnitude;
+
+mod pendulum;
+
+pub use pendulum::{
+    DoublePendulum, DoublePendulumState, Pendulum, PendulumState, PhysicalPendulum,
+};
+
+/// The tolerance around a damping ratio of 1 considered critical damping.
+const CRITICAL_TOLERANCE: Magnitude = 1e-9;
+
+/// The damping regime of a [`HarmonicOscillator`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Damping {
+    /// No damping (`ζ = 0`): it oscillates forever.
+    Undamped,
+    /// Light damping (`ζ < 1`): it oscillates with a decaying amplitude.
+    Underdamped,
+    /// Critical damping (`ζ = 1`): it returns to equilibrium as fast as
+    /// possible without oscillating.
+    Critical,
+    /// Heavy damping (`ζ > 1`): it returns to equilibrium slowly without
+    /// oscillating.
+    Overdamped,
+}
+
+/// A sinusoidal driving force (`F(t) = F₀ cos(2π f t)`).
+#[derive(Clone, Copy, Debug)]
+pub struct Drive {
+    /// The amplitude `F₀`. Only its magnitude is used.
+    pub amplitude: Force,
+    pub frequency: Frequency,
+}
+
+impl Drive {
+    /// New Drive.
+    #[inline]
+    pub const fn new(amplitude: Force, frequency: Frequency) -> Self {
+        Self {
+            amplitude,
+            frequency,
+        }
+    }
+}
+
+/// A one-dimensional damped driven harmonic oscillator.
+///
+/// The displacement is measured from the equilibrium position.
+#[derive(Clone, Copy, Debug)]
+pub struct HarmonicOscillator {
+    pub mass: Mass,
+    pub stiffness: SpringConstant,
+    pub damping: DampingCoefficient,
+    pub drive: Option<Drive>,
+    /// The displacement at `t = 0`.
+    pub initial_position: Length,
+    /// The velocity at `t = 0`.
+    pub initial_velocity: Speed,
+}
+
+impl HarmonicOscillator {
+    /// New undamped and undriven `HarmonicOscillator`.
+    pub fn new(
+        mass: Mass,
+        stiffness: SpringConstant,
+        initial_position: Length,
+        initial_velocity: Speed,
+    ) -> Self {
+        Self {
+            mass,
+            stiffness,
+            damping: DampingCoefficient::new(0.),
+            drive: None,
+            initial_position,
+            initial_velocity,
+        }
+    }
+
+    /// Returns the oscillator with the given damping.
+    pub fn with_damping(mut self, damping: DampingCoefficient) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Returns the oscillator with the given driving force.
+    pub fn with_drive(mut self, drive: Drive) -> Self {
+        self.drive = Some(drive);
+        self
+    }
+}
+
+/// # Characteristics
+impl HarmonicOscillator {
+    /// Returns the natural angular frequency in `rad/s` (`ω₀ = √(k / m)`).
+    pub fn angular_frequency(&self) -> Magnitude {
+        (self.stiffness.m() / self.mass.m()).sqrt()
+    }
+
+    /// Returns the undamped natural [`Frequency`] (`f₀ = ω₀ / 2π`).
+    pub fn natural_frequency(&self) -> Frequency {
+        Frequency::new(self.angular_frequency() / TAU)
+    }
+
+    /// Returns the undamped period (`T = 2π √(m / k)`).
+    pub fn period(&self) -> Time {
+        Time::new(TAU / self.angular_frequency())
+    }
+
+    /// Returns the damping ratio (`ζ = c / 2√(k m)`).
+    pub fn damping_ratio(&self) -> Magnitude {
+        self.damping.m() / (2. * (self.stiffness.m() * self.mass.m()).sqrt())
+    }
+
+    /// Returns the quality factor (`Q = 1 / 2ζ`), infinite without damping.
+    pub fn quality_factor(&self) -> Magnitude {
+        1. / (2. * self.damping_ratio())
+    }
+
+    /// Returns the damping regime.
+    pub fn damping_regime(&self) -> Damping {
+        let zeta = self.damping_ratio();
+        if zeta == 0. {
+            Damping::Undamped
+        } else if (zeta - 1.).abs() <= CRITICAL_TOLERANCE {
+            Damping::Critical
+        } else if zeta < 1. {
+            Damping::Underdamped
+        } else {
+            Damping::Overdamped
+        }
+    }
+
+    /// Returns the damped [`Frequency`] of the free oscillations
+    /// (`f_d = f₀ √(1 - ζ²)`), or `None` if it doesn't oscillate.
+    pub fn damped_frequency(&self) -> Option<Frequency> {
+        let zeta = self.damping_ratio();
+        matches![
+            self.damping_regime(),
+            Damping::Undamped | Damping::Underdamped
+        ]
+        .then(|| Frequency::new(self.natural_frequency().m() * (1. - zeta * zeta).sqrt()))
+    }
+
+    /// Returns the amplitude of the steady-state response to the drive,
+    /// or `None` if undriven.
+    ///
+    /// It's infinite at resonance without damping.
+    pub fn steady_amplitude(&self) -> Option<Length> {
+        let drive = self.drive?;
+        let (w0, w) = (self.angular_frequency(), TAU * drive.frequency.m());
+        let f0 = drive.amplitude.m() / self.mass.m();
+        let (a, b) = (w0 * w0 - w * w, 2. * self.damping_ratio() * w0 * w);
+        Some(Length::new(f0 / a.hypot(b)))
+    }
+}
+
+/// # Motion
+impl HarmonicOscillator {
+    /// Returns the displacement at the given [`Time`].
+    pub fn position(&self, t: Time) -> Length {
+        Length::new(self.state(t.m()).0)
+    }
+
+    /// Returns the velocity at the given [`Time`].
+    pub fn velocity(&self, t: Time) -> Speed {
+        Speed::new(self.state(t.m()).1)
+    }
+
+    /// Returns the mechanical [`Energy`] at the given [`Time`], kinetic plus
+    /// elastic potential (`E = ½ m v² + ½ k x²`).
+    pub fn energy(&self, t: Time) -> Energy {
+        let (x, v) = self.state(t.m());
+        Energy::new(0.5 * self.mass.m() * v * v + 0.5 * self.stiffness.m() * x * x)
+    }
+
+    /// Returns the displacement and velocity at the time `t`, as the sum of
+    /// the steady-state response to the drive and the free response.
+    fn state(&self, t: Magnitude) -> (Magnitude, Magnitude) {
+        let (x0, v0) = (self.initial_position.m(), self.initial_velocity.m());
+        let (px, pv) = self.particular(t);
+        let (px0, pv0) = self.particular(0.);
+        let (hx, hv) = self.homogeneous(x0 - px0, v0 - pv0, t);
+        (px + hx, pv + hv)
+    }
+
+    /// Returns the free response at the time `t` for the initial conditions.
+    fn homogeneous(&self, x0: Magnitude, v0: Magnitude, t: Magnitude) -> (Magnitude, Magnitude) {
+        let (w0, zeta) = (self.angular_frequency(), self.damping_ratio());
+        match self.damping_regime() {
+            Damping::Undamped | Damping::Underdamped => {
+                let (a, wd) = (zeta * w0, w0 * (1. - zeta * zeta).sqrt());
+                let (c1, c2) = (x0, (v0 + a * x0) / wd);
+                let (sin, cos) = (wd * t).sin_cos();
+                let decay = (-a * t).exp();
+                (
+                    decay * (c1 * cos + c2 * sin),
+                    decay * ((c2 * wd - a * c1) * cos - (a * c2 + c1 * wd) * sin),
+                )
+            }
+            Damping::Critical => {
+                let (c1, c2) = (x0, v0 + w0 * x0);
+                let decay = (-w0 * t).exp();
+                (decay * (c1 + c2 * t), decay * (c2 - w0 * (c1 + c2 * t)))
+            }
+            Damping::Overdamped => {
+                let root = (zeta * zeta - 1.).sqrt();
+                let (r1, r2) = (-w0 * (zeta - root), -w0 * (zeta + root));
+                let c1 = (v0 - r2 * x0) / (r1 - r2);
+                let c2 = x0 - c1;
+                let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+                (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+            }
+        }
+    }
+
+    /// Returns the steady-state response to the drive at the time `t`.
+    fn particular(&self, t: Magnitude) -> (Magnitude, Magnitude) {
+        let Some(drive) = self.drive else {
+            return (0., 0.);
+        };
+        let (w0, w) = (self.angular_frequency(), TAU * drive.frequency.m());
+        let f0 = drive.amplitude.m() / self.mass.m();
+        let (a, b) = (w0 * w0 - w * w, 2. * self.damping_ratio() * w0 * w);
+        if a == 0. && b == 0. {
+            // undamped resonance: the amplitude grows linearly
+            let k = f0 / (2. * w0);
+            let (sin, cos) = (w0 * t).sin_cos();
+            return (k * t * sin, k * (sin + w0 * t * cos));
+        }
+        let amplitude = f0 / a.hypot(b);
+        let phase = w * t - b.atan2(a);
+        (amplitude * phase.cos(), -amplitude * w * phase.sin())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{math::rk4, Direction};
+    use float_eq::assert_float_eq;
+
+    /// Checks the free response against a numerical integration.
+    fn check_numerically(osc: &HarmonicOscillator) {
+        let (m, k, c) = (osc.mass.m(), osc.stiffness.m(), osc.damping.m());
+        let (x, v) = (osc.initial_position.m(), osc.initial_velocity.m());
+        let dt = 1e-4;
+        let force = |t: Magnitude| {
+            osc.drive
+                .map_or(0., |d| d.amplitude.m() * (TAU * d.frequency.m() * t).cos())
+        };
+        let accel = |t: Magnitude, x: Magnitude, v: Magnitude| (force(t) - c * v - k * x) / m;
+        // the time is integrated along, to drive the oscillator
+        let mut y = [x, v, 0.];
+        for _ in 0..30_000 {
+            y = rk4(y, dt, |[x, v, t]| [v, accel(t, x, v), 1.]);
+        }
+        let [x, v, _] = y;
+        let t = Time::new(3.);
+        assert_float_eq!(x, osc.position(t).m(), abs <= 1e-8);
+        assert_float_eq!(v, osc.velocity(t).m(), abs <= 1e-8);
+    }
+
+    /// Checks all the damping regimes.
+    #[test]
+    fn oscillator_regimes() {
+        let base = HarmonicOscillator::new(
+            Mass::new(2.),
+            SpringConstant::new(50.),
+            Length::new(0.1),
+            Speed::new(-0.3),
+        );
+        assert_float_eq!(5., base.angular_frequency(), r2nd <= Magnitude::EPSILON);
+        assert_eq![Damping::Undamped, base.damping_regime()];
+        assert!(base.quality_factor().is_infinite());
+        // the energy is conserved without damping
+        let e0 = base.energy(Time::new(0.)).m();
+        assert_float_eq!(e0, base.energy(Time::new(7.3)).m(), rmax <= 1e-12);
+
+        // the critical damping is `2 √(k m)`
+        for (c, regime) in [
+            (0., Damping::Undamped),
+            (4., Damping::Underdamped),
+            (20., Damping::Critical),
+            (50., Damping::Overdamped),
+        ] {
+            let osc = base.with_damping(DampingCoefficient::new(c));
+            assert_eq![regime, osc.damping_regime()];
+            check_numerically(&osc);
+        }
+        let light = base.with_damping(DampingCoefficient::new(4.));
+        assert_float_eq!(0.2, light.damping_ratio(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(2.5, light.quality_factor(), r2nd <= Magnitude::EPSILON);
+        let fd = light.damped_frequency().unwrap();
+        assert_float_eq!(5. * 0.96_f64.sqrt() / TAU, fd.m(), r2nd <= 1e-12);
+        assert!(light.energy(Time::new(3.)).m() < e0);
+    }
+
+    /// Checks the driven response.
+    #[test]
+    fn oscillator_driven() {
+        let osc = HarmonicOscillator::new(
+            Mass::new(2.),
+            SpringConstant::new(50.),
+            Length::new(0.),
+            Speed::new(0.),
+        );
+        let force = Force::new(Direction::new(1., 0., 0.));
+        for (c, f) in [(4., 0.5), (4., 5. / TAU), (0., 0.5), (0., 5. / TAU)] {
+            let driven = osc
+                .with_damping(DampingCoefficient::new(c))
+                .with_drive(Drive::new(force, Frequency::new(f)));
+            check_numerically(&driven);
+        }
+        // near resonance the amplitude is `Q` times the static deflection
+        let driven = osc
+            .with_damping(DampingCoefficient::new(1.))
+            .with_drive(Drive::new(force, osc.natural_frequency()));
+        let q = driven.quality_factor();
+        let amplitude = driven.steady_amplitude().unwrap().m();
+        assert_float_eq!(q * 1. / 50., amplitude, rmax <= 1e-12);
+    }
+
+    /// Computes the seconds pendulum mentioned in the docs of [`Time`],
+    /// as an oscillator of stiffness `m g / L`.
+    #[test]
+    fn oscillator_seconds_pendulum() {
+        let (mass, length) = (Mass::new(1.), 0.994);
+        let g = crate::units::Gfs::in_earth().m();
+        let osc = HarmonicOscillator::new(
+            mass,
+            SpringConstant::new(mass.m() * g / length),
+            Length::new(0.01),
+            Speed::new(0.),
+        );
+        // a single swing, half a period, takes about a second
+        assert_float_eq!(1., osc.period().m() / 2., abs <= 0.005);
+    }
+}
@@ -0,0 +1,380 @@
+// fisica::oscillator::pendulum
+//
+// Pendulums.
+
+use alloc::vec::Vec;
+use core::f64::consts::{PI, TAU};
+
+use crate::math::rk4;
+use crate::units::{Energy, Gfs, Length, Mass, MomentOfInertia, Time};
+use crate::{Magnitude, Position};
+
+/// A simple pendulum: a point bob hanging from a massless rigid rod of some
+/// [`Length`], swinging in a uniform [`Gfs`].
+///
+/// The angles are in radians from the downward vertical. The positions of the
+/// bob are relative to the pivot, in the `xy` plane, with `+y` upwards.
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Pendulum_(mechanics)>
+#[derive(Clone, Copy, Debug)]
+pub struct Pendulum {
+    pub length: Length,
+    /// The gravitational field. Only its magnitude is used.
+    pub gfs: Gfs,
+}
+
+/// The state of a [`Pendulum`] at some [`Time`].
+#[derive(Clone, Copy, Debug)]
+pub struct PendulumState {
+    pub time: Time,
+    /// The angle from the downward vertical.
+    pub angle: Magnitude,
+    /// The angular speed, in `rad/s`.
+    pub angular_speed: Magnitude,
+    /// The [`Position`] of the bob relative to the pivot.
+    pub bob: Position,
+}
+
+impl Pendulum {
+    /// New Pendulum.
+    #[inline]
+    pub const fn new(length: Length, gfs: Gfs) -> Self {
+        Self { length, gfs }
+    }
+
+    /// New `Pendulum` with the given small-angle `period`
+    /// (`L = g (T / 2π)²`).
+    ///
+    /// Without gravity the length is zero.
+    pub fn from_period(period: Time, gfs: Gfs) -> Self {
+        let k = period.m() / TAU;
+        Self::new(Length::new(gfs.m() * k * k), gfs)
+    }
+
+    /// Returns the period for small amplitudes (`T₀ = 2π √(L / g)`).
+    ///
+    /// Requires a positive length and field: it's infinite without gravity,
+    /// and NaN if the length is zero too.
+    pub fn small_angle_period(&self) -> Time {
+        Time::new(TAU * (self.length.m() / self.gfs.m()).sqrt())
+    }
+
+    /// Returns the exact period for the given `amplitude` (in radians), using
+    /// the complete elliptic integral of the first kind
+    /// (`T = 4 √(L / g) K(sin(θ₀ / 2))`).
+    ///
+    /// It grows with the amplitude, and is infinite for an amplitude of `π`.
+    /// Like the [small-angle period][Self::small_angle_period], it requires a
+    /// positive length and field.
+    pub fn calc_period(&self, amplitude: Magnitude) -> Time {
+        let k = (amplitude / 2.).sin().abs();
+        Time::new(4. * (self.length.m() / self.gfs.m()).sqrt() * calc_elliptic_k(k))
+    }
+
+    /// Returns the [`Position`] of the bob at the given `angle`.
+    pub fn calc_bob(&self, angle: Magnitude) -> Position {
+        let (sin, cos) = angle.sin_cos();
+        Position::new(sin, -cos, 0.) * self.length.m()
+    }
+
+    /// Simulates the nonlinear motion (`θ̈ = -(g / L) sin θ`) from the
+    /// initial `angle` and `angular_speed`, with a fixed time step `dt`,
+    /// returning the initial state and the state after each of the `steps`.
+    pub fn simulate(
+        &self,
+        angle: Magnitude,
+        angular_speed: Magnitude,
+        dt: Time,
+        steps: usize,
+    ) -> Vec<PendulumState> {
+        let k = self.gfs.m() / self.length.m();
+        let state = |i: usize, [angle, angular_speed]: [Magnitude; 2]| PendulumState {
+            time: Time::new(i as Magnitude * dt.m()),
+            angle,
+            angular_speed,
+            bob: self.calc_bob(angle),
+        };
+        let mut y = [angle, angular_speed];
+        let mut states = Vec::with_capacity(steps + 1);
+        states.push(state(0, y));
+        for i in 1..=steps {
+            y = rk4(y, dt.m(), |[a, w]| [w, -k * a.sin()]);
+            states.push(state(i, y));
+        }
+        states
+    }
+}
+
+/// A physical (compound) pendulum: a rigid body swinging around a pivot.
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Pendulum_(mechanics)#Compound_pendulum>
+#[derive(Clone, Copy, Debug)]
+pub struct PhysicalPendulum {
+    pub mass: Mass,
+    /// The [`MomentOfInertia`] about the pivot axis.
+    pub inertia: MomentOfInertia,
+    /// The distance from the pivot to the center of mass.
+    pub pivot_distance: Length,
+    /// The gravitational field. Only its magnitude is used.
+    pub gfs: Gfs,
+}
+
+impl PhysicalPendulum {
+    /// New PhysicalPendulum.
+    #[inline]
+    pub const fn new(
+        mass: Mass,
+        inertia: MomentOfInertia,
+        pivot_distance: Length,
+        gfs: Gfs,
+    ) -> Self {
+        Self {
+            mass,
+            inertia,
+            pivot_distance,
+            gfs,
+        }
+    }
+
+    /// Returns the period for small amplitudes (`T₀ = 2π √(I / m g d)`).
+    ///
+    /// Requires a positive mass, pivot distance and field, otherwise it's
+    /// infinite, or NaN if the inertia is zero too.
+    pub fn small_angle_period(&self) -> Time {
+        let mgd = self.mass.m() * self.gfs.m() * self.pivot_distance.m();
+        Time::new(TAU * (self.inertia.m() / mgd).sqrt())
+    }
+
+    /// Returns the length of the simple [`Pendulum`] with the same period
+    /// (`L = I / m d`).
+    ///
+    /// Requires a positive mass and pivot distance: it's infinite for a pivot
+    /// at the center of mass, which doesn't swing.
+    pub fn equivalent_length(&self) -> Length {
+        Length::new(self.inertia.m() / (self.mass.m() * self.pivot_distance.m()))
+    }
+}
+
+/// A double pendulum: a simple pendulum hanging from the bob of another one,
+/// with a chaotic motion for large amplitudes.
+///
+/// The angles are in radians from the downward vertical. The positions of the
+/// bobs are relative to the pivot, in the `xy` plane, with `+y` upwards.
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Double_pendulum>
+#[derive(Clone, Copy, Debug)]
+pub struct DoublePendulum {
+    /// The [`Mass`]es of the upper and lower bobs.
+    pub masses: [Mass; 2],
+    /// The [`Length`]s of the upper and lower rods.
+    pub lengths: [Length; 2],
+    /// The gravitational field. Only its magnitude is used.
+    pub gfs: Gfs,
+}
+
+/// The state of a [`DoublePendulum`] at some [`Time`].
+#[derive(Clone, Copy, Debug)]
+pub struct DoublePendulumState {
+    pub time: Time,
+    /// The angles of the upper and lower rods from the downward vertical.
+    pub angles: [Magnitude; 2],
+    /// The angular speeds of the upper and lower rods, in `rad/s`.
+    pub angular_speeds: [Magnitude; 2],
+    /// The [`Position`]s of the upper and lower bobs relative to the pivot.
+    pub bobs: [Position; 2],
+}
+
+impl DoublePendulum {
+    /// New DoublePendulum.
+    #[inline]
+    pub const fn new(masses: [Mass; 2], lengths: [Length; 2], gfs: Gfs) -> Self {
+        Self {
+            masses,
+            lengths,
+            gfs,
+        }
+    }
+
+    /// Returns the [`Position`]s of both bobs at the given angles.
+    pub fn calc_bobs(&self, angles: [Magnitude; 2]) -> [Position; 2] {
+        let (s1, c1) = angles[0].sin_cos();
+        let (s2, c2) = angles[1].sin_cos();
+        let upper = Position::new(s1, -c1, 0.) * self.lengths[0].m();
+        [
+            upper,
+            upper + Position::new(s2, -c2, 0.) * self.lengths[1].m(),
+        ]
+    }
+
+    /// Returns the mechanical [`Energy`] of a state, with the potential energy
+    /// measured from the pivot.
+    pub fn calc_energy(&self, angles: [Magnitude; 2], angular_speeds: [Magnitude; 2]) -> Energy {
+        let (m1, m2) = (self.masses[0].m(), self.masses[1].m());
+        let (l1, l2) = (self.lengths[0].m(), self.lengths[1].m());
+        let (w1, w2) = (angular_speeds[0], angular_speeds[1]);
+        let kinetic = 0.5 * m1 * l1 * l1 * w1 * w1
+            + 0.5
+                * m2
+                * (l1 * l1 * w1 * w1
+                    + l2 * l2 * w2 * w2
+                    + 2. * l1 * l2 * w1 * w2 * (angles[0] - angles[1]).cos());
+        let g = self.gfs.m();
+        let potential = -(m1 + m2) * g * l1 * angles[0].cos() - m2 * g * l2 * angles[1].cos();
+        Energy::new(kinetic + potential)
+    }
+
+    /// Simulates the motion from the initial `angles` and `angular_speeds`,
+    /// with a fixed time step `dt`, returning the initial state and the state
+    /// after each of the `steps`.
+    pub fn simulate(
+        &self,
+        angles: [Magnitude; 2],
+        angular_speeds: [Magnitude; 2],
+        dt: Time,
+        steps: usize,
+    ) -> Vec<DoublePendulumState> {
+        let (m1, m2) = (self.masses[0].m(), self.masses[1].m());
+        let (l1, l2) = (self.lengths[0].m(), self.lengths[1].m());
+        let g = self.gfs.m();
+        let derivative = |[a1, a2, w1, w2]: [Magnitude; 4]| {
+            let delta = a1 - a2;
+            let (sin, cos) = delta.sin_cos();
+            let den = 2. * m1 + m2 - m2 * (2. * delta).cos();
+            let acc1 = (-g * (2. * m1 + m2) * a1.sin()
+                - m2 * g * (a1 - 2. * a2).sin()
+                - 2. * sin * m2 * (w2 * w2 * l2 + w1 * w1 * l1 * cos))
+                / (l1 * den);
+            let acc2 = 2.
+                * sin
+                * (w1 * w1 * l1 * (m1 + m2) + g * (m1 + m2) * a1.cos() + w2 * w2 * l2 * m2 * cos)
+                / (l2 * den);
+            [w1, w2, acc1, acc2]
+        };
+        let state = |i: usize, [a1, a2, w1, w2]: [Magnitude; 4]| DoublePendulumState {
+            time: Time::new(i as Magnitude * dt.m()),
+            angles: [a1, a2],
+            angular_speeds: [w1, w2],
+            bobs: self.calc_bobs([a1, a2]),
+        };
+        let mut y = [angles[0], angles[1], angular_speeds[0], angular_speeds[1]];
+        let mut states = Vec::with_capacity(steps + 1);
+        states.push(state(0, y));
+        for i in 1..=steps {
+            y = rk4(y, dt.m(), derivative);
+            states.push(state(i, y));
+        }
+        states
+    }
+}
+
+/// Returns the complete elliptic integral of the first kind `K(k)`, using the
+/// arithmetic-geometric mean (`K = π / 2 AGM(1, √(1 - k²))`).
+fn calc_elliptic_k(k: Magnitude) -> Magnitude {
+    let (mut a, mut b) = (1., (1. - k * k).sqrt());
+    if b == 0. {
+        return Magnitude::INFINITY;
+    }
+    while (a - b).abs() > 1e-15 * a {
+        (a, b) = ((a + b) / 2., (a * b).sqrt());
+    }
+    PI / (2. * a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Direction;
+    use float_eq::assert_float_eq;
+
+    fn gfs(g: Magnitude) -> Gfs {
+        Gfs::new(Direction::new(0., g, 0.))
+    }
+
+    /// Checks the periods of simple and physical pendulums.
+    #[test]
+    fn pendulum_periods() {
+        // the seconds pendulum from the docs of `Time`
+        let earth = Gfs::in_earth();
+        let seconds = Pendulum::new(Length::new(0.994), earth);
+        assert_float_eq!(2., seconds.small_angle_period().m(), abs <= 0.005);
+        let back = Pendulum::from_period(Time::new(2.), earth);
+        assert_float_eq!(0.994, back.length.m(), abs <= 0.005);
+
+        // the exact period grows with the amplitude
+        let p = Pendulum::new(Length::new(1.), gfs(9.8));
+        let t0 = p.small_angle_period().m();
+        assert_float_eq!(t0, p.calc_period(1e-6).m(), rmax <= 1e-12);
+        assert_float_eq!(1.180_340_6, p.calc_period(PI / 2.).m() / t0, abs <= 1e-7);
+        assert!(p.calc_period(PI).m().is_infinite());
+
+        // a uniform rod swinging from an end
+        let (m, l) = (Mass::new(3.), 1.2);
+        let rod = PhysicalPendulum::new(
+            m,
+            MomentOfInertia::new(m.m() * l * l / 3.),
+            Length::new(l / 2.),
+            gfs(9.8),
+        );
+        assert_float_eq!(0.8, rod.equivalent_length().m(), r2nd <= 1e-15);
+        let simple = Pendulum::new(rod.equivalent_length(), gfs(9.8));
+        assert_float_eq!(
+            simple.small_angle_period().m(),
+            rod.small_angle_period().m(),
+            r2nd <= 1e-15
+        );
+
+        // without gravity they never swing back
+        let floating = Pendulum::new(Length::new(1.), gfs(0.));
+        assert!(floating.small_angle_period().m().is_infinite());
+        assert!(floating.calc_period(1.).m().is_infinite());
+        assert_eq![0., Pendulum::from_period(Time::new(2.), gfs(0.)).length.m()];
+        let floating = PhysicalPendulum {
+            gfs: gfs(0.),
+            ..rod
+        };
+        assert!(floating.small_angle_period().m().is_infinite());
+        assert_float_eq!(0.8, floating.equivalent_length().m(), r2nd <= 1e-15);
+    }
+
+    /// Checks the simulated swing matches the exact period.
+    #[test]
+    fn pendulum_simulation() {
+        let p = Pendulum::new(Length::new(1.), gfs(9.8));
+        let amplitude = PI / 3.;
+        let dt = Time::new(1e-3);
+        let states = p.simulate(amplitude, 0., dt, 3_000);
+        // the first time the bob comes back, a full period
+        let back = states
+            .windows(2)
+            .skip(10)
+            .find(|w| w[0].angular_speed > 0. && w[1].angular_speed <= 0.)
+            .unwrap();
+        assert_float_eq!(p.calc_period(amplitude).m(), back[1].time.m(), abs <= 2e-3);
+        assert_float_eq!(amplitude, back[1].angle, abs <= 1e-4);
+        for s in &states {
+            assert_float_eq!(1., s.bob.magnitude(), r2nd <= 1e-12);
+        }
+    }
+
+    /// Checks the double pendulum conserves the energy.
+    #[test]
+    fn pendulum_double() {
+        let dp = DoublePendulum::new(
+            [Mass::new(1.), Mass::new(2.)],
+            [Length::new(1.), Length::new(0.5)],
+            gfs(9.8),
+        );
+        let (angles, speeds) = ([PI / 2., PI], [0., 0.]);
+        let e0 = dp.calc_energy(angles, speeds).m();
+        let states = dp.simulate(angles, speeds, Time::new(1e-4), 50_000);
+        let last = states.last().unwrap();
+        let e = dp.calc_energy(last.angles, last.angular_speeds).m();
+        assert_float_eq!(e0, e, rmax <= 1e-6);
+        let [upper, lower] = last.bobs;
+        assert_float_eq!(1., upper.magnitude(), r2nd <= 1e-12);
+        assert_float_eq!(0.5, (lower - upper).magnitude(), r2nd <= 1e-12);
+    }
+}
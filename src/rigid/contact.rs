@@ -0,0 +1,434 @@
+// fisica::rigid::contact
+//
+// Impulse based contact response.
+
+use alloc::vec::Vec;
+
+use super::RigidBody;
+use crate::collision::Contact;
+use crate::math::{Matrix, V3};
+use crate::units::{Length, Momentum, Speed};
+use crate::{Direction, Magnitude, Position};
+
+/// The surface parameters of a contact between two bodies.
+#[derive(Clone, Copy, Debug)]
+pub struct ContactMaterial {
+    /// The coefficient of restitution, from 0 (perfectly inelastic)
+    /// to 1 (perfectly elastic).
+    pub restitution: Magnitude,
+    /// The coefficient of static friction, while the surfaces stick.
+    pub static_friction: Magnitude,
+    /// The coefficient of kinetic friction, while the surfaces slide.
+    pub kinetic_friction: Magnitude,
+}
+
+impl ContactMaterial {
+    /// New ContactMaterial.
+    #[inline]
+    pub const fn new(
+        restitution: Magnitude,
+        static_friction: Magnitude,
+        kinetic_friction: Magnitude,
+    ) -> Self {
+        Self {
+            restitution,
+            static_friction,
+            kinetic_friction,
+        }
+    }
+
+    /// New frictionless `ContactMaterial` with the given `restitution`.
+    pub const fn frictionless(restitution: Magnitude) -> Self {
+        Self::new(restitution, 0., 0.)
+    }
+}
+
+/// A [`Contact`] between the bodies at the indices `a` and `b` of a slice.
+///
+/// The contact normal must point from `a` towards `b`.
+#[derive(Clone, Debug)]
+pub struct ContactConstraint {
+    pub a: usize,
+    pub b: usize,
+    pub contact: Contact,
+    pub material: ContactMaterial,
+}
+
+impl ContactConstraint {
+    /// New ContactConstraint.
+    #[inline]
+    pub const fn new(a: usize, b: usize, contact: Contact, material: ContactMaterial) -> Self {
+        Self {
+            a,
+            b,
+            contact,
+            material,
+        }
+    }
+}
+
+/// A sequential impulse contact solver.
+#[derive(Clone, Copy, Debug)]
+pub struct ContactSolver {
+    /// The number of passes over all the contacts. More iterations let the
+    /// impulses propagate through stacks.
+    pub iterations: usize,
+    /// The fraction of the penetration removed by the positional correction.
+    pub correction: Magnitude,
+    /// The penetration allowed without correction, which avoids jitter.
+    pub slop: Length,
+    /// The approach [`Speed`] under which contacts don't bounce,
+    /// so that resting bodies settle.
+    pub restitution_threshold: Speed,
+}
+
+impl Default for ContactSolver {
+    fn default() -> Self {
+        Self {
+            iterations: 10,
+            correction: 0.8,
+            slop: Length::new(0.005),
+            restitution_threshold: Speed::new(0.5),
+        }
+    }
+}
+
+/// The solver state of a contact point.
+struct PointState {
+    position: Position,
+    ra: V3,
+    rb: V3,
+    normal_mass: Magnitude,
+    /// The target normal relative speed, for restitution.
+    bias: Magnitude,
+    /// The accumulated normal impulse.
+    jn: Magnitude,
+    /// The accumulated tangential impulse.
+    jt: V3,
+}
+
+/// The solver state of a contact constraint.
+pub(super) struct ConstraintState {
+    a: usize,
+    b: usize,
+    normal: V3,
+    inv_inertia_a: Matrix,
+    inv_inertia_b: Matrix,
+    points: Vec<PointState>,
+}
+
+impl ConstraintState {
+    /// Returns the inverse of the effective mass along the direction `d`.
+    fn calc_inverse_mass(&self, bodies: &[RigidBody], p: &PointState, d: V3) -> Magnitude {
+        let ia = (self.inv_inertia_a * p.ra.cross(d)).cross(p.ra);
+        let ib = (self.inv_inertia_b * p.rb.cross(d)).cross(p.rb);
+        bodies[self.a].inverse_mass + bodies[self.b].inverse_mass + d.dot(ia + ib)
+    }
+
+    /// Returns the velocity of `b` relative to `a` at the contact point.
+    fn relative_velocity(&self, bodies: &[RigidBody], p: &PointState) -> V3 {
+        (bodies[self.b].velocity_at(p.position).d - bodies[self.a].velocity_at(p.position).d)
+            .vector()
+    }
+
+    /// Applies the `impulse` to `b`, and its opposite to `a`.
+    fn apply(&self, bodies: &mut [RigidBody], position: Position, impulse: V3) {
+        let j = Direction::from_vector(impulse);
+        bodies[self.a].apply_impulse(Momentum::new(-j), position);
+        bodies[self.b].apply_impulse(Momentum::new(j), position);
+    }
+}
+
+impl ContactSolver {
+    /// New `ContactSolver` with the given number of `iterations`,
+    /// and the default parameters otherwise.
+    pub fn new(iterations: usize) -> Self {
+        Self {
+            iterations,
+            ..Self::default()
+        }
+    }
+
+    /// Resolves the contacts between the `bodies`, by changing their
+    /// velocities and correcting their positions.
+    ///
+    /// Returns the total impulse applied to the body `b` of each constraint.
+    ///
+    /// # Panics
+    /// Panics if any constraint indexes a body out of bounds.
+    pub fn solve(
+        &self,
+        bodies: &mut [RigidBody],
+        constraints: &[ContactConstraint],
+    ) -> Vec<Momentum> {
+        let impulses = self.solve_velocities(bodies, constraints);
+        self.correct_positions(bodies, constraints);
+        impulses
+    }
+
+    /// Resolves a single contact between the bodies `a` and `b`.
+    ///
+    /// Returns the impulse applied to `b`. The one applied to `a` is its opposite.
+    pub fn solve_pair(
+        &self,
+        a: &mut RigidBody,
+        b: &mut RigidBody,
+        contact: &Contact,
+        material: ContactMaterial,
+    ) -> Momentum {
+        let mut bodies = [*a, *b];
+        let constraint = ContactConstraint::new(0, 1, contact.clone(), material);
+        let impulse = self.solve(&mut bodies, &[constraint])[0];
+        [*a, *b] = bodies;
+        impulse
+    }
+
+    /// Applies the contact impulses, without correcting the positions.
+    ///
+    /// Returns the total impulse applied to the body `b` of each constraint.
+    ///
+    /// # Panics
+    /// Panics if any constraint indexes a body out of bounds.
+    pub fn solve_velocities(
+        &self,
+        bodies: &mut [RigidBody],
+        constraints: &[ContactConstraint],
+    ) -> Vec<Momentum> {
+        let mut states = self.prepare_all(bodies, constraints);
+        for _ in 0..self.iterations.max(1) {
+            Self::iterate(bodies, &mut states, constraints);
+        }
+        Self::collect_impulses(&states)
+    }
+
+    /// Precomputes the solver state of every constraint.
+    pub(super) fn prepare_all(
+        &self,
+        bodies: &[RigidBody],
+        constraints: &[ContactConstraint],
+    ) -> Vec<ConstraintState> {
+        constraints
+            .iter()
+            .map(|c| self.prepare(bodies, c))
+            .collect()
+    }
+
+    /// Makes a single pass of impulses over all the constraints.
+    pub(super) fn iterate(
+        bodies: &mut [RigidBody],
+        states: &mut [ConstraintState],
+        constraints: &[ContactConstraint],
+    ) {
+        for (state, c) in states.iter_mut().zip(constraints) {
+            let n = state.normal;
+            // normal impulses, which can only push
+            for i in 0..state.points.len() {
+                let p = &state.points[i];
+                let vn = state.relative_velocity(bodies, p).dot(n);
+                let jn = (p.jn + (p.bias - vn) * p.normal_mass).max(0.);
+                let (dj, position) = (jn - p.jn, p.position);
+                state.points[i].jn = jn;
+                state.apply(bodies, position, n * dj);
+            }
+            // friction impulses, within the Coulomb cone
+            for i in 0..state.points.len() {
+                let p = &state.points[i];
+                let v = state.relative_velocity(bodies, p);
+                let vt = v - n * v.dot(n);
+                let speed = vt.length();
+                if speed <= Magnitude::EPSILON {
+                    continue;
+                }
+                let t = vt / speed;
+                let k = state.calc_inverse_mass(bodies, p, t);
+                if k <= 0. {
+                    continue;
+                }
+                let mut jt = p.jt - t * (speed / k);
+                let m = &c.material;
+                if jt.length() > m.static_friction * p.jn {
+                    jt = jt.normalize_or_zero() * m.kinetic_friction * p.jn;
+                }
+                let (dj, position) = (jt - p.jt, p.position);
+                state.points[i].jt = jt;
+                state.apply(bodies, position, dj);
+            }
+        }
+    }
+
+    /// Returns the total impulse applied to the body `b` of each constraint.
+    pub(super) fn collect_impulses(states: &[ConstraintState]) -> Vec<Momentum> {
+        states
+            .iter()
+            .map(|s| {
+                let total = s
+                    .points
+                    .iter()
+                    .fold(V3::ZERO, |acc, p| acc + s.normal * p.jn + p.jt);
+                Momentum::new(Direction::from_vector(total))
+            })
+            .collect()
+    }
+
+    /// Moves the bodies apart along the contact normals to remove a fraction
+    /// of their penetration, in proportion to their inverse masses.
+    ///
+    /// # Panics
+    /// Panics if any constraint indexes a body out of bounds.
+    pub fn correct_positions(&self, bodies: &mut [RigidBody], constraints: &[ContactConstraint]) {
+        for c in constraints {
+            let (ima, imb) = (bodies[c.a].inverse_mass, bodies[c.b].inverse_mass);
+            let depth = (c.contact.depth().m() - self.slop.m()).max(0.);
+            if ima + imb <= 0. || depth <= 0. {
+                continue;
+            }
+            let shift = c.contact.normal * (depth * self.correction / (ima + imb));
+            bodies[c.a].position -= shift * ima;
+            bodies[c.b].position += shift * imb;
+        }
+    }
+
+    /// Precomputes the solver state of a constraint.
+    fn prepare(&self, bodies: &[RigidBody], c: &ContactConstraint) -> ConstraintState {
+        let (a, b) = (&bodies[c.a], &bodies[c.b]);
+        let mut state = ConstraintState {
+            a: c.a,
+            b: c.b,
+            normal: c.contact.normal.vector(),
+            inv_inertia_a: a.world_inverse_inertia(),
+            inv_inertia_b: b.world_inverse_inertia(),
+            points: Vec::with_capacity(c.contact.points.len()),
+        };
+        for cp in &c.contact.points {
+            let mut p = PointState {
+                position: cp.position,
+                ra: (cp.position - a.position).vector(),
+                rb: (cp.position - b.position).vector(),
+                normal_mass: 0.,
+                bias: 0.,
+                jn: 0.,
+                jt: V3::ZERO,
+            };
+            let k = state.calc_inverse_mass(bodies, &p, state.normal);
+            p.normal_mass = if k > 0. { 1. / k } else { 0. };
+            let vn = state.relative_velocity(bodies, &p).dot(state.normal);
+            if vn < -self.restitution_threshold.m() {
+                p.bias = -c.material.restitution * vn;
+            }
+            state.points.push(p);
+        }
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collision::{Contact, Obb, Plane};
+    use crate::mass_properties::MassProperties;
+    use crate::units::{Acceleration, InertiaTensor, Mass, Time, Velocity};
+    use crate::Orientation;
+    use float_eq::assert_float_eq;
+
+    /// A body that doesn't rotate.
+    fn particle(position: Position, velocity: Direction) -> RigidBody {
+        let mut body = RigidBody::new(position, Mass::new(1.), InertiaTensor::ZERO);
+        body.velocity = Velocity::new(velocity);
+        body
+    }
+
+    /// Checks restitution and friction.
+    #[test]
+    fn contact_impulses() {
+        let solver = ContactSolver::default();
+        let x = Direction::new(1., 0., 0.);
+
+        // elastic head-on collision between equal masses swaps the velocities
+        let mut a = particle(Position::ZERO, x);
+        let mut b = particle(Position::new(1.9, 0., 0.), -x);
+        let contact = Contact::single(x, Position::new(0.95, 0., 0.), Length::new(0.1));
+        let j = solver.solve_pair(&mut a, &mut b, &contact, ContactMaterial::frictionless(1.));
+        assert_float_eq!(-1., a.velocity.d.x(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(1., b.velocity.d.x(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(2., j.d.x(), r2nd <= Magnitude::EPSILON);
+        // and pushes them apart
+        assert!(b.position.x() - a.position.x() > 1.9);
+
+        // bounce on the ground
+        let up = Direction::new(0., 1., 0.);
+        let contact = Contact::single(up, Position::ZERO, Length::new(0.));
+        let mut ground = RigidBody::fixed(Position::ZERO);
+        let mut ball = particle(up, up * -2.);
+        solver.solve_pair(
+            &mut ground,
+            &mut ball,
+            &contact,
+            ContactMaterial::new(0.5, 0., 0.),
+        );
+        assert_float_eq!(1., ball.velocity.d.y(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(0., ground.velocity.m(), abs <= 0.);
+
+        // static friction stops slow sliding
+        let rough = ContactMaterial::new(0., 0.5, 0.3);
+        let mut ball = particle(up, Direction::new(0.1, -1., 0.));
+        solver.solve_pair(&mut ground, &mut ball, &contact, rough);
+        assert_float_eq!(0., ball.velocity.d.x(), abs <= 1e-12);
+        assert_float_eq!(0., ball.velocity.d.y(), abs <= 1e-12);
+
+        // kinetic friction only slows down fast sliding (Δv = μk Δvn)
+        let mut ball = particle(up, Direction::new(3., -1., 0.));
+        solver.solve_pair(&mut ground, &mut ball, &contact, rough);
+        assert_float_eq!(2.7, ball.velocity.d.x(), r2nd <= 1e-12);
+    }
+
+    /// Checks a stack of boxes comes to rest on the ground.
+    #[test]
+    fn contact_stack() {
+        let dt = Time::new(1. / 60.);
+        let gravity = Acceleration::new(Direction::new(0., -9.8, 0.));
+        let material = ContactMaterial::new(0.2, 0.6, 0.4);
+        let solver = ContactSolver::new(20);
+        let ground = Plane::new(Direction::new(0., 1., 0.), Length::new(0.));
+        let props = MassProperties::cuboid(
+            Length::new(1.),
+            Length::new(1.),
+            Length::new(1.),
+            Mass::new(1.),
+        );
+
+        let mut bodies = alloc::vec![RigidBody::fixed(Position::ZERO)];
+        for i in 0..3 {
+            let y = 0.5 + i as Magnitude;
+            bodies.push(RigidBody::from_mass_properties(
+                Position::new(0., y, 0.),
+                &props,
+            ));
+        }
+        let obb = |b: &RigidBody| Obb::new(b.position, Direction::ONE * 0.5, b.orientation);
+
+        for _ in 0..120 {
+            for body in bodies.iter_mut() {
+                body.integrate(dt, gravity);
+            }
+            let mut constraints = Vec::new();
+            for i in 1..bodies.len() {
+                if let Some(c) = obb(&bodies[i]).contact_plane(&ground) {
+                    constraints.push(ContactConstraint::new(i, 0, c, material));
+                }
+                if i + 1 < bodies.len() {
+                    if let Some(c) = obb(&bodies[i]).contact_obb(&obb(&bodies[i + 1])) {
+                        constraints.push(ContactConstraint::new(i, i + 1, c, material));
+                    }
+                }
+            }
+            solver.solve(&mut bodies, &constraints);
+        }
+
+        let top = &bodies[3];
+        assert_float_eq!(2.5, top.position.y(), abs <= 0.05);
+        assert_float_eq!(0., top.position.x(), abs <= 0.05);
+        assert!(top.velocity.m() < 0.1);
+        assert!(top.angular_velocity.m() < 0.1);
+        assert!(top.orientation.abs_diff_eq(Orientation::IDENTITY, 1e-2));
+    }
+}
@@ -0,0 +1,826 @@
+// fisica::rigid::joint
+//
+// Joints between rigid bodies.
+
+use alloc::vec::Vec;
+
+use super::{ContactConstraint, ContactSolver, RigidBody};
+use crate::math::{Matrix, V3};
+use crate::units::{DampingCoefficient, Force, Length, Momentum, SpringConstant, Time, Torque};
+use crate::{Direction, Magnitude, Orientation, Position};
+
+/// A motor driving a hinge at a target angular speed, with a limited
+/// [`Torque`].
+#[derive(Clone, Copy, Debug)]
+pub struct Motor {
+    /// The target angular speed of `b` relative to `a`, in `rad/s`.
+    pub speed: Magnitude,
+    /// The maximum torque. Only its magnitude is used.
+    pub max_torque: Torque,
+}
+
+impl Motor {
+    /// New Motor.
+    #[inline]
+    pub const fn new(speed: Magnitude, max_torque: Torque) -> Self {
+        Self { speed, max_torque }
+    }
+}
+
+/// The relative motion allowed by a [`Joint`].
+///
+/// The axes are given in the local space of the body `a`.
+#[derive(Clone, Copy, Debug)]
+pub enum JointKind {
+    /// Keeps the anchors together, allowing any rotation.
+    BallSocket,
+    /// Keeps the anchors together, allowing only the rotation around the
+    /// `axis`, optionally within the angle `limits` in radians, and driven
+    /// by a `motor`.
+    Hinge {
+        axis: Direction,
+        limits: Option<[Magnitude; 2]>,
+        motor: Option<Motor>,
+    },
+    /// Allows only the translation along the `axis`, optionally within the
+    /// offset `limits` between the anchors.
+    Slider {
+        axis: Direction,
+        limits: Option<[Length; 2]>,
+    },
+    /// Allows no relative motion.
+    Fixed,
+    /// Pulls the anchors together with a damped spring of the given `rest`
+    /// length, allowing any motion.
+    Spring {
+        rest: Length,
+        stiffness: SpringConstant,
+        damping: DampingCoefficient,
+    },
+}
+
+/// A joint between the bodies at the indices `a` and `b` of a slice.
+#[derive(Clone, Copy, Debug)]
+pub struct Joint {
+    pub a: usize,
+    pub b: usize,
+    /// The anchor point in the local space of `a`.
+    pub anchor_a: Position,
+    /// The anchor point in the local space of `b`.
+    pub anchor_b: Position,
+    /// The [`Orientation`] of `b` relative to `a` kept by the angular
+    /// constraints (`q_b = q_a r`).
+    pub reference: Orientation,
+    pub kind: JointKind,
+}
+
+impl Joint {
+    /// New `Joint` between the local anchors of the bodies `a` and `b`,
+    /// with both bodies aligned as the reference.
+    #[inline]
+    pub const fn new(
+        a: usize,
+        b: usize,
+        anchor_a: Position,
+        anchor_b: Position,
+        kind: JointKind,
+    ) -> Self {
+        Self {
+            a,
+            b,
+            anchor_a,
+            anchor_b,
+            reference: Orientation::IDENTITY,
+            kind,
+        }
+    }
+
+    /// New `Joint` at the given world `anchor`, taking the current relative
+    /// orientation of the bodies as the reference.
+    pub fn at(bodies: &[RigidBody], a: usize, b: usize, anchor: Position, kind: JointKind) -> Self {
+        let (ba, bb) = (&bodies[a], &bodies[b]);
+        Self {
+            a,
+            b,
+            anchor_a: (anchor - ba.position).rotate(ba.orientation.inverse()),
+            anchor_b: (anchor - bb.position).rotate(bb.orientation.inverse()),
+            reference: ba.orientation.inverse() * bb.orientation,
+            kind,
+        }
+    }
+
+    /// Returns the joint with the given reference [`Orientation`].
+    pub fn with_reference(mut self, reference: Orientation) -> Self {
+        self.reference = reference;
+        self
+    }
+
+    /// Returns the world [`Position`]s of both anchors.
+    pub fn anchors(&self, bodies: &[RigidBody]) -> [Position; 2] {
+        let (a, b) = (&bodies[self.a], &bodies[self.b]);
+        [
+            a.position + self.anchor_a.rotate(a.orientation),
+            b.position + self.anchor_b.rotate(b.orientation),
+        ]
+    }
+
+    /// Returns the rotation angle of a hinge, in radians from the reference,
+    /// positive counterclockwise around its axis.
+    ///
+    /// It's zero for the other kinds of joints.
+    pub fn hinge_angle(&self, bodies: &[RigidBody]) -> Magnitude {
+        let JointKind::Hinge { axis, .. } = self.kind else {
+            return 0.;
+        };
+        let (qa, qb) = (bodies[self.a].orientation, bodies[self.b].orientation);
+        let axis = axis.vector().normalize();
+        let u = axis.any_orthonormal_vector();
+        let (n, ua, ub) = (qa * axis, qa * u, qb * (self.reference.inverse() * u));
+        n.dot(ua.cross(ub)).atan2(ua.dot(ub))
+    }
+
+    /// Returns the offset between the anchors of a slider along its axis.
+    ///
+    /// It's zero for the other kinds of joints.
+    pub fn slider_offset(&self, bodies: &[RigidBody]) -> Length {
+        let JointKind::Slider { axis, .. } = self.kind else {
+            return Length::new(0.);
+        };
+        let [pa, pb] = self.anchors(bodies);
+        let n = axis.normalize().rotate(bodies[self.a].orientation);
+        Length::new((pb - pa).dot(n))
+    }
+}
+
+/// The [`Force`] and [`Torque`] a [`Joint`] applies to its body `b`,
+/// at its anchor. The ones applied to `a` are their opposites.
+#[derive(Clone, Copy, Debug)]
+pub struct JointReaction {
+    pub force: Force,
+    /// The couple transmitted by the joint, besides the force.
+    pub torque: Torque,
+}
+
+/// A sequential impulse joint solver.
+///
+/// The constraints of each joint are solved together as a block, which
+/// converges even when the masses and inertias are very different. After the
+/// velocities, the positions are corrected directly so that the joints don't
+/// drift apart. The errors beyond the limits are fed back into the velocities
+/// instead ([*Baumgarte stabilization*][0]).
+///
+/// [0]:https://box2d.org/files/ErinCatto_SequentialImpulses_GDC2006.pdf
+#[derive(Clone, Copy, Debug)]
+pub struct JointSolver {
+    /// The number of passes over all the joints.
+    pub iterations: usize,
+    /// The fraction of the error beyond the limits corrected per step.
+    pub correction: Magnitude,
+    /// The number of passes over all the joints to correct the positions.
+    pub position_iterations: usize,
+}
+
+impl Default for JointSolver {
+    fn default() -> Self {
+        Self {
+            iterations: 10,
+            correction: 0.2,
+            position_iterations: 4,
+        }
+    }
+}
+
+/// The maximum number of equality rows of a joint.
+const MAX_ROWS: usize = 6;
+
+/// The inverse masses and world inverse inertias of the bodies of a joint.
+struct Weights {
+    ima: Magnitude,
+    ia: Matrix,
+    imb: Magnitude,
+    ib: Matrix,
+}
+
+/// The Jacobian of a constraint row, for the linear and angular velocities
+/// of `a` and `b`.
+#[derive(Clone, Copy)]
+struct Jacobian {
+    la: V3,
+    aa: V3,
+    lb: V3,
+    ab: V3,
+}
+
+impl Jacobian {
+    /// A row keeping the anchors of `a` and `b` along `dir`.
+    fn linear(dir: V3, ra: V3, rb: V3) -> Self {
+        Self {
+            la: -dir,
+            aa: -ra.cross(dir),
+            lb: dir,
+            ab: rb.cross(dir),
+        }
+    }
+
+    /// A row keeping the rotation of `a` and `b` around `dir`.
+    fn angular(dir: V3) -> Self {
+        Self {
+            la: V3::ZERO,
+            aa: -dir,
+            lb: V3::ZERO,
+            ab: dir,
+        }
+    }
+
+    /// Returns `J W Kᵀ`, the velocity along this row caused by a unit
+    /// impulse along the row `k`.
+    fn calc_coupling(&self, k: &Jacobian, w: &Weights) -> Magnitude {
+        w.ima * self.la.dot(k.la)
+            + self.aa.dot(w.ia * k.aa)
+            + w.imb * self.lb.dot(k.lb)
+            + self.ab.dot(w.ib * k.ab)
+    }
+
+    /// Returns the velocity along the row.
+    fn calc_velocity(&self, bodies: &[RigidBody], a: usize, b: usize) -> Magnitude {
+        let (ba, bb) = (&bodies[a], &bodies[b]);
+        self.la.dot(ba.velocity.d.vector())
+            + self.aa.dot(ba.angular_velocity.d.vector())
+            + self.lb.dot(bb.velocity.d.vector())
+            + self.ab.dot(bb.angular_velocity.d.vector())
+    }
+}
+
+/// The equality rows of a joint and their position errors, in the current
+/// configuration of the bodies.
+struct Rows {
+    a: usize,
+    b: usize,
+    weights: Weights,
+    /// The anchor of `b`, relative to its center of mass.
+    rb: V3,
+    /// The world axis of hinges and sliders.
+    axis: V3,
+    /// The offset between the anchors.
+    offset: V3,
+    jacobians: Vec<Jacobian>,
+    errors: Vec<Magnitude>,
+}
+
+impl Rows {
+    /// Computes the rows of a joint.
+    fn new(bodies: &[RigidBody], joint: &Joint) -> Self {
+        let [pa, pb] = joint.anchors(bodies);
+        let (ba, bb) = (&bodies[joint.a], &bodies[joint.b]);
+        let (ra, rb) = ((pa - ba.position).vector(), (pb - bb.position).vector());
+        let (qa, qb) = (ba.orientation, bb.orientation);
+        let offset = (pb - pa).vector();
+        let mut rows = Self {
+            a: joint.a,
+            b: joint.b,
+            weights: Weights {
+                ima: ba.inverse_mass,
+                ia: ba.world_inverse_inertia(),
+                imb: bb.inverse_mass,
+                ib: bb.world_inverse_inertia(),
+            },
+            rb,
+            axis: V3::ZERO,
+            offset,
+            jacobians: Vec::with_capacity(MAX_ROWS),
+            errors: Vec::with_capacity(MAX_ROWS),
+        };
+        let point = |rows: &mut Self| {
+            for dir in [V3::X, V3::Y, V3::Z] {
+                rows.push(Jacobian::linear(dir, ra, rb), offset.dot(dir));
+            }
+        };
+        // the rotation of `b` from where it should be relative to `a`
+        let lock = |rows: &mut Self| {
+            let mut e = qb * (qa * joint.reference).inverse();
+            if e.w < 0. {
+                e = -e;
+            }
+            let e = V3::new(e.x, e.y, e.z) * 2.;
+            for dir in [V3::X, V3::Y, V3::Z] {
+                rows.push(Jacobian::angular(dir), e.dot(dir));
+            }
+        };
+        match joint.kind {
+            JointKind::BallSocket => point(&mut rows),
+            JointKind::Hinge { axis, .. } => {
+                point(&mut rows);
+                let axis = axis.vector().normalize();
+                let (na, nb) = (qa * axis, qb * (joint.reference.inverse() * axis));
+                let e = na.cross(nb);
+                let (t1, t2) = na.any_orthonormal_pair();
+                for dir in [t1, t2] {
+                    rows.push(Jacobian::angular(dir), e.dot(dir));
+                }
+                rows.axis = na;
+            }
+            JointKind::Slider { axis, .. } => {
+                lock(&mut rows);
+                let n = qa * axis.vector().normalize();
+                let (t1, t2) = n.any_orthonormal_pair();
+                for dir in [t1, t2] {
+                    rows.push(Jacobian::linear(dir, ra, rb), offset.dot(dir));
+                }
+                rows.axis = n;
+            }
+            JointKind::Fixed => {
+                point(&mut rows);
+                lock(&mut rows);
+            }
+            JointKind::Spring { .. } => {}
+        }
+        rows
+    }
+
+    fn push(&mut self, jacobian: Jacobian, error: Magnitude) {
+        self.jacobians.push(jacobian);
+        self.errors.push(error);
+    }
+
+    /// Returns the inverse of the effective mass matrix `J W Jᵀ`, or `None`
+    /// if it's singular.
+    fn calc_inverse_mass(&self) -> Option<[[Magnitude; MAX_ROWS]; MAX_ROWS]> {
+        let n = self.jacobians.len();
+        let mut m = [[0.; MAX_ROWS]; MAX_ROWS];
+        for (row, ji) in m.iter_mut().zip(&self.jacobians) {
+            for (m, jj) in row.iter_mut().zip(&self.jacobians) {
+                *m = ji.calc_coupling(jj, &self.weights);
+            }
+        }
+        invert(m, n)
+    }
+}
+
+/// A one-sided or bounded constraint row of a joint: a limit or a motor.
+struct Row {
+    jacobian: Jacobian,
+    /// The effective mass, accounting for the equality rows.
+    mass: Magnitude,
+    /// The target velocity error.
+    bias: Magnitude,
+    /// The accumulated impulse, and its bounds.
+    lambda: Magnitude,
+    min: Magnitude,
+    max: Magnitude,
+}
+
+/// The solver state of a joint.
+struct JointState {
+    rows: Rows,
+    /// The inverse effective mass of the equality rows.
+    inverse: Option<[[Magnitude; MAX_ROWS]; MAX_ROWS]>,
+    limits: Vec<Row>,
+    /// The accumulated linear impulse and couple on `b`.
+    linear: V3,
+    angular: V3,
+}
+
+impl JointState {
+    /// Applies an impulse `lambda` along a row.
+    fn apply(&mut self, bodies: &mut [RigidBody], j: &Jacobian, lambda: Magnitude) {
+        let w = &self.rows.weights;
+        let (a, b) = (self.rows.a, self.rows.b);
+        let d = Direction::from_vector;
+        bodies[a].velocity.d += d(j.la * (w.ima * lambda));
+        bodies[a].angular_velocity.d += d(w.ia * j.aa * lambda);
+        bodies[b].velocity.d += d(j.lb * (w.imb * lambda));
+        bodies[b].angular_velocity.d += d(w.ib * j.ab * lambda);
+        self.linear += j.lb * lambda;
+        self.angular += (j.ab - self.rows.rb.cross(j.lb)) * lambda;
+    }
+
+    /// Makes a single pass over the limits and the equality rows.
+    fn iterate(&mut self, bodies: &mut [RigidBody]) {
+        let (a, b) = (self.rows.a, self.rows.b);
+        for i in 0..self.limits.len() {
+            let row = &self.limits[i];
+            let v = row.jacobian.calc_velocity(bodies, a, b);
+            let lambda = (row.lambda - row.mass * (v + row.bias)).clamp(row.min, row.max);
+            let (delta, jacobian) = (lambda - row.lambda, row.jacobian);
+            self.limits[i].lambda = lambda;
+            self.apply(bodies, &jacobian, delta);
+        }
+        let Some(inverse) = self.inverse else {
+            return;
+        };
+        let mut v = [0.; MAX_ROWS];
+        for (v, j) in v.iter_mut().zip(&self.rows.jacobians) {
+            *v = j.calc_velocity(bodies, a, b);
+        }
+        let n = self.rows.jacobians.len();
+        for (i, inverse) in inverse.iter().enumerate().take(n) {
+            let (lambda, jacobian) = (-dot(inverse, &v), self.rows.jacobians[i]);
+            self.apply(bodies, &jacobian, lambda);
+        }
+    }
+}
+
+impl JointSolver {
+    /// New `JointSolver` with the given number of `iterations`,
+    /// and the default parameters otherwise.
+    pub fn new(iterations: usize) -> Self {
+        Self {
+            iterations,
+            ..Self::default()
+        }
+    }
+
+    /// Enforces the joints between the `bodies` over a step of [`Time`],
+    /// by changing their velocities and correcting their positions.
+    ///
+    /// Returns the reaction of each joint.
+    ///
+    /// # Panics
+    /// Panics if any joint indexes a body out of bounds.
+    pub fn solve(
+        &self,
+        bodies: &mut [RigidBody],
+        joints: &[Joint],
+        dt: Time,
+    ) -> Vec<JointReaction> {
+        self.solve_with_contacts(bodies, joints, dt, &ContactSolver::new(0), &[])
+            .0
+    }
+
+    /// Enforces the joints and resolves the contacts together, alternating
+    /// their iterations so that the impulses propagate between them, and
+    /// then corrects the positions.
+    ///
+    /// Makes as many iterations as the largest of both solvers. Returns the
+    /// reaction of each joint, and the total impulse applied to the body `b`
+    /// of each contact constraint.
+    ///
+    /// # Panics
+    /// Panics if any joint or constraint indexes a body out of bounds.
+    pub fn solve_with_contacts(
+        &self,
+        bodies: &mut [RigidBody],
+        joints: &[Joint],
+        dt: Time,
+        contact_solver: &ContactSolver,
+        contacts: &[ContactConstraint],
+    ) -> (Vec<JointReaction>, Vec<Momentum>) {
+        let h = dt.m();
+        let mut states: Vec<JointState> =
+            joints.iter().map(|j| self.prepare(bodies, j, h)).collect();
+        let mut contact_states = contact_solver.prepare_all(bodies, contacts);
+        let iterations = self.iterations.max(contact_solver.iterations).max(1);
+        for i in 0..iterations {
+            if i < self.iterations.max(1) {
+                for state in states.iter_mut() {
+                    state.iterate(bodies);
+                }
+            }
+            if i < contact_solver.iterations {
+                ContactSolver::iterate(bodies, &mut contact_states, contacts);
+            }
+        }
+        contact_solver.correct_positions(bodies, contacts);
+        self.correct_positions(bodies, joints);
+
+        let reactions = states
+            .iter()
+            .map(|s| JointReaction {
+                force: Force::new(Direction::from_vector(s.linear / h)),
+                torque: Torque::new(Direction::from_vector(s.angular / h)),
+            })
+            .collect();
+        (reactions, ContactSolver::collect_impulses(&contact_states))
+    }
+
+    /// Moves and rotates the bodies to remove the errors of the joints, in
+    /// proportion to their inverse masses and inertias.
+    ///
+    /// It makes `position_iterations` passes over all the joints, and
+    /// doesn't enforce the limits.
+    ///
+    /// # Panics
+    /// Panics if any joint indexes a body out of bounds.
+    pub fn correct_positions(&self, bodies: &mut [RigidBody], joints: &[Joint]) {
+        for _ in 0..self.position_iterations {
+            for joint in joints {
+                let rows = Rows::new(bodies, joint);
+                let Some(inverse) = rows.calc_inverse_mass() else {
+                    continue;
+                };
+                let (w, (a, b)) = (&rows.weights, (rows.a, rows.b));
+                let (mut la, mut aa, mut lb, mut ab) = (V3::ZERO, V3::ZERO, V3::ZERO, V3::ZERO);
+                for (j, inverse) in rows.jacobians.iter().zip(&inverse) {
+                    let lambda = -dot(inverse, &rows.errors);
+                    la += j.la * lambda;
+                    aa += j.aa * lambda;
+                    lb += j.lb * lambda;
+                    ab += j.ab * lambda;
+                }
+                bodies[a].position += Direction::from_vector(la * w.ima);
+                rotate(&mut bodies[a], w.ia * aa);
+                bodies[b].position += Direction::from_vector(lb * w.imb);
+                rotate(&mut bodies[b], w.ib * ab);
+            }
+        }
+    }
+
+    /// Precomputes the solver state of a joint, applying the impulse of
+    /// springs.
+    fn prepare(&self, bodies: &mut [RigidBody], joint: &Joint, h: Magnitude) -> JointState {
+        let rows = Rows::new(bodies, joint);
+        let inverse = rows.calc_inverse_mass();
+        let mut state = JointState {
+            rows,
+            inverse,
+            limits: Vec::new(),
+            linear: V3::ZERO,
+            angular: V3::ZERO,
+        };
+
+        let beta = self.correction / h;
+        // the speculative bias of a one-sided limit, allowing to reach it
+        let limit_bias = |c: Magnitude| if c < 0. { beta * c } else { c / h };
+        let inf = Magnitude::INFINITY;
+        let (n, offset) = (state.rows.axis, state.rows.offset);
+        match joint.kind {
+            JointKind::Hinge { limits, motor, .. } => {
+                let angle = joint.hinge_angle(bodies);
+                if let Some([min, max]) = limits {
+                    state.add_limit(Jacobian::angular(n), limit_bias(angle - min), 0., inf);
+                    state.add_limit(Jacobian::angular(-n), limit_bias(max - angle), 0., inf);
+                }
+                if let Some(motor) = motor {
+                    let limit = motor.max_torque.m() * h;
+                    state.add_limit(Jacobian::angular(n), -motor.speed, -limit, limit);
+                }
+            }
+            JointKind::Slider {
+                limits: Some([min, max]),
+                ..
+            } => {
+                let [pa, pb] = joint.anchors(bodies);
+                let (ra, rb) = (
+                    (pa - bodies[joint.a].position).vector(),
+                    (pb - bodies[joint.b].position).vector(),
+                );
+                let along = offset.dot(n);
+                let (lo, hi) = (along - min.m(), max.m() - along);
+                state.add_limit(Jacobian::linear(n, ra, rb), limit_bias(lo), 0., inf);
+                state.add_limit(Jacobian::linear(-n, ra, rb), limit_bias(hi), 0., inf);
+            }
+            JointKind::Spring {
+                rest,
+                stiffness,
+                damping,
+            } => {
+                let length = offset.length();
+                if length > 0. {
+                    let dir = offset / length;
+                    let [pa, pb] = joint.anchors(bodies);
+                    let (ra, rb) = (
+                        (pa - bodies[joint.a].position).vector(),
+                        (pb - bodies[joint.b].position).vector(),
+                    );
+                    let j = Jacobian::linear(dir, ra, rb);
+                    let closing = j.calc_velocity(bodies, joint.a, joint.b);
+                    let f = stiffness.m() * (length - rest.m()) + damping.m() * closing;
+                    state.apply(bodies, &j, -f * h);
+                }
+            }
+            _ => {}
+        }
+        state
+    }
+}
+
+impl JointState {
+    /// Adds a bounded row, with its effective mass through the equality rows
+    /// (`J W Jᵀ - u M⁻¹ u`, where `u = J_eq W Jᵀ`).
+    fn add_limit(&mut self, jacobian: Jacobian, bias: Magnitude, min: Magnitude, max: Magnitude) {
+        let w = &self.rows.weights;
+        let mut k = jacobian.calc_coupling(&jacobian, w);
+        if let Some(inverse) = &self.inverse {
+            let u: Vec<Magnitude> = self
+                .rows
+                .jacobians
+                .iter()
+                .map(|j| j.calc_coupling(&jacobian, w))
+                .collect();
+            for (ui, inverse) in u.iter().zip(inverse) {
+                k -= ui * dot(inverse, &u);
+            }
+        }
+        self.limits.push(Row {
+            jacobian,
+            mass: if k > Magnitude::EPSILON { 1. / k } else { 0. },
+            bias,
+            lambda: 0.,
+            min,
+            max,
+        });
+    }
+}
+
+/// Returns the dot product of two rows, up to the shortest.
+fn dot(a: &[Magnitude], b: &[Magnitude]) -> Magnitude {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// Rotates a body by the given rotation vector.
+fn rotate(body: &mut RigidBody, v: V3) {
+    if v != V3::ZERO {
+        body.orientation = (Orientation::from_scaled_axis(v) * body.orientation).normalize();
+    }
+}
+
+/// Returns the inverse of the top left `n × n` block of `m`, by Gauss–Jordan
+/// elimination, or `None` if it's singular.
+fn invert(
+    mut m: [[Magnitude; MAX_ROWS]; MAX_ROWS],
+    n: usize,
+) -> Option<[[Magnitude; MAX_ROWS]; MAX_ROWS]> {
+    if n == 0 {
+        return None;
+    }
+    let scale = (0..n).map(|i| m[i][i].abs()).fold(0., Magnitude::max);
+    let mut inv = [[0.; MAX_ROWS]; MAX_ROWS];
+    for (i, row) in inv.iter_mut().enumerate().take(n) {
+        row[i] = 1.;
+    }
+    for col in 0..n {
+        let pivot = (col..n).max_by(|x, y| m[*x][col].abs().total_cmp(&m[*y][col].abs()))?;
+        if m[pivot][col].abs() <= 1e-12 * scale {
+            return None;
+        }
+        m.swap(col, pivot);
+        inv.swap(col, pivot);
+        let p = m[col][col];
+        for k in 0..n {
+            m[col][k] /= p;
+            inv[col][k] /= p;
+        }
+        for row in 0..n {
+            if row != col {
+                let f = m[row][col];
+                for k in 0..n {
+                    m[row][k] -= f * m[col][k];
+                    inv[row][k] -= f * inv[col][k];
+                }
+            }
+        }
+    }
+    Some(inv)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mass_properties::MassProperties;
+    use crate::units::{Acceleration, Mass};
+    use float_eq::assert_float_eq;
+
+    fn gravity() -> Acceleration {
+        Acceleration::new(Direction::new(0., -9.8, 0.))
+    }
+
+    /// Advances the bodies and enforces the joints for a number of steps,
+    /// returning the last reactions.
+    fn run(
+        bodies: &mut [RigidBody],
+        joints: &[Joint],
+        g: Acceleration,
+        steps: usize,
+    ) -> Vec<JointReaction> {
+        let (dt, solver) = (Time::new(1. / 60.), JointSolver::new(20));
+        let mut reactions = Vec::new();
+        for _ in 0..steps {
+            for body in bodies.iter_mut() {
+                body.integrate(dt, g);
+            }
+            reactions = solver.solve(bodies, joints, dt);
+        }
+        reactions
+    }
+
+    fn ball(position: Position) -> RigidBody {
+        let props = MassProperties::sphere(Length::new(0.1), Mass::new(2.));
+        RigidBody::from_mass_properties(position, &props)
+    }
+
+    /// Checks a ball-socket pendulum and a welded cantilever.
+    #[test]
+    fn joint_ball_socket_fixed() {
+        // a pendulum swinging from the ground keeps its length
+        let mut bodies = [
+            RigidBody::fixed(Position::ZERO),
+            ball(Position::new(1., 0., 0.)),
+        ];
+        let joints = [Joint::at(
+            &bodies,
+            0,
+            1,
+            Position::ZERO,
+            JointKind::BallSocket,
+        )];
+        for _ in 0..4 {
+            run(&mut bodies, &joints, gravity(), 30);
+            assert_float_eq!(1., bodies[1].position.magnitude(), abs <= 0.01);
+        }
+
+        // hanging at rest, it holds the weight
+        let mut bodies = [
+            RigidBody::fixed(Position::ZERO),
+            ball(Position::new(0., -1., 0.)),
+        ];
+        let joints = [Joint::at(
+            &bodies,
+            0,
+            1,
+            Position::ZERO,
+            JointKind::BallSocket,
+        )];
+        let reaction = run(&mut bodies, &joints, gravity(), 60)[0];
+        assert_float_eq!(2. * 9.8, reaction.force.d.y(), r2nd <= 1e-6);
+
+        // a cantilever welded to a wall holds the weight and its moment
+        let mut bodies = [
+            RigidBody::fixed(Position::ZERO),
+            ball(Position::new(1., 0., 0.)),
+        ];
+        let joints = [Joint::at(&bodies, 0, 1, Position::ZERO, JointKind::Fixed)];
+        let reaction = run(&mut bodies, &joints, gravity(), 60)[0];
+        assert_float_eq!(0., bodies[1].position.y(), abs <= 1e-3);
+        assert_float_eq!(2. * 9.8, reaction.force.d.y(), r2nd <= 1e-3);
+        assert_float_eq!(2. * 9.8, reaction.torque.d.z(), r2nd <= 1e-3);
+    }
+
+    /// Checks a motorized hinge with limits.
+    #[test]
+    fn joint_hinge() {
+        let axis = Direction::new(0., 0., 1.);
+        let mut bodies = [
+            RigidBody::fixed(Position::ZERO),
+            ball(Position::new(1., 0., 0.)),
+        ];
+        let motor = Motor::new(2., Torque::new(Direction::new(0., 0., 100.)));
+        let free = JointKind::Hinge {
+            axis,
+            limits: None,
+            motor: Some(motor),
+        };
+        let joints = [Joint::at(&bodies, 0, 1, Position::ZERO, free)];
+        run(&mut bodies, &joints, Acceleration::new(Direction::ZERO), 30);
+        let w = bodies[1].angular_velocity.d;
+        assert_float_eq!(2., w.z(), abs <= 1e-6);
+        assert_float_eq!(0., w.x().abs() + w.y().abs(), abs <= 1e-6);
+        assert_float_eq!(1., joints[0].hinge_angle(&bodies), abs <= 0.1);
+
+        // the motor pushes against the upper limit
+        let limited = JointKind::Hinge {
+            axis,
+            limits: Some([-0.5, 0.5]),
+            motor: Some(motor),
+        };
+        let mut bodies = [
+            RigidBody::fixed(Position::ZERO),
+            ball(Position::new(1., 0., 0.)),
+        ];
+        let joints = [Joint::at(&bodies, 0, 1, Position::ZERO, limited)];
+        run(&mut bodies, &joints, Acceleration::new(Direction::ZERO), 60);
+        assert_float_eq!(0.5, joints[0].hinge_angle(&bodies), abs <= 0.01);
+        assert_float_eq!(1., bodies[1].position.magnitude(), abs <= 0.01);
+    }
+
+    /// Checks a slider and a spring joint.
+    #[test]
+    fn joint_slider_spring() {
+        // sliding along x until the limit, without falling
+        let slider = JointKind::Slider {
+            axis: Direction::new(1., 0., 0.),
+            limits: Some([Length::new(-1.), Length::new(1.)]),
+        };
+        let mut bodies = [RigidBody::fixed(Position::ZERO), ball(Position::ZERO)];
+        bodies[1].velocity.d = Direction::new(3., 0., 0.);
+        let joints = [Joint::at(&bodies, 0, 1, Position::ZERO, slider)];
+        run(&mut bodies, &joints, gravity(), 60);
+        assert_float_eq!(1., joints[0].slider_offset(&bodies).m(), abs <= 0.01);
+        assert_float_eq!(0., bodies[1].position.y(), abs <= 1e-3);
+        assert_float_eq!(0., bodies[1].angular_velocity.m(), abs <= 1e-9);
+
+        // a weight hanging from a damped spring settles at its static stretch
+        let spring = JointKind::Spring {
+            rest: Length::new(1.),
+            stiffness: SpringConstant::new(200.),
+            damping: DampingCoefficient::new(20.),
+        };
+        let mut bodies = [
+            RigidBody::fixed(Position::ZERO),
+            ball(Position::new(0., -1., 0.)),
+        ];
+        let joints = [Joint::new(0, 1, Position::ZERO, Position::ZERO, spring)];
+        let reaction = run(&mut bodies, &joints, gravity(), 600)[0];
+        assert_float_eq!(-1. - 2. * 9.8 / 200., bodies[1].position.y(), abs <= 1e-3);
+        assert_float_eq!(2. * 9.8, reaction.force.d.y(), r2nd <= 1e-3);
+    }
+}
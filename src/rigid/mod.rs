@@ -0,0 +1,200 @@
+// fisica::rigid
+//
+//! Rigid body dynamics
+//!
+//! A [`RigidBody`] moves as a whole, with a linear [`Velocity`] of its center
+//! of mass and an [`AngularVelocity`] around it.
+//!
+//! ## Contact response
+//!
+//! The [`ContactSolver`] resolves the [`Contact`][crate::collision::Contact]s
+//! between bodies by applying impulses, which change their [`Momentum`]:
+//! - the normal impulse stops the approach, bouncing back according to the
+//!   coefficient of restitution.
+//! - the tangential impulse opposes sliding, limited by [*Coulomb's law of
+//!   friction*][0]: bodies stick while the required impulse is within the
+//!   static friction cone, and slide with kinetic friction otherwise.
+//!
+//! Stacks of contacts converge by iterating over all of them several times
+//! ([*sequential impulses*][1]), and the remaining penetration is removed by
+//! a positional correction.
+//!
+//! ## Joints
+//!
+//! A [`Joint`] connects two bodies at an anchor, removing some of their
+//! relative degrees of freedom ([`JointKind`]): a ball-socket only keeps the
+//! anchors together, a hinge also restricts the rotation to an axis, a
+//! slider allows only a translation, and a fixed joint welds the bodies.
+//! Hinges and sliders can be limited, and hinges driven by a [`Motor`].
+//!
+//! The [`JointSolver`] enforces them with sequential impulses as well,
+//! optionally interleaved with the contacts, and returns the
+//! [`Force`][crate::units::Force] and [`Torque`][crate::units::Torque]
+//! transmitted by each joint.
+//!
+//! [0]:https://en.wikipedia.org/wiki/Friction#Dry_friction
+//! [1]:https://box2d.org/publications/
+//!
+//! # External links
+//! - <https://en.wikipedia.org/wiki/Rigid_body_dynamics>
+//! - <https://en.wikipedia.org/wiki/Collision_response>
+//
+
+use crate::mass_properties::MassProperties;
+use crate::math::Matrix;
+use crate::units::{
+    Acceleration, AngularImpulse, AngularVelocity, InertiaTensor, Mass, Momentum, Time, Velocity,
+};
+use crate::{Direction, Magnitude, Orientation, Position};
+
+mod contact;
+mod joint;
+
+pub use contact::{ContactConstraint, ContactMaterial, ContactSolver};
+pub use joint::{Joint, JointKind, JointReaction, JointSolver, Motor};
+
+/// A rigid body.
+///
+/// Its `position` is the center of mass, and its local axes are the ones
+/// the inertia tensor is expressed in.
+///
+/// A body with zero inverse mass and inverse inertia is static: it's not
+/// affected by impulses, like the ground.
+#[derive(Clone, Copy, Debug)]
+pub struct RigidBody {
+    pub position: Position,
+    pub orientation: Orientation,
+    pub velocity: Velocity,
+    pub angular_velocity: AngularVelocity,
+    /// The inverse of the mass, in `1/kg`.
+    pub inverse_mass: Magnitude,
+    /// The inverse of the inertia tensor about the center of mass,
+    /// in local space.
+    pub inverse_inertia: Matrix,
+}
+
+impl RigidBody {
+    /// New `RigidBody` at rest, with the given [`Mass`] and [`InertiaTensor`]
+    /// about its center of mass.
+    pub fn new(position: Position, mass: Mass, inertia: InertiaTensor) -> Self {
+        Self {
+            position,
+            orientation: Orientation::IDENTITY,
+            velocity: Velocity::new(Direction::ZERO),
+            angular_velocity: AngularVelocity::ZERO,
+            inverse_mass: if mass.m() > 0. { 1. / mass.m() } else { 0. },
+            inverse_inertia: inertia.inverse(),
+        }
+    }
+
+    /// New `RigidBody` at rest from its [`MassProperties`], with its center
+    /// of mass at the given `position`.
+    pub fn from_mass_properties(position: Position, props: &MassProperties) -> Self {
+        Self::new(position, props.mass, props.inertia)
+    }
+
+    /// New static `RigidBody`, with infinite mass.
+    pub fn fixed(position: Position) -> Self {
+        Self::new(position, Mass::new(0.), InertiaTensor::ZERO)
+    }
+
+    /// Returns `true` if the body is not affected by impulses.
+    pub fn is_static(&self) -> bool {
+        self.inverse_mass == 0. && self.inverse_inertia == Matrix::ZERO
+    }
+
+    /// Returns the [`Mass`], infinite for static bodies.
+    pub fn mass(&self) -> Mass {
+        Mass::new(1. / self.inverse_mass)
+    }
+
+    /// Returns the linear [`Momentum`] (`p = m v`).
+    pub fn momentum(&self) -> Momentum {
+        if self.inverse_mass == 0. {
+            Momentum::new(Direction::ZERO)
+        } else {
+            Momentum::new(self.velocity.d / self.inverse_mass)
+        }
+    }
+
+    /// Returns the inverse inertia tensor in world space (`R I⁻¹ Rᵀ`).
+    pub fn world_inverse_inertia(&self) -> Matrix {
+        let r = Matrix::from_quat(self.orientation);
+        r * self.inverse_inertia * r.transpose()
+    }
+
+    /// Returns the [`Velocity`] of the point of the body at the given world
+    /// [`Position`] (`v + ω × r`).
+    pub fn velocity_at(&self, point: Position) -> Velocity {
+        let r = point - self.position;
+        Velocity::new(self.velocity.d + self.angular_velocity.calc_velocity(r).d)
+    }
+
+    /// Applies an `impulse` at the given world [`Position`], changing the
+    /// linear and angular velocities.
+    ///
+    /// `Δv = J / m`, `Δω = I⁻¹ (r × J)`
+    pub fn apply_impulse(&mut self, impulse: Momentum, point: Position) {
+        let r = point - self.position;
+        self.velocity.d += impulse.d * self.inverse_mass;
+        let dw = self.world_inverse_inertia() * r.cross(impulse.d).vector();
+        self.angular_velocity.d += Direction::from_vector(dw);
+    }
+
+    /// Applies an angular `impulse`, changing only the angular velocity
+    /// (`Δω = I⁻¹ L`).
+    pub fn apply_angular_impulse(&mut self, impulse: AngularImpulse) {
+        let dw = self.world_inverse_inertia() * impulse.d.vector();
+        self.angular_velocity.d += Direction::from_vector(dw);
+    }
+
+    /// Advances the body in [`Time`] under a uniform [`Acceleration`],
+    /// using semi-implicit Euler integration.
+    ///
+    /// Static bodies don't move.
+    pub fn integrate(&mut self, dt: Time, gravity: Acceleration) {
+        if self.is_static() {
+            return;
+        }
+        self.velocity.d += gravity.d * dt.m();
+        self.position += self.velocity.d * dt.m();
+        self.orientation = self.angular_velocity.calc_orientation(self.orientation, dt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::Length;
+    use float_eq::assert_float_eq;
+
+    /// Checks the impulses change the momentum as expected.
+    #[test]
+    fn rigid_body_impulses() {
+        let props = MassProperties::sphere(Length::new(1.), Mass::new(2.));
+        let mut body = RigidBody::from_mass_properties(Position::ZERO, &props);
+        assert_float_eq!(2., body.mass().m(), r2nd <= Magnitude::EPSILON);
+
+        // an impulse through the center only changes the linear velocity
+        body.apply_impulse(Momentum::new(Direction::new(4., 0., 0.)), Position::ZERO);
+        assert_float_eq!(2., body.velocity.d.x(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(0., body.angular_velocity.m(), abs <= 0.);
+        assert_float_eq!(4., body.momentum().d.x(), r2nd <= Magnitude::EPSILON);
+
+        // an off-center impulse also makes it spin (I = 0.8 kg m²)
+        body.apply_impulse(
+            Momentum::new(Direction::new(0., 0.8, 0.)),
+            Position::new(1., 0., 0.),
+        );
+        assert_float_eq!(1., body.angular_velocity.d.z(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            1.,
+            body.velocity_at(Position::new(0., 1., 0.)).d.x(),
+            r2nd <= Magnitude::EPSILON
+        );
+
+        let ground = RigidBody::fixed(Position::ZERO);
+        assert!(ground.is_static());
+        assert_float_eq!(0., ground.momentum().m(), abs <= 0.);
+    }
+}
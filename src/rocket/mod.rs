@@ -0,0 +1,422 @@
+// fisica::rocket
+//
+//! Rocket propulsion
+//!
+//! An [`Engine`] produces a thrust [`Force`] by expelling propellant at the
+//! exhaust [`Speed`] `vₑ`, usually given as the *specific impulse* `Iₛₚ`, the
+//! [`Time`] a unit weight of propellant can produce a unit weight of thrust
+//! (`vₑ = Iₛₚ g₀`).
+//!
+//! The change in speed (*delta-v*) a vehicle can achieve follows the
+//! [*Tsiolkovsky rocket equation*][0] (`Δv = vₑ ln(m₀ / m_f)`). A [`Rocket`]
+//! stacks several [`Stage`]s, each one dropping its dry [`Mass`] when it burns
+//! out, so that the following stages don't need to accelerate it.
+//!
+//! [0]:https://en.wikipedia.org/wiki/Tsiolkovsky_rocket_equation
+//!
+//! # External links
+//! - <https://en.wikipedia.org/wiki/Specific_impulse>
+//! - <https://en.wikipedia.org/wiki/Multistage_rocket>
+//! - <https://en.wikipedia.org/wiki/Gravity_drag>
+//
+
+use alloc::vec::Vec;
+
+use crate::units::{Area, Density, Force, Gfs, Length, Mass, MassFlowRate, Speed, Time};
+use crate::{Direction, Magnitude};
+
+/// A rocket engine.
+#[derive(Clone, Copy, Debug)]
+pub struct Engine {
+    /// The thrust. Only its magnitude is used.
+    pub thrust: Force,
+    /// The specific impulse `Iₛₚ`.
+    pub specific_impulse: Time,
+}
+
+impl Engine {
+    /// New Engine.
+    #[inline]
+    pub const fn new(thrust: Force, specific_impulse: Time) -> Self {
+        Self {
+            thrust,
+            specific_impulse,
+        }
+    }
+
+    /// New `Engine` from the exhaust [`Speed`].
+    pub fn from_exhaust_speed(thrust: Force, ve: Speed) -> Self {
+        Self::new(thrust, Time::from_exhaust_speed(ve))
+    }
+
+    /// Returns the effective exhaust [`Speed`] (`vₑ = Iₛₚ g₀`).
+    pub fn exhaust_speed(&self) -> Speed {
+        Speed::from_specific_impulse(self.specific_impulse)
+    }
+
+    /// Returns the [`MassFlowRate`] of propellant (`ṁ = F / vₑ`).
+    pub fn mass_flow_rate(&self) -> MassFlowRate {
+        MassFlowRate::new(self.thrust.m() / self.exhaust_speed().m())
+    }
+
+    /// Returns the [`Time`] needed to burn the given propellant [`Mass`]
+    /// (`t = m / ṁ`).
+    pub fn calc_burn_time(&self, propellant: Mass) -> Time {
+        Time::new(propellant.m() / self.mass_flow_rate().m())
+    }
+}
+
+/// A rocket stage, with its own propellant and engines.
+#[derive(Clone, Copy, Debug)]
+pub struct Stage {
+    /// The [`Mass`] without propellant, dropped when the stage burns out.
+    pub dry_mass: Mass,
+    /// The [`Mass`] fully fueled.
+    pub wet_mass: Mass,
+    /// All the engines of the stage, combined.
+    pub engine: Engine,
+}
+
+impl Stage {
+    /// New Stage.
+    #[inline]
+    pub const fn new(dry_mass: Mass, wet_mass: Mass, engine: Engine) -> Self {
+        Self {
+            dry_mass,
+            wet_mass,
+            engine,
+        }
+    }
+
+    /// Returns the propellant [`Mass`].
+    pub fn propellant_mass(&self) -> Mass {
+        Mass::new(self.wet_mass.m() - self.dry_mass.m())
+    }
+
+    /// Returns the [`Time`] needed to burn all the propellant.
+    pub fn burn_time(&self) -> Time {
+        self.engine.calc_burn_time(self.propellant_mass())
+    }
+
+    /// Returns the delta-v of the stage carrying the given `payload` [`Mass`],
+    /// which includes any upper stages.
+    pub fn calc_delta_v(&self, payload: Mass) -> Speed {
+        self.engine.exhaust_speed().calc_delta_v(
+            Mass::new(self.wet_mass.m() + payload.m()),
+            Mass::new(self.dry_mass.m() + payload.m()),
+        )
+    }
+}
+
+/// A multistage rocket.
+#[derive(Clone, Debug)]
+pub struct Rocket {
+    /// The stages, in firing order.
+    pub stages: Vec<Stage>,
+    /// The [`Mass`] carried by the last stage.
+    pub payload: Mass,
+}
+
+impl Rocket {
+    /// New Rocket.
+    pub fn new(stages: Vec<Stage>, payload: Mass) -> Self {
+        Self { stages, payload }
+    }
+
+    /// Returns the total [`Mass`] at liftoff.
+    pub fn mass(&self) -> Mass {
+        Mass::new(
+            self.payload.m()
+                + self
+                    .stages
+                    .iter()
+                    .map(|s| s.wet_mass.m())
+                    .sum::<Magnitude>(),
+        )
+    }
+
+    /// Returns the delta-v of each stage, carrying the stages above it.
+    pub fn stage_delta_vs(&self) -> Vec<Speed> {
+        let mut above = self.payload.m();
+        let mut dvs: Vec<Speed> = self
+            .stages
+            .iter()
+            .rev()
+            .map(|s| {
+                let dv = s.calc_delta_v(Mass::new(above));
+                above += s.wet_mass.m();
+                dv
+            })
+            .collect();
+        dvs.reverse();
+        dvs
+    }
+
+    /// Returns the total delta-v, the sum of the delta-v of all the stages.
+    pub fn delta_v(&self) -> Speed {
+        Speed::new(self.stage_delta_vs().iter().map(|dv| dv.m()).sum())
+    }
+
+    /// Returns the total burn [`Time`] of all the stages, fired one after
+    /// the other.
+    pub fn burn_time(&self) -> Time {
+        Time::new(self.stages.iter().map(|s| s.burn_time().m()).sum())
+    }
+}
+
+/// The aerodynamic drag over a vertical ascent, in an exponential atmosphere
+/// (`ρ = ρ₀ e^(-h/H)`, `D = ½ ρ v² C_d A`).
+#[derive(Clone, Copy, Debug)]
+pub struct AscentDrag {
+    /// The reference cross-sectional [`Area`].
+    pub area: Area,
+    /// The drag coefficient `C_d`.
+    pub drag_coefficient: Magnitude,
+    /// The air [`Density`] at the ground `ρ₀`.
+    pub ground_density: Density,
+    /// The scale height `H` of the atmosphere.
+    pub scale_height: Length,
+}
+
+impl AscentDrag {
+    /// New AscentDrag.
+    #[inline]
+    pub const fn new(
+        area: Area,
+        drag_coefficient: Magnitude,
+        ground_density: Density,
+        scale_height: Length,
+    ) -> Self {
+        Self {
+            area,
+            drag_coefficient,
+            ground_density,
+            scale_height,
+        }
+    }
+
+    /// New `AscentDrag` in the atmosphere of the Earth
+    /// (`ρ₀ = 1.225 kg/m³`, `H = 8.5 km`).
+    pub const fn in_earth(area: Area, drag_coefficient: Magnitude) -> Self {
+        Self::new(
+            area,
+            drag_coefficient,
+            Density::new(1.225),
+            Length::new(8_500.),
+        )
+    }
+
+    /// Returns the drag [`Force`] at the given `altitude` and vertical `speed`,
+    /// opposing the motion along the `y` axis, with `+y` upwards.
+    pub fn calc_force(&self, altitude: Length, speed: Speed) -> Force {
+        let rho = self.ground_density.m() * (-altitude.m() / self.scale_height.m()).exp();
+        let v = speed.m();
+        let d = 0.5 * rho * v * v * self.drag_coefficient * self.area.m();
+        Force::new(Direction::new(0., -d * v.signum(), 0.))
+    }
+}
+
+/// The state of a [`Rocket`] during a vertical ascent.
+#[derive(Clone, Copy, Debug)]
+pub struct AscentState {
+    /// The [`Time`] since liftoff.
+    pub time: Time,
+    pub altitude: Length,
+    /// The vertical speed, negative when falling.
+    pub speed: Speed,
+    pub mass: Mass,
+    /// The index of the stage firing, or `None` after the last burnout.
+    pub stage: Option<usize>,
+}
+
+/// The result of a vertical ascent simulation.
+#[derive(Clone, Debug)]
+pub struct Ascent {
+    /// The states at every time step, from liftoff to the apex.
+    pub trajectory: Vec<AscentState>,
+    /// The state at the burnout of the last stage.
+    pub burnout: AscentState,
+    /// The state at the highest altitude.
+    pub apex: AscentState,
+    /// The delta-v lost to gravity during the burns (`∫ g dt`).
+    pub gravity_loss: Speed,
+    /// The delta-v lost to drag during the burns (`∫ D / m dt`).
+    pub drag_loss: Speed,
+}
+
+/// # Vertical ascent
+impl Rocket {
+    /// Simulates a vertical ascent from the ground, firing the stages one after
+    /// the other, in a uniform gravitational field `gfs` and with optional drag,
+    /// until the apex is reached. The last stage is not dropped.
+    ///
+    /// While the thrust doesn't overcome the weight, the rocket stays on the
+    /// ground. Without gravity there is no apex, and the simulation stops at
+    /// the burnout of the last stage.
+    ///
+    /// # Panics
+    /// Panics if `dt` is not positive, or if a stage has propellant but no
+    /// mass flow, so that it would burn forever.
+    pub fn simulate_ascent(&self, gfs: Gfs, drag: Option<AscentDrag>, dt: Time) -> Ascent {
+        assert!(dt.m() > 0.);
+        let g = gfs.m();
+        let (mut t, mut h, mut v) = (0., 0., 0.);
+        let mut mass = self.mass().m();
+        let (mut gravity_loss, mut drag_loss) = (0., 0.);
+        let state = |t, h, v, mass, stage| AscentState {
+            time: Time::new(t),
+            altitude: Length::new(h),
+            speed: Speed::new(v),
+            mass: Mass::new(mass),
+            stage,
+        };
+        let mut trajectory = alloc::vec![state(t, h, v, mass, self.stages.first().map(|_| 0))];
+
+        // the acceleration from drag, opposing the motion
+        let calc_drag = |h: Magnitude, v: Magnitude, mass: Magnitude| {
+            drag.map_or(0., |d| {
+                -d.calc_force(Length::new(h), Speed::new(v)).d.y() / mass
+            })
+        };
+
+        for (i, stage) in self.stages.iter().enumerate() {
+            let (thrust, rate) = (stage.engine.thrust.m(), stage.engine.mass_flow_rate().m());
+            let mut remaining = stage.burn_time().m();
+            assert!(
+                !remaining.is_infinite(),
+                "stage {i} has propellant but no mass flow"
+            );
+            while remaining > 0. {
+                let step = dt.m().min(remaining);
+                let a_drag = calc_drag(h, v, mass);
+                let mut a = thrust / mass - g - a_drag;
+                if h <= 0. && v <= 0. && a < 0. {
+                    a = 0.;
+                } else {
+                    gravity_loss += g * step;
+                    drag_loss += a_drag * step;
+                }
+                v += a * step;
+                h = (h + v * step).max(0.);
+                mass -= rate * step;
+                t += step;
+                remaining -= step;
+                trajectory.push(state(t, h, v, mass, Some(i)));
+            }
+            // the last stage stays attached to the payload
+            if i + 1 < self.stages.len() {
+                mass -= stage.dry_mass.m();
+            }
+            if let Some(last) = trajectory.last_mut() {
+                last.mass = Mass::new(mass);
+                last.stage = self.stages.get(i + 1).map(|_| i + 1);
+            }
+        }
+        let burnout = *trajectory.last().expect("some state");
+
+        // without gravity nothing slows the rocket down enough to stop it
+        while g > 0. && v > 0. {
+            v -= (g + calc_drag(h, v, mass)) * dt.m();
+            h = (h + v * dt.m()).max(0.);
+            t += dt.m();
+            trajectory.push(state(t, h, v, mass, None));
+        }
+        let apex = *trajectory
+            .iter()
+            .max_by(|a, b| a.altitude.m().total_cmp(&b.altitude.m()))
+            .expect("some state");
+
+        Ascent {
+            trajectory,
+            burnout,
+            apex,
+            gravity_loss: Speed::new(gravity_loss),
+            drag_loss: Speed::new(drag_loss),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::STANDARD_GRAVITY as G0;
+    use float_eq::assert_float_eq;
+
+    fn thrust(f: Magnitude) -> Force {
+        Force::new(Direction::new(0., f, 0.))
+    }
+
+    /// Checks the rocket equation for single and multiple stages.
+    #[test]
+    fn rocket_equation() {
+        let engine = Engine::new(thrust(845e3), Time::new(282.));
+        assert_float_eq!(2_765.5, engine.exhaust_speed().m(), abs <= 0.1);
+        assert_float_eq!(305.6, engine.mass_flow_rate().m(), abs <= 0.1);
+        let back = Engine::from_exhaust_speed(engine.thrust, engine.exhaust_speed());
+        assert_float_eq!(282., back.specific_impulse.m(), r2nd <= 1e-12);
+
+        let ve = Speed::new(3_000.);
+        let dv = ve.calc_delta_v(Mass::new(core::f64::consts::E), Mass::new(1.));
+        assert_float_eq!(3_000., dv.m(), r2nd <= 1e-12);
+
+        // two stages beat a single one with the same propellant and structure
+        let engine = Engine::from_exhaust_speed(thrust(1e6), ve);
+        let stages = alloc::vec![
+            Stage::new(Mass::new(8e3), Mass::new(80e3), engine),
+            Stage::new(Mass::new(2e3), Mass::new(20e3), engine),
+        ];
+        let staged = Rocket::new(stages, Mass::new(1e3));
+        let single = Rocket::new(
+            alloc::vec![Stage::new(Mass::new(10e3), Mass::new(100e3), engine)],
+            Mass::new(1e3),
+        );
+        assert_float_eq!(101e3, staged.mass().m(), r2nd <= 1e-12);
+        let dvs = staged.stage_delta_vs();
+        assert_float_eq!(3_000. * (101_f64 / 29.).ln(), dvs[0].m(), r2nd <= 1e-12);
+        assert_float_eq!(3_000. * (21_f64 / 3.).ln(), dvs[1].m(), r2nd <= 1e-12);
+        assert!(staged.delta_v().m() > single.delta_v().m() * 1.2);
+        assert_float_eq!(
+            single.burn_time().m(),
+            staged.burn_time().m(),
+            r2nd <= 1e-12
+        );
+    }
+
+    /// Checks the vertical ascent accounts for the losses.
+    #[test]
+    fn rocket_ascent() {
+        let engine = Engine::new(thrust(2e5), Time::new(300.));
+        let rocket = Rocket::new(
+            alloc::vec![Stage::new(Mass::new(1e3), Mass::new(10e3), engine)],
+            Mass::new(100.),
+        );
+        let gfs = Gfs::new(Direction::new(0., G0, 0.));
+        let dt = Time::new(0.01);
+
+        let ascent = rocket.simulate_ascent(gfs, None, dt);
+        let burnout = ascent.burnout;
+        assert_float_eq!(rocket.burn_time().m(), burnout.time.m(), abs <= 1e-6);
+        assert_float_eq!(G0 * burnout.time.m(), ascent.gravity_loss.m(), r2nd <= 1e-9);
+        let expected = rocket.delta_v().m() - ascent.gravity_loss.m();
+        assert_float_eq!(expected, burnout.speed.m(), rmax <= 1e-3);
+        assert_float_eq!(1_100., burnout.mass.m(), abs <= 1e-6);
+        // coasting up to the apex
+        let h = burnout.altitude.m() + burnout.speed.m().powi(2) / (2. * G0);
+        assert_float_eq!(h, ascent.apex.altitude.m(), rmax <= 1e-3);
+
+        let drag = AscentDrag::in_earth(Area::new(1.), 0.5);
+        let draggy = rocket.simulate_ascent(gfs, Some(drag), dt);
+        assert!(draggy.drag_loss.m() > 0.);
+        assert!(draggy.apex.altitude.m() < ascent.apex.altitude.m());
+        let expected = expected - draggy.drag_loss.m();
+        assert_float_eq!(expected, draggy.burnout.speed.m(), rmax <= 1e-3);
+
+        // in free space the apex is the burnout
+        let zero = Gfs::new(Direction::ZERO);
+        let free = rocket.simulate_ascent(zero, None, dt);
+        assert_eq![free.burnout.time.m(), free.apex.time.m()];
+        assert_float_eq!(rocket.delta_v().m(), free.burnout.speed.m(), rmax <= 1e-3);
+        let free = rocket.simulate_ascent(zero, Some(drag), dt);
+        assert_eq![free.burnout.time.m(), free.apex.time.m()];
+    }
+}
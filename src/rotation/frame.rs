@@ -0,0 +1,205 @@
+// fisica::rotation::frame
+//
+// Rotating reference frames.
+
+use crate::units::{Acceleration, AngularVelocity, Force, Mass, Time, Velocity};
+use crate::{Direction, Orientation, Position};
+
+/// A reference frame rotating about its `origin` relative to an inertial
+/// frame.
+///
+/// The angular velocity and acceleration are expressed in the inertial
+/// frame. The quantities in the rotating frame are expressed along its own
+/// axes, given by its current `orientation` in the inertial frame.
+#[derive(Clone, Copy, Debug)]
+pub struct RotatingFrame {
+    pub origin: Position,
+    pub orientation: Orientation,
+    pub angular_velocity: AngularVelocity,
+    /// The angular acceleration, in `rad/s²`.
+    pub angular_acceleration: Direction,
+}
+
+/// The fictitious [`Force`]s felt by a [`Mass`] in a [`RotatingFrame`],
+/// along its axes.
+#[derive(Clone, Copy, Debug)]
+pub struct FictitiousForces {
+    /// The Coriolis force (`F = -2 m ω × v`).
+    pub coriolis: Force,
+    /// The centrifugal force (`F = -m ω × (ω × r)`).
+    pub centrifugal: Force,
+    /// The Euler force, from the angular acceleration (`F = -m α × r`).
+    pub euler: Force,
+}
+
+impl FictitiousForces {
+    /// Returns the sum of the fictitious forces.
+    pub fn total(&self) -> Force {
+        Force::new(self.coriolis.d + self.centrifugal.d + self.euler.d)
+    }
+}
+
+impl RotatingFrame {
+    /// New `RotatingFrame` about the inertial origin, aligned with the inertial
+    /// axes, with the given constant [`AngularVelocity`].
+    pub const fn new(angular_velocity: AngularVelocity) -> Self {
+        Self {
+            origin: Position::ZERO,
+            orientation: Orientation::IDENTITY,
+            angular_velocity,
+            angular_acceleration: Direction::ZERO,
+        }
+    }
+
+    /// Returns the frame after the given [`Time`], rotated by its angular
+    /// velocity, which is then updated by the angular acceleration.
+    pub fn advanced(&self, t: Time) -> Self {
+        let w = self.angular_velocity;
+        Self {
+            orientation: w.calc_orientation(self.orientation, t),
+            angular_velocity: AngularVelocity::new(w.d + self.angular_acceleration * t.m()),
+            ..*self
+        }
+    }
+
+    /// Converts the inertial [`Position`] `p` to the rotating frame.
+    pub fn to_rotating_position(&self, p: Position) -> Position {
+        (p - self.origin).rotate(self.orientation.inverse())
+    }
+
+    /// Converts the [`Position`] `p` in the rotating frame to the inertial
+    /// frame.
+    pub fn to_inertial_position(&self, p: Position) -> Position {
+        p.rotate(self.orientation) + self.origin
+    }
+
+    /// Converts the inertial [`Velocity`] `v` of a point at the inertial
+    /// [`Position`] `p` to the rotating frame (`v' = v - ω × r`).
+    pub fn to_rotating_velocity(&self, p: Position, v: Velocity) -> Velocity {
+        let r = p - self.origin;
+        let v = v.d - self.angular_velocity.calc_velocity(r).d;
+        Velocity::new(v.rotate(self.orientation.inverse()))
+    }
+
+    /// Converts the [`Velocity`] `v` of a point at the [`Position`] `p`, both
+    /// in the rotating frame, to the inertial frame (`v = v' + ω × r`).
+    pub fn to_inertial_velocity(&self, p: Position, v: Velocity) -> Velocity {
+        let r = p.rotate(self.orientation);
+        Velocity::new(v.d.rotate(self.orientation) + self.angular_velocity.calc_velocity(r).d)
+    }
+
+    /// Converts the inertial [`Acceleration`] `a` of a point at the inertial
+    /// [`Position`] `p` with the inertial [`Velocity`] `v` to the rotating
+    /// frame (`a' = a - 2 ω × v' - ω × (ω × r) - α × r`).
+    pub fn to_rotating_acceleration(
+        &self,
+        p: Position,
+        v: Velocity,
+        a: Acceleration,
+    ) -> Acceleration {
+        let (r, w) = (p - self.origin, self.angular_velocity);
+        let relative = v.d - w.calc_velocity(r).d;
+        let a = a.d
+            - w.d.cross(relative) * 2.
+            - w.calc_centripetal_acceleration(r).d
+            - self.angular_acceleration.cross(r);
+        Acceleration::new(a.rotate(self.orientation.inverse()))
+    }
+
+    /// Converts the [`Acceleration`] `a` of a point at the [`Position`] `p`
+    /// with the [`Velocity`] `v`, all in the rotating frame, to the inertial
+    /// frame (`a = a' + 2 ω × v' + ω × (ω × r) + α × r`).
+    pub fn to_inertial_acceleration(
+        &self,
+        p: Position,
+        v: Velocity,
+        a: Acceleration,
+    ) -> Acceleration {
+        let o = self.orientation;
+        let (r, v, w) = (p.rotate(o), v.d.rotate(o), self.angular_velocity);
+        let a = a.d.rotate(o)
+            + w.d.cross(v) * 2.
+            + w.calc_centripetal_acceleration(r).d
+            + self.angular_acceleration.cross(r);
+        Acceleration::new(a)
+    }
+
+    /// Returns the [`FictitiousForces`] on a [`Mass`] at the [`Position`] `p`
+    /// moving with the [`Velocity`] `v`, both in the rotating frame.
+    pub fn calc_fictitious_forces(&self, m: Mass, p: Position, v: Velocity) -> FictitiousForces {
+        // the angular velocity and acceleration along the rotating axes
+        let inverse = self.orientation.inverse();
+        let w = AngularVelocity::new(self.angular_velocity.d.rotate(inverse));
+        let alpha = self.angular_acceleration.rotate(inverse);
+        let m = m.m();
+        FictitiousForces {
+            coriolis: Force::new(w.d.cross(v.d) * (-2. * m)),
+            centrifugal: Force::new(w.calc_centripetal_acceleration(p).d * -m),
+            euler: Force::new(alpha.cross(p) * -m),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::Magnitude, float_eq::assert_float_eq};
+
+    fn assert_close(a: Direction, b: Direction) {
+        assert_float_eq!(a.to_array(), b.to_array(), abs_all <= 1e-12);
+    }
+
+    /// Checks the conversions between the frames.
+    #[test]
+    fn frame_conversions() {
+        let mut frame = RotatingFrame::new(AngularVelocity::new(Direction::new(0.3, -0.2, 1.)));
+        frame.origin = Position::new(1., 2., 3.);
+        frame.angular_acceleration = Direction::new(0.1, 0.05, -0.2);
+        let frame = frame.advanced(Time::new(0.7));
+
+        let (p, v, a) = (
+            Position::new(4., -1., 2.),
+            Velocity::new(Direction::new(0.5, 2., -1.)),
+            Acceleration::new(Direction::new(0., -9.8, 0.)),
+        );
+        let p2 = frame.to_rotating_position(p);
+        assert_close(p, frame.to_inertial_position(p2));
+        let v2 = frame.to_rotating_velocity(p, v);
+        assert_close(v.d, frame.to_inertial_velocity(p2, v2).d);
+        let a2 = frame.to_rotating_acceleration(p, v, a);
+        assert_close(a.d, frame.to_inertial_acceleration(p2, v2, a2).d);
+
+        // a free body accelerates in the rotating frame by the fictitious forces
+        let free = Acceleration::new(Direction::ZERO);
+        let a2 = frame.to_rotating_acceleration(p, v, free);
+        let forces = frame.calc_fictitious_forces(Mass::new(2.), p2, v2);
+        assert_close(a2.d * 2., forces.total().d);
+
+        // a body at rest in the inertial frame circles backwards
+        let frame = RotatingFrame::new(AngularVelocity::new(Direction::new(0., 0., 2.)));
+        let v2 =
+            frame.to_rotating_velocity(Position::new(1., 0., 0.), Velocity::new(Direction::ZERO));
+        assert_close(Direction::new(0., -2., 0.), v2.d);
+    }
+
+    /// Checks the fictitious forces on the rotating Earth.
+    #[test]
+    fn frame_fictitious_forces() {
+        let w: Magnitude = 7.292_115e-5;
+        let earth = RotatingFrame::new(AngularVelocity::new(Direction::new(0., 0., w)));
+        let m = Mass::new(1.);
+
+        // on the equator the centrifugal force points outwards
+        let p = Position::new(6.378e6, 0., 0.);
+        let still = earth.calc_fictitious_forces(m, p, Velocity::new(Direction::ZERO));
+        assert_float_eq!(w * w * 6.378e6, still.centrifugal.d.x(), r2nd <= 1e-15);
+        assert_float_eq!(0., still.coriolis.m(), abs <= 0.);
+        assert_float_eq!(0., still.euler.m(), abs <= 0.);
+
+        // moving along the ground at the north pole, it deflects to the right
+        let pole = Position::new(0., 0., 6.357e6);
+        let north = Velocity::new(Direction::new(10., 0., 0.));
+        let f = earth.calc_fictitious_forces(m, pole, north);
+        assert_float_eq!(-2. * w * 10., f.coriolis.d.y(), r2nd <= 1e-15);
+        assert_float_eq!(0., f.centrifugal.m(), abs <= 0.);
+    }
+}
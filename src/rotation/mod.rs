@@ -0,0 +1,165 @@
+// fisica::rotation
+//
+//! Circular motion and rotating frames
+//!
+//! A body in [uniform circular motion][0] moves at a constant [`Speed`]
+//! around a circle, pulled towards its center by a centripetal force. The
+//! [`CircularMotion`] relates its speed, radius, angular speed and period,
+//! and gives the banking of a curve taken at that speed.
+//!
+//! A [`RotatingFrame`] converts the positions, velocities and accelerations
+//! between an inertial frame and a frame rotating with it, and gives the
+//! [`FictitiousForces`] felt in the rotating frame: the Coriolis, centrifugal
+//! and Euler forces.
+//!
+//! [0]:https://en.wikipedia.org/wiki/Circular_motion
+//!
+//! # External links
+//! - <https://en.wikipedia.org/wiki/Centripetal_force>
+//! - <https://en.wikipedia.org/wiki/Banked_turn>
+//! - <https://en.wikipedia.org/wiki/Rotating_reference_frame>
+//! - <https://en.wikipedia.org/wiki/Fictitious_force>
+//
+
+use core::f64::consts::TAU;
+
+use crate::units::{
+    Acceleration, AngularVelocity, Force, Frequency, Gfs, Length, Mass, Speed, Time,
+};
+use crate::{Direction, Magnitude};
+
+mod frame;
+
+pub use frame::{FictitiousForces, RotatingFrame};
+
+/// A body moving at a constant [`Speed`] around a circle.
+#[derive(Clone, Copy, Debug)]
+pub struct CircularMotion {
+    pub radius: Length,
+    pub speed: Speed,
+}
+
+impl CircularMotion {
+    /// New CircularMotion.
+    #[inline]
+    pub const fn new(radius: Length, speed: Speed) -> Self {
+        Self { radius, speed }
+    }
+
+    /// New `CircularMotion` at the given [`AngularVelocity`] (`v = ω r`).
+    #[inline]
+    pub fn from_angular_velocity(radius: Length, w: AngularVelocity) -> Self {
+        Self::new(radius, Speed::new(w.m() * radius.m()))
+    }
+
+    /// New `CircularMotion` completing a turn in the given `period`
+    /// (`v = 2π r / T`).
+    #[inline]
+    pub fn from_period(radius: Length, period: Time) -> Self {
+        Self::new(radius, Speed::new(TAU * radius.m() / period.m()))
+    }
+
+    /// Returns the angular speed, in `rad/s` (`ω = v / r`).
+    #[inline]
+    pub fn angular_speed(&self) -> Magnitude {
+        self.speed.m() / self.radius.m()
+    }
+
+    /// Returns the [`Time`] to complete a turn (`T = 2π r / v`).
+    #[inline]
+    pub fn period(&self) -> Time {
+        Time::new(TAU * self.radius.m() / self.speed.m())
+    }
+
+    /// Returns the number of turns per second (`f = v / (2π r)`).
+    #[inline]
+    pub fn frequency(&self) -> Frequency {
+        Frequency::new(1. / self.period().m())
+    }
+
+    /// Returns the centripetal [`Acceleration`] towards the center, in the
+    /// direction `inward` (`a = v² / r`).
+    pub fn calc_centripetal_acceleration(&self, inward: Direction) -> Acceleration {
+        let v = self.speed.m();
+        Acceleration::new(inward.normalize() * (v * v / self.radius.m()))
+    }
+
+    /// Returns the centripetal [`Force`] needed to keep a [`Mass`] on the
+    /// circle, towards the center in the direction `inward` (`F = m v² / r`).
+    pub fn calc_centripetal_force(&self, m: Mass, inward: Direction) -> Force {
+        self.calc_centripetal_acceleration(inward).calc_force(m)
+    }
+
+    /// Returns the angle, in radians, of a curve banked so that it can be
+    /// taken at this speed without friction (`tan θ = v² / (r g)`).
+    pub fn calc_banking_angle(&self, g: Gfs) -> Magnitude {
+        let v = self.speed.m();
+        (v * v / (self.radius.m() * g.m())).atan()
+    }
+
+    /// Returns the minimum and maximum [`Speed`]s at which a curve of the given
+    /// `radius` and banking `angle` can be taken without sliding, with the
+    /// given coefficient of static `friction`.
+    ///
+    /// The maximum is infinite when the friction is enough to hold at any
+    /// speed (`μ ≥ cot θ`).
+    pub fn calc_banked_speeds(
+        radius: Length,
+        angle: Magnitude,
+        friction: Magnitude,
+        g: Gfs,
+    ) -> [Speed; 2] {
+        let (sin, cos, rg) = (angle.sin(), angle.cos(), radius.m() * g.m());
+        let min = rg * (sin - friction * cos) / (cos + friction * sin);
+        let max_divisor = cos - friction * sin;
+        let max = if max_divisor > 0. {
+            (rg * (sin + friction * cos) / max_divisor).sqrt()
+        } else {
+            Magnitude::INFINITY
+        };
+        [Speed::new(min.max(0.).sqrt()), Speed::new(max)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks a car taking a curve.
+    #[test]
+    fn circular_motion() {
+        let g = Gfs::new(Direction::new(0., -9.81, 0.));
+        let car = CircularMotion::new(Length::new(50.), Speed::new(20.));
+        assert_float_eq!(0.4, car.angular_speed(), r2nd <= 1e-15);
+        assert_float_eq!(TAU / 0.4, car.period().m(), r2nd <= 1e-15);
+        assert_float_eq!(0.4 / TAU, car.frequency().m(), r2nd <= 1e-15);
+
+        let inward = Direction::new(-2., 0., 0.);
+        let a = car.calc_centripetal_acceleration(inward);
+        assert_float_eq!(-8., a.d.x(), r2nd <= 1e-15);
+        let f = car.calc_centripetal_force(Mass::new(1000.), inward);
+        assert_float_eq!(8000., f.m(), r2nd <= 1e-15);
+
+        let w = AngularVelocity::new(Direction::new(0., 0.4, 0.));
+        let same = CircularMotion::from_angular_velocity(Length::new(50.), w);
+        assert_float_eq!(20., same.speed.m(), r2nd <= 1e-15);
+        let same = CircularMotion::from_period(Length::new(50.), car.period());
+        assert_float_eq!(20., same.speed.m(), r2nd <= 1e-15);
+
+        // the ideal banking holds the car without friction
+        let angle = car.calc_banking_angle(g);
+        assert_float_eq!((400_f64 / 490.5).atan(), angle, r2nd <= 1e-15);
+        let [min, max] = CircularMotion::calc_banked_speeds(car.radius, angle, 0., g);
+        assert_float_eq!(20., min.m(), r2nd <= 1e-14);
+        assert_float_eq!(20., max.m(), r2nd <= 1e-14);
+
+        // a flat curve is held by the friction alone (v = √(μ r g))
+        let [min, max] = CircularMotion::calc_banked_speeds(car.radius, 0., 0.7, g);
+        assert_float_eq!(0., min.m(), abs <= 0.);
+        assert_float_eq!((0.7 * 490.5_f64).sqrt(), max.m(), r2nd <= 1e-15);
+
+        // a steep curve with grippy tyres has no maximum speed
+        let [min, max] = CircularMotion::calc_banked_speeds(car.radius, 1.2, 1., g);
+        assert!(min.m() > 0. && max.m().is_infinite());
+    }
+}
@@ -0,0 +1,57 @@
+// fisica::shapes
+//
+//! Geometric shapes
+//!
+//! Shapes are parameterised by [`Length`]s and derive their measures as typed
+//! quantities.
+//!
+//! - [`PlaneShape`]s have an [`Area`] and a perimeter [`Length`].
+//! - [`SolidShape`]s have a [`Volume`] and a surface [`Area`], and can be
+//!   combined with a [`Density`] to give their [`Mass`].
+//!
+//! # External links
+//! - <https://en.wikipedia.org/wiki/List_of_formulas_in_elementary_geometry>
+//
+
+use crate::units::{Area, Density, Length, Mass, Volume};
+
+mod plane;
+mod solid;
+
+pub use {plane::*, solid::*};
+
+/// A two-dimensional shape.
+pub trait PlaneShape {
+    /// Returns the [`Area`] enclosed by the shape.
+    fn area(&self) -> Area;
+
+    /// Returns the [`Length`] of the boundary of the shape.
+    fn perimeter(&self) -> Length;
+
+    /// Returns the [`Volume`] of the prism formed by extruding the shape to
+    /// the given `height` (`V = A × h`).
+    fn calc_prism_volume(&self, height: Length) -> Volume {
+        Volume::new(self.area().m() * height.m())
+    }
+
+    /// Returns the [`Mass`] of a plate of the given `thickness`
+    /// and [`Density`] (`m = ρ × A × h`).
+    fn calc_plate_mass(&self, thickness: Length, d: Density) -> Mass {
+        d.calc_mass(self.calc_prism_volume(thickness))
+    }
+}
+
+/// A three-dimensional shape.
+pub trait SolidShape {
+    /// Returns the [`Volume`] enclosed by the shape.
+    fn volume(&self) -> Volume;
+
+    /// Returns the [`Area`] of the surface of the shape.
+    fn surface_area(&self) -> Area;
+
+    /// Returns the [`Mass`] of the shape filled with the given [`Density`]
+    /// (`m = ρ × V`).
+    fn calc_mass(&self, d: Density) -> Mass {
+        d.calc_mass(self.volume())
+    }
+}
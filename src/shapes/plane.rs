@@ -0,0 +1,228 @@
+// fisica::shapes::plane
+//
+
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+use super::PlaneShape;
+use crate::units::{Area, Length};
+use crate::{Magnitude, Position};
+
+/// A circle with the given `radius`.
+#[derive(Clone, Copy, Debug)]
+pub struct Circle {
+    pub radius: Length,
+}
+
+impl Circle {
+    /// New Circle.
+    #[inline]
+    pub const fn new(radius: Length) -> Self {
+        Self { radius }
+    }
+}
+
+impl PlaneShape for Circle {
+    /// `A = π r²`
+    fn area(&self) -> Area {
+        Area::new(PI * self.radius.m() * self.radius.m())
+    }
+
+    /// `P = 2 π r`
+    fn perimeter(&self) -> Length {
+        Length::new(2. * PI * self.radius.m())
+    }
+}
+
+/// A rectangle with the given `width` and `height`.
+#[derive(Clone, Copy, Debug)]
+pub struct Rectangle {
+    pub width: Length,
+    pub height: Length,
+}
+
+impl Rectangle {
+    /// New Rectangle.
+    #[inline]
+    pub const fn new(width: Length, height: Length) -> Self {
+        Self { width, height }
+    }
+}
+
+impl PlaneShape for Rectangle {
+    /// `A = w × h`
+    fn area(&self) -> Area {
+        Area::new(self.width.m() * self.height.m())
+    }
+
+    /// `P = 2 (w + h)`
+    fn perimeter(&self) -> Length {
+        Length::new(2. * (self.width.m() + self.height.m()))
+    }
+}
+
+/// A triangle with the given lengths of its three sides.
+#[derive(Clone, Copy, Debug)]
+pub struct Triangle {
+    pub a: Length,
+    pub b: Length,
+    pub c: Length,
+}
+
+impl Triangle {
+    /// New Triangle.
+    #[inline]
+    pub const fn new(a: Length, b: Length, c: Length) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl PlaneShape for Triangle {
+    /// Uses [*Heron's formula*][0]: `A = √(s (s - a)(s - b)(s - c))`,
+    /// where `s` is the semiperimeter.
+    ///
+    /// Returns zero if the sides don't form a triangle.
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Heron%27s_formula
+    fn area(&self) -> Area {
+        let (a, b, c) = (self.a.m(), self.b.m(), self.c.m());
+        let s = (a + b + c) / 2.;
+        Area::new((s * (s - a) * (s - b) * (s - c)).max(0.).sqrt())
+    }
+
+    /// `P = a + b + c`
+    fn perimeter(&self) -> Length {
+        Length::new(self.a.m() + self.b.m() + self.c.m())
+    }
+}
+
+/// A regular polygon with the given number of `sides`, each of `side` length.
+#[derive(Clone, Copy, Debug)]
+pub struct RegularPolygon {
+    pub sides: usize,
+    pub side: Length,
+}
+
+impl RegularPolygon {
+    /// New RegularPolygon.
+    #[inline]
+    pub const fn new(sides: usize, side: Length) -> Self {
+        Self { sides, side }
+    }
+
+    /// Returns the [*apothem*][0], the distance from the center to the
+    /// midpoint of any side (`a = s / (2 tan(π / n))`).
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Apothem
+    pub fn apothem(&self) -> Length {
+        Length::new(self.side.m() / (2. * (PI / self.sides as Magnitude).tan()))
+    }
+
+    /// Returns the circumradius, the distance from the center to any vertex
+    /// (`R = s / (2 sin(π / n))`).
+    pub fn circumradius(&self) -> Length {
+        Length::new(self.side.m() / (2. * (PI / self.sides as Magnitude).sin()))
+    }
+}
+
+impl PlaneShape for RegularPolygon {
+    /// `A = ½ P a`, where `a` is the apothem.
+    fn area(&self) -> Area {
+        Area::new(self.perimeter().m() * self.apothem().m() / 2.)
+    }
+
+    /// `P = n s`
+    fn perimeter(&self) -> Length {
+        Length::new(self.sides as Magnitude * self.side.m())
+    }
+}
+
+/// A simple polygon defined by its vertices on the XY plane.
+///
+/// The Z coordinate of the vertices is ignored.
+#[derive(Clone, Debug, Default)]
+pub struct Polygon {
+    pub vertices: Vec<Position>,
+}
+
+impl Polygon {
+    /// New Polygon.
+    #[inline]
+    pub const fn new(vertices: Vec<Position>) -> Self {
+        Self { vertices }
+    }
+
+    /// Returns an iterator over the pairs of consecutive vertices.
+    fn edges(&self) -> impl Iterator<Item = (Position, Position)> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+}
+
+impl PlaneShape for Polygon {
+    /// Uses the [*shoelace formula*][0]: `A = ½ |Σ (xᵢ yᵢ₊₁ - xᵢ₊₁ yᵢ)|`.
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Shoelace_formula
+    fn area(&self) -> Area {
+        let twice: Magnitude = self
+            .edges()
+            .map(|(a, b)| a.x() * b.y() - b.x() * a.y())
+            .sum();
+        Area::new(twice.abs() / 2.)
+    }
+
+    /// `P = Σ |vᵢ₊₁ - vᵢ|`
+    fn perimeter(&self) -> Length {
+        Length::new(
+            self.edges()
+                .map(|(a, b)| {
+                    let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+                    (dx * dx + dy * dy).sqrt()
+                })
+                .sum(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::Density;
+    use float_eq::assert_float_eq;
+
+    /// Checks the measures of the shapes are as expected.
+    #[test]
+    fn plane_shapes() {
+        let circle = Circle::new(Length::new(2.));
+        assert_float_eq!(4. * PI, circle.area().m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(4. * PI, circle.perimeter().m(), r2nd <= Magnitude::EPSILON);
+
+        let rectangle = Rectangle::new(Length::new(3.), Length::new(4.));
+        assert_float_eq!(12., rectangle.area().m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(14., rectangle.perimeter().m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            24.,
+            rectangle
+                .calc_plate_mass(Length::new(0.5), Density::new(4.))
+                .m(),
+            r2nd <= Magnitude::EPSILON
+        );
+
+        let triangle = Triangle::new(Length::new(3.), Length::new(4.), Length::new(5.));
+        assert_float_eq!(6., triangle.area().m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(12., triangle.perimeter().m(), r2nd <= Magnitude::EPSILON);
+
+        let square = RegularPolygon::new(4, Length::new(2.));
+        assert_float_eq!(4., square.area().m(), abs <= 1e-12);
+        assert_float_eq!(8., square.perimeter().m(), r2nd <= Magnitude::EPSILON);
+
+        let polygon = Polygon::new(vec![
+            Position::new(0., 0., 0.),
+            Position::new(3., 0., 0.),
+            Position::new(3., 4., 0.),
+            Position::new(0., 4., 0.),
+        ]);
+        assert_float_eq!(12., polygon.area().m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(14., polygon.perimeter().m(), r2nd <= Magnitude::EPSILON);
+    }
+}
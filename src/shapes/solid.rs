@@ -0,0 +1,250 @@
+// fisica::shapes::solid
+//
+
+use core::f64::consts::PI;
+
+use super::SolidShape;
+use crate::units::{Area, Length, Volume};
+use crate::Magnitude;
+
+/// A sphere with the given `radius`.
+#[derive(Clone, Copy, Debug)]
+pub struct Sphere {
+    pub radius: Length,
+}
+
+impl Sphere {
+    /// New Sphere.
+    #[inline]
+    pub const fn new(radius: Length) -> Self {
+        Self { radius }
+    }
+}
+
+impl SolidShape for Sphere {
+    /// `V = ⁴⁄₃ π r³`
+    fn volume(&self) -> Volume {
+        let r = self.radius.m();
+        Volume::new(4. / 3. * PI * r * r * r)
+    }
+
+    /// `S = 4 π r²`
+    fn surface_area(&self) -> Area {
+        let r = self.radius.m();
+        Area::new(4. * PI * r * r)
+    }
+}
+
+/// A right circular cylinder with the given `radius` and `height`.
+#[derive(Clone, Copy, Debug)]
+pub struct Cylinder {
+    pub radius: Length,
+    pub height: Length,
+}
+
+impl Cylinder {
+    /// New Cylinder.
+    #[inline]
+    pub const fn new(radius: Length, height: Length) -> Self {
+        Self { radius, height }
+    }
+}
+
+impl SolidShape for Cylinder {
+    /// `V = π r² h`
+    fn volume(&self) -> Volume {
+        let (r, h) = (self.radius.m(), self.height.m());
+        Volume::new(PI * r * r * h)
+    }
+
+    /// `S = 2 π r (r + h)`
+    fn surface_area(&self) -> Area {
+        let (r, h) = (self.radius.m(), self.height.m());
+        Area::new(2. * PI * r * (r + h))
+    }
+}
+
+/// A right circular cone with the given base `radius` and `height`.
+#[derive(Clone, Copy, Debug)]
+pub struct Cone {
+    pub radius: Length,
+    pub height: Length,
+}
+
+impl Cone {
+    /// New Cone.
+    #[inline]
+    pub const fn new(radius: Length, height: Length) -> Self {
+        Self { radius, height }
+    }
+
+    /// Returns the slant height, from the apex to the edge of the base
+    /// (`l = √(r² + h²)`).
+    pub fn slant_height(&self) -> Length {
+        Length::new(self.radius.m().hypot(self.height.m()))
+    }
+}
+
+impl SolidShape for Cone {
+    /// `V = ⅓ π r² h`
+    fn volume(&self) -> Volume {
+        let (r, h) = (self.radius.m(), self.height.m());
+        Volume::new(PI * r * r * h / 3.)
+    }
+
+    /// `S = π r (r + l)`, where `l` is the slant height.
+    fn surface_area(&self) -> Area {
+        let r = self.radius.m();
+        Area::new(PI * r * (r + self.slant_height().m()))
+    }
+}
+
+/// A rectangular cuboid (a box) with the given side lengths.
+#[derive(Clone, Copy, Debug)]
+pub struct Cuboid {
+    pub x: Length,
+    pub y: Length,
+    pub z: Length,
+}
+
+impl Cuboid {
+    /// New Cuboid.
+    #[inline]
+    pub const fn new(x: Length, y: Length, z: Length) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl SolidShape for Cuboid {
+    /// `V = x y z`
+    fn volume(&self) -> Volume {
+        Volume::new(self.x.m() * self.y.m() * self.z.m())
+    }
+
+    /// `S = 2 (xy + yz + xz)`
+    fn surface_area(&self) -> Area {
+        let (x, y, z) = (self.x.m(), self.y.m(), self.z.m());
+        Area::new(2. * (x * y + y * z + x * z))
+    }
+}
+
+/// A ring torus with the given `major` radius, from the center of the hole
+/// to the center of the tube, and the `minor` radius of the tube.
+#[derive(Clone, Copy, Debug)]
+pub struct Torus {
+    pub major: Length,
+    pub minor: Length,
+}
+
+impl Torus {
+    /// New Torus.
+    #[inline]
+    pub const fn new(major: Length, minor: Length) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl SolidShape for Torus {
+    /// `V = 2 π² R r²`
+    fn volume(&self) -> Volume {
+        let (rr, r) = (self.major.m(), self.minor.m());
+        Volume::new(2. * PI * PI * rr * r * r)
+    }
+
+    /// `S = 4 π² R r`
+    fn surface_area(&self) -> Area {
+        Area::new(4. * PI * PI * self.major.m() * self.minor.m())
+    }
+}
+
+/// An ellipsoid with the given semi-axes `a`, `b` and `c`.
+#[derive(Clone, Copy, Debug)]
+pub struct Ellipsoid {
+    pub a: Length,
+    pub b: Length,
+    pub c: Length,
+}
+
+impl Ellipsoid {
+    /// New Ellipsoid.
+    #[inline]
+    pub const fn new(a: Length, b: Length, c: Length) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl SolidShape for Ellipsoid {
+    /// `V = ⁴⁄₃ π a b c`
+    fn volume(&self) -> Volume {
+        Volume::new(4. / 3. * PI * self.a.m() * self.b.m() * self.c.m())
+    }
+
+    /// Uses [*Knud Thomsen's approximation*][0], with a relative error
+    /// of at most 1.061%, and exact for spheres.
+    ///
+    /// `S ≈ 4 π ((aᵖbᵖ + aᵖcᵖ + bᵖcᵖ) / 3)^(1/p)`, with `p ≈ 1.6075`.
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Ellipsoid#Approximate_formula
+    fn surface_area(&self) -> Area {
+        const P: Magnitude = 1.6075;
+        let (a, b, c) = (self.a.m().powf(P), self.b.m().powf(P), self.c.m().powf(P));
+        Area::new(4. * PI * ((a * b + a * c + b * c) / 3.).powf(1. / P))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::Density;
+    use float_eq::assert_float_eq;
+
+    /// Checks the measures of the shapes are as expected.
+    #[test]
+    fn solid_shapes() {
+        let sphere = Sphere::new(Length::new(3.));
+        assert_float_eq!(36. * PI, sphere.volume().m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            36. * PI,
+            sphere.surface_area().m(),
+            r2nd <= Magnitude::EPSILON
+        );
+
+        let cylinder = Cylinder::new(Length::new(1.), Length::new(2.));
+        assert_float_eq!(2. * PI, cylinder.volume().m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            6. * PI,
+            cylinder.surface_area().m(),
+            r2nd <= Magnitude::EPSILON
+        );
+
+        let cone = Cone::new(Length::new(3.), Length::new(4.));
+        assert_float_eq!(12. * PI, cone.volume().m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            24. * PI,
+            cone.surface_area().m(),
+            r2nd <= Magnitude::EPSILON
+        );
+
+        let cuboid = Cuboid::new(Length::new(1.), Length::new(2.), Length::new(3.));
+        assert_float_eq!(6., cuboid.volume().m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(22., cuboid.surface_area().m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            6_000.,
+            cuboid.calc_mass(Density::new(1_000.)).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+
+        let torus = Torus::new(Length::new(2.), Length::new(1.));
+        assert_float_eq!(4. * PI * PI, torus.volume().m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            8. * PI * PI,
+            torus.surface_area().m(),
+            r2nd <= Magnitude::EPSILON
+        );
+
+        // a spherical ellipsoid
+        let ellipsoid = Ellipsoid::new(Length::new(3.), Length::new(3.), Length::new(3.));
+        assert_float_eq!(36. * PI, ellipsoid.volume().m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(36. * PI, ellipsoid.surface_area().m(), rmax <= 1e-12);
+    }
+}
@@ -0,0 +1,500 @@
+// fisica::soft
+//
+//! Soft bodies
+//!
+//! ## Mass–spring systems
+//!
+//! A [`MassSpring`] system models deformable objects like ropes, cloth and
+//! soft bodies as a set of [`Particle`]s connected by damped [`Spring`]s.
+//! For cloth, there are three kinds of springs ([`SpringKind`]):
+//! - *structural* springs between neighbours resist stretching.
+//! - *shear* springs along the diagonals resist shearing.
+//! - *bending* springs skipping a particle resist folding.
+//!
+//! Stiff springs make explicit integrators unstable unless the time step is
+//! very small, so the system is advanced with the [*implicit Euler*][0]
+//! method, linearizing the forces and solving the resulting system with the
+//! conjugate gradient method. It's stable for any time step, at the cost of
+//! some numerical damping.
+//!
+//! Pinned particles have infinite mass: they're not moved by forces.
+//!
+//! ## Position-based dynamics
+//!
+//! A [`PbdSolver`] works directly on the positions of the particles instead
+//! of the forces, projecting them onto a set of [`Constraint`]s: distances,
+//! angles, tetrahedral volumes and collisions with planes and spheres.
+//! Each constraint has a *compliance*, the inverse of its stiffness, which
+//! makes the result independent of the time step and the number of iterations
+//! ([*XPBD*][1]). It's unconditionally stable and fast, well suited for
+//! interactive simulation, and its accuracy is controlled by the number of
+//! substeps.
+//!
+//! [0]:https://www.cs.cmu.edu/~baraff/papers/sig98.pdf
+//! [1]:https://matthias-research.github.io/pages/publications/XPBD.pdf
+//!
+//! # External links
+//! - <https://en.wikipedia.org/wiki/Soft-body_dynamics>
+//! - <https://en.wikipedia.org/wiki/Cloth_modeling>
+//
+
+use alloc::vec::Vec;
+
+use crate::math::{Matrix, V3};
+use crate::units::{
+    Acceleration, DampingCoefficient, Energy, Force, Length, Mass, SpringConstant, Time, Velocity,
+};
+use crate::{Direction, Magnitude, Position};
+
+mod pbd;
+
+pub use pbd::{Constraint, PbdSolver};
+
+/// A point mass of a [`MassSpring`] system.
+#[derive(Clone, Copy, Debug)]
+pub struct Particle {
+    pub position: Position,
+    pub velocity: Velocity,
+    /// The inverse of the mass, in `1/kg`. Zero for pinned particles.
+    pub inverse_mass: Magnitude,
+}
+
+impl Particle {
+    /// New `Particle` at rest, with the given [`Mass`].
+    pub fn new(position: Position, mass: Mass) -> Self {
+        Self {
+            position,
+            velocity: Velocity::new(Direction::ZERO),
+            inverse_mass: if mass.m() > 0. { 1. / mass.m() } else { 0. },
+        }
+    }
+
+    /// New pinned `Particle`, with infinite mass.
+    pub fn pinned(position: Position) -> Self {
+        Self::new(position, Mass::new(0.))
+    }
+
+    /// Returns `true` if the particle is not moved by forces.
+    pub fn is_pinned(&self) -> bool {
+        self.inverse_mass == 0.
+    }
+
+    /// Returns the [`Mass`], infinite for pinned particles.
+    pub fn mass(&self) -> Mass {
+        Mass::new(1. / self.inverse_mass)
+    }
+}
+
+/// The role of a [`Spring`] in a [`MassSpring`] system.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpringKind {
+    /// Resists stretching, between neighbouring particles.
+    Structural,
+    /// Resists shearing, along the diagonals of a grid.
+    Shear,
+    /// Resists bending, between particles two apart.
+    Bending,
+}
+
+/// A damped spring between two [`Particle`]s of a [`MassSpring`] system.
+#[derive(Clone, Copy, Debug)]
+pub struct Spring {
+    /// The indices of the connected particles.
+    pub particles: [usize; 2],
+    /// The rest [`Length`].
+    pub rest: Length,
+    pub stiffness: SpringConstant,
+    /// The damping along the spring, opposing the relative velocity of the
+    /// ends.
+    pub damping: DampingCoefficient,
+    pub kind: SpringKind,
+}
+
+impl Spring {
+    /// New Spring.
+    #[inline]
+    pub const fn new(
+        particles: [usize; 2],
+        rest: Length,
+        stiffness: SpringConstant,
+        damping: DampingCoefficient,
+        kind: SpringKind,
+    ) -> Self {
+        Self {
+            particles,
+            rest,
+            stiffness,
+            damping,
+            kind,
+        }
+    }
+}
+
+/// A system of [`Particle`]s connected by [`Spring`]s.
+#[derive(Clone, Debug, Default)]
+pub struct MassSpring {
+    pub particles: Vec<Particle>,
+    pub springs: Vec<Spring>,
+}
+
+impl MassSpring {
+    /// The maximum number of conjugate gradient iterations per step.
+    const MAX_ITERATIONS: usize = 200;
+
+    /// New MassSpring.
+    pub fn new(particles: Vec<Particle>, springs: Vec<Spring>) -> Self {
+        Self { particles, springs }
+    }
+
+    /// Adds a spring between the particles `a` and `b`, with their current
+    /// distance as the rest length.
+    pub fn connect(
+        &mut self,
+        a: usize,
+        b: usize,
+        stiffness: SpringConstant,
+        damping: DampingCoefficient,
+        kind: SpringKind,
+    ) {
+        let rest = (self.particles[b].position - self.particles[a].position).magnitude();
+        self.springs.push(Spring::new(
+            [a, b],
+            Length::new(rest),
+            stiffness,
+            damping,
+            kind,
+        ));
+    }
+
+    /// Pins the particle `i` at its current position.
+    pub fn pin(&mut self, i: usize) {
+        self.particles[i].inverse_mass = 0.;
+        self.particles[i].velocity = Velocity::new(Direction::ZERO);
+    }
+
+    /// New rope from `start` to `end`, divided in `segments` with the total
+    /// [`Mass`] spread evenly over its particles.
+    ///
+    /// Consecutive particles are joined by structural springs, and the
+    /// `bending` springs skip one particle, making the rope stiffer.
+    pub fn rope(
+        start: Position,
+        end: Position,
+        segments: usize,
+        mass: Mass,
+        stiffness: SpringConstant,
+        bending: SpringConstant,
+        damping: DampingCoefficient,
+    ) -> Self {
+        let segments = segments.max(1);
+        let m = Mass::new(mass.m() / (segments + 1) as Magnitude);
+        let step = (end - start) / segments as Magnitude;
+        let particles = (0..=segments)
+            .map(|i| Particle::new(start + step * i as Magnitude, m))
+            .collect();
+        let mut rope = Self::new(particles, Vec::new());
+        for i in 0..segments {
+            rope.connect(i, i + 1, stiffness, damping, SpringKind::Structural);
+        }
+        for i in 1..segments {
+            rope.connect(i - 1, i + 1, bending, damping, SpringKind::Bending);
+        }
+        rope
+    }
+
+    /// New rectangular cloth with its corner at `origin`, spanning the `u`
+    /// and `v` sides, with a grid of `[nu, nv]` particles (at least 2 per
+    /// side), and the total [`Mass`] spread evenly over them.
+    ///
+    /// The `stiffness` is given for each [`SpringKind`], in order: structural,
+    /// shear and bending. The particle at `(i, j)` has the index `j nu + i`.
+    pub fn cloth(
+        origin: Position,
+        u: Direction,
+        v: Direction,
+        [nu, nv]: [usize; 2],
+        mass: Mass,
+        stiffness: [SpringConstant; 3],
+        damping: DampingCoefficient,
+    ) -> Self {
+        let (nu, nv) = (nu.max(2), nv.max(2));
+        let m = Mass::new(mass.m() / (nu * nv) as Magnitude);
+        let (du, dv) = (u / (nu - 1) as Magnitude, v / (nv - 1) as Magnitude);
+        let particles = (0..nv)
+            .flat_map(|j| (0..nu).map(move |i| (i, j)))
+            .map(|(i, j)| Particle::new(origin + du * i as Magnitude + dv * j as Magnitude, m))
+            .collect();
+        let mut cloth = Self::new(particles, Vec::new());
+        let [structural, shear, bending] = stiffness;
+        // the offsets to the other particle of each spring
+        let offsets = [
+            (1, 0, structural, SpringKind::Structural),
+            (0, 1, structural, SpringKind::Structural),
+            (1, 1, shear, SpringKind::Shear),
+            (-1, 1, shear, SpringKind::Shear),
+            (2, 0, bending, SpringKind::Bending),
+            (0, 2, bending, SpringKind::Bending),
+        ];
+        for j in 0..nv {
+            for i in 0..nu {
+                for (di, dj, k, kind) in offsets {
+                    let (Some(oi), oj) = (i.checked_add_signed(di), j + dj) else {
+                        continue;
+                    };
+                    if oi < nu && oj < nv {
+                        cloth.connect(j * nu + i, oj * nu + oi, k, damping, kind);
+                    }
+                }
+            }
+        }
+        cloth
+    }
+
+    /// Returns the [`Force`] of the springs on each particle.
+    pub fn forces(&self) -> Vec<Force> {
+        let mut forces = alloc::vec![V3::ZERO; self.particles.len()];
+        for s in &self.springs {
+            let [a, b] = s.particles;
+            let f = self.calc_spring_force(s);
+            forces[a] += f;
+            forces[b] -= f;
+        }
+        forces
+            .into_iter()
+            .map(|f| Force::new(Direction::from_vector(f)))
+            .collect()
+    }
+
+    /// Advances the system in [`Time`] under a uniform [`Acceleration`], using
+    /// the implicit Euler method.
+    ///
+    /// Solves `(M - h ∂f/∂v - h² ∂f/∂x) Δv = h (f + h ∂f/∂x v)` for the change
+    /// in velocity `Δv` with the conjugate gradient method, keeping the pinned
+    /// particles fixed.
+    pub fn step(&mut self, dt: Time, gravity: Acceleration) {
+        let h = dt.m();
+        let n = self.particles.len();
+        let free: Vec<bool> = self.particles.iter().map(|p| !p.is_pinned()).collect();
+        let masses: Vec<Magnitude> = self
+            .particles
+            .iter()
+            .map(|p| {
+                if p.is_pinned() {
+                    0.
+                } else {
+                    1. / p.inverse_mass
+                }
+            })
+            .collect();
+
+        // the right-hand side, and the blocks `h C + h² K` of each spring
+        let mut rhs: Vec<V3> = masses
+            .iter()
+            .map(|m| gravity.d.vector() * (m * h))
+            .collect();
+        let mut blocks = Vec::with_capacity(self.springs.len());
+        for s in &self.springs {
+            let [a, b] = s.particles;
+            let f = self.calc_spring_force(s);
+            let (k, c) = self.calc_spring_jacobians(s);
+            let dv = (self.particles[a].velocity.d - self.particles[b].velocity.d).vector();
+            let impulse = (f - k * dv * h) * h;
+            rhs[a] += impulse;
+            rhs[b] -= impulse;
+            blocks.push(c * h + k * (h * h));
+        }
+        let system = |x: &[V3]| -> Vec<V3> {
+            let mut y: Vec<V3> = x.iter().zip(&masses).map(|(x, m)| *x * *m).collect();
+            for (s, block) in self.springs.iter().zip(&blocks) {
+                let [a, b] = s.particles;
+                let d = *block * (x[a] - x[b]);
+                y[a] += d;
+                y[b] -= d;
+            }
+            filter(&mut y, &free);
+            y
+        };
+        filter(&mut rhs, &free);
+
+        // conjugate gradient
+        let mut x = alloc::vec![V3::ZERO; n];
+        let mut r = rhs;
+        let mut p = r.clone();
+        let mut rr = dot(&r, &r);
+        let tolerance = rr * 1e-20;
+        for _ in 0..Self::MAX_ITERATIONS {
+            if rr <= tolerance || rr == 0. {
+                break;
+            }
+            let ap = system(&p);
+            let alpha = rr / dot(&p, &ap);
+            for i in 0..n {
+                x[i] += p[i] * alpha;
+                r[i] -= ap[i] * alpha;
+            }
+            let rr_new = dot(&r, &r);
+            let beta = rr_new / rr;
+            for i in 0..n {
+                p[i] = r[i] + p[i] * beta;
+            }
+            rr = rr_new;
+        }
+
+        for (particle, dv) in self.particles.iter_mut().zip(x) {
+            if !particle.is_pinned() {
+                particle.velocity.d += Direction::from_vector(dv);
+                particle.position += particle.velocity.d * h;
+            }
+        }
+    }
+
+    /// Returns the total kinetic [`Energy`] of the free particles.
+    pub fn kinetic_energy(&self) -> Energy {
+        Energy::new(
+            self.particles
+                .iter()
+                .filter(|p| !p.is_pinned())
+                .map(|p| 0.5 * p.velocity.d.magnitude_squared() / p.inverse_mass)
+                .sum(),
+        )
+    }
+
+    /// Returns the total elastic potential [`Energy`] of the springs.
+    pub fn elastic_energy(&self) -> Energy {
+        Energy::new(
+            self.springs
+                .iter()
+                .map(|s| s.stiffness.calc_energy(self.calc_extension(s)).m())
+                .sum(),
+        )
+    }
+
+    /// Returns the extension of a spring beyond its rest length.
+    pub fn calc_extension(&self, spring: &Spring) -> Length {
+        let [a, b] = spring.particles;
+        let length = (self.particles[b].position - self.particles[a].position).magnitude();
+        Length::new(length - spring.rest.m())
+    }
+
+    /// Returns the force of the spring on its first particle.
+    fn calc_spring_force(&self, s: &Spring) -> V3 {
+        let [a, b] = s.particles;
+        let (pa, pb) = (&self.particles[a], &self.particles[b]);
+        let d = (pb.position - pa.position).vector();
+        let length = d.length();
+        if length == 0. {
+            return V3::ZERO;
+        }
+        let dir = d / length;
+        let closing = (pb.velocity.d - pa.velocity.d).vector().dot(dir);
+        dir * (s.stiffness.m() * (length - s.rest.m()) + s.damping.m() * closing)
+    }
+
+    /// Returns the stiffness and damping matrices `(K, C)` of a spring, the
+    /// negated Jacobians of the force on its first particle with respect to
+    /// its position and velocity.
+    ///
+    /// The transverse stiffness of compressed springs is dropped, to keep
+    /// the system positive definite.
+    fn calc_spring_jacobians(&self, s: &Spring) -> (Matrix, Matrix) {
+        let [a, b] = s.particles;
+        let d = (self.particles[b].position - self.particles[a].position).vector();
+        let length = d.length();
+        if length == 0. {
+            return (Matrix::ZERO, Matrix::ZERO);
+        }
+        let dir = d / length;
+        let outer = Matrix::from_cols(dir * dir.x, dir * dir.y, dir * dir.z);
+        let transverse = (1. - s.rest.m() / length).max(0.);
+        let k = (outer + (Matrix::IDENTITY - outer) * transverse) * s.stiffness.m();
+        (k, outer * s.damping.m())
+    }
+}
+
+/// Zeroes the entries of the pinned particles.
+fn filter(v: &mut [V3], free: &[bool]) {
+    for (v, free) in v.iter_mut().zip(free) {
+        if !free {
+            *v = V3::ZERO;
+        }
+    }
+}
+
+/// Returns the dot product of two lists of vectors.
+fn dot(a: &[V3], b: &[V3]) -> Magnitude {
+    a.iter().zip(b).map(|(a, b)| a.dot(*b)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_eq::assert_float_eq;
+
+    fn gravity() -> Acceleration {
+        Acceleration::new(Direction::new(0., -9.8, 0.))
+    }
+
+    /// Checks a hanging rope settles at its static stretch.
+    #[test]
+    fn mass_spring_rope() {
+        let k = SpringConstant::new(1e4);
+        let mut rope = MassSpring::rope(
+            Position::ZERO,
+            Position::new(0., -2., 0.),
+            10,
+            Mass::new(1.1),
+            k,
+            SpringConstant::new(1e2),
+            DampingCoefficient::new(1.),
+        );
+        rope.pin(0);
+        let dt = Time::new(1. / 60.);
+        for _ in 0..600 {
+            rope.step(dt, gravity());
+        }
+        // each spring holds the weight below it
+        let last = rope.particles.last().unwrap();
+        assert_float_eq!(-2.0054, last.position.y(), abs <= 5e-4);
+        for (i, s) in rope.springs.iter().take(10).enumerate() {
+            let below = (10 - i) as Magnitude * 0.1 * 9.8;
+            assert_float_eq!(below / k.m(), rope.calc_extension(s).m(), abs <= 1e-4);
+        }
+        assert!(rope.kinetic_energy().m() < 1e-6);
+    }
+
+    /// Checks a stiff cloth stays stable at 60 Hz and its forces balance.
+    #[test]
+    fn mass_spring_cloth() {
+        let mut cloth = MassSpring::cloth(
+            Position::ZERO,
+            Direction::new(1., 0., 0.),
+            Direction::new(0., 0., 1.),
+            [10, 10],
+            Mass::new(0.3),
+            [1e5, 1e4, 1e2].map(SpringConstant::new),
+            DampingCoefficient::new(0.5),
+        );
+        assert_eq!(2 * 10 * 9 + 2 * 9 * 9 + 2 * 10 * 8, cloth.springs.len());
+        cloth.pin(0);
+        cloth.pin(9);
+
+        let dt = Time::new(1. / 60.);
+        for _ in 0..120 {
+            cloth.step(dt, gravity());
+            let sum = cloth.forces().iter().fold(Direction::ZERO, |s, f| s + f.d);
+            assert_float_eq!(0., sum.magnitude(), abs <= 1e-6);
+        }
+        for p in &cloth.particles {
+            assert!(p.position.y() <= 1e-9 && p.position.y() > -1.5);
+        }
+        for s in cloth
+            .springs
+            .iter()
+            .filter(|s| s.kind == SpringKind::Structural)
+        {
+            assert!(cloth.calc_extension(s).m().abs() < 0.05 * s.rest.m());
+        }
+        // the pinned corner didn't move
+        assert_float_eq!(1., cloth.particles[9].position.x(), abs <= 0.);
+    }
+}
@@ -0,0 +1,437 @@
+// fisica::soft::pbd
+//
+// Position-based dynamics.
+
+use alloc::vec::Vec;
+
+use crate::collision::{Ball, Plane};
+use crate::math::V3;
+use crate::soft::{MassSpring, Particle};
+use crate::units::{Acceleration, Length, Time, Velocity, Volume};
+use crate::{Direction, Magnitude, Position};
+
+/// A constraint on the [`Position`]s of the particles of a [`PbdSolver`].
+///
+/// The `compliance` is the inverse of the stiffness, in `m/N` for distances
+/// (or the equivalent unit for other constraints). A compliance of zero makes
+/// the constraint rigid, and an infinite one disables it.
+#[derive(Clone, Copy, Debug)]
+pub enum Constraint {
+    /// Keeps two particles at the `rest` distance.
+    Distance {
+        particles: [usize; 2],
+        rest: Length,
+        compliance: Magnitude,
+    },
+    /// Keeps the angle at the middle particle, between the other two, at
+    /// the `rest` angle in radians.
+    ///
+    /// It resists the bending of ropes and hair, with a rest angle of `π`
+    /// for a straight line.
+    Angle {
+        particles: [usize; 3],
+        rest: Magnitude,
+        compliance: Magnitude,
+    },
+    /// Keeps the signed [`Volume`] of a tetrahedron at the `rest` volume.
+    ///
+    /// It's positive when the last three particles are counterclockwise seen
+    /// from the first one.
+    Volume {
+        particles: [usize; 4],
+        rest: Volume,
+        compliance: Magnitude,
+    },
+    /// Keeps a particle of some `radius` on the free side of a [`Plane`].
+    Plane {
+        particle: usize,
+        plane: Plane,
+        radius: Length,
+    },
+    /// Keeps a particle of some `radius` outside a [`Ball`].
+    Sphere {
+        particle: usize,
+        ball: Ball,
+        radius: Length,
+    },
+}
+
+impl Constraint {
+    /// Returns the value of the constraint function `C` and its gradients
+    /// for each particle, or `None` if it's satisfied or degenerate.
+    ///
+    /// The collision constraints are only active when `C < 0`.
+    fn evaluate(&self, p: &[Particle]) -> Option<Evaluation> {
+        let pos = |i: usize| p[i].position.vector();
+        let none = (0, V3::ZERO);
+        match *self {
+            Constraint::Distance {
+                particles: [a, b],
+                rest,
+                ..
+            } => {
+                let d = pos(a) - pos(b);
+                let length = d.length();
+                if length == 0. {
+                    return None;
+                }
+                let n = d / length;
+                Some((length - rest.m(), [(a, n), (b, -n), none, none], 2))
+            }
+            Constraint::Angle {
+                particles: [a, b, c],
+                rest,
+                ..
+            } => {
+                let (u, w) = (pos(a) - pos(b), pos(c) - pos(b));
+                let (lu, lw) = (u.length(), w.length());
+                if lu == 0. || lw == 0. {
+                    return None;
+                }
+                let (u, w) = (u / lu, w / lw);
+                let cos = u.dot(w).clamp(-1., 1.);
+                // the directions that open the angle, perpendicular to each arm
+                let (open_a, open_c) = ((u * cos - w), (w * cos - u));
+                let sin = (1. - cos * cos).sqrt();
+                if sin < 1e-9 {
+                    return None;
+                }
+                let (ga, gc) = (open_a / (sin * lu), open_c / (sin * lw));
+                Some((
+                    cos.acos() - rest,
+                    [(a, ga), (c, gc), (b, -ga - gc), none],
+                    3,
+                ))
+            }
+            Constraint::Volume {
+                particles: [a, b, c, d],
+                rest,
+                ..
+            } => {
+                let (e1, e2, e3) = (pos(b) - pos(a), pos(c) - pos(a), pos(d) - pos(a));
+                let volume = e1.cross(e2).dot(e3) / 6.;
+                let (gb, gc, gd) = (e2.cross(e3) / 6., e3.cross(e1) / 6., e1.cross(e2) / 6.);
+                Some((
+                    volume - rest.m(),
+                    [(a, -gb - gc - gd), (b, gb), (c, gc), (d, gd)],
+                    4,
+                ))
+            }
+            Constraint::Plane {
+                particle,
+                plane,
+                radius,
+            } => {
+                let c = plane.calc_distance(p[particle].position).m() - radius.m();
+                (c < 0.).then(|| (c, [(particle, plane.normal.vector()), none, none, none], 1))
+            }
+            Constraint::Sphere {
+                particle,
+                ball,
+                radius,
+            } => {
+                let d = pos(particle) - ball.center.vector();
+                let length = d.length();
+                let c = length - ball.radius.m() - radius.m();
+                (c < 0. && length > 0.).then(|| (c, [(particle, d / length), none, none, none], 1))
+            }
+        }
+    }
+
+    /// Returns the compliance, zero for collisions.
+    fn compliance(&self) -> Magnitude {
+        match *self {
+            Constraint::Distance { compliance, .. }
+            | Constraint::Angle { compliance, .. }
+            | Constraint::Volume { compliance, .. } => compliance,
+            Constraint::Plane { .. } | Constraint::Sphere { .. } => 0.,
+        }
+    }
+}
+
+/// The value of a constraint function, the gradients for up to four
+/// particles, and the number of them used.
+type Evaluation = (Magnitude, [(usize, V3); 4], usize);
+
+/// A position-based dynamics solver, moving [`Particle`]s to satisfy a set of
+/// [`Constraint`]s.
+#[derive(Clone, Debug)]
+pub struct PbdSolver {
+    pub particles: Vec<Particle>,
+    pub constraints: Vec<Constraint>,
+    /// The number of substeps each step is divided in.
+    pub substeps: usize,
+    /// The number of Gauss–Seidel iterations over the constraints per substep.
+    pub iterations: usize,
+}
+
+impl PbdSolver {
+    /// New `PbdSolver`, with 10 substeps of 1 iteration.
+    pub fn new(particles: Vec<Particle>) -> Self {
+        Self {
+            particles,
+            constraints: Vec::new(),
+            substeps: 10,
+            iterations: 1,
+        }
+    }
+
+    /// New `PbdSolver` with the particles of a [`MassSpring`] system and a
+    /// distance constraint for each spring, with a compliance of `1 / k`.
+    ///
+    /// The springs without stiffness are skipped.
+    pub fn from_mass_spring(system: &MassSpring) -> Self {
+        let mut solver = Self::new(system.particles.clone());
+        solver
+            .constraints
+            .extend(
+                system
+                    .springs
+                    .iter()
+                    .filter(|s| s.stiffness.m() != 0.)
+                    .map(|s| Constraint::Distance {
+                        particles: s.particles,
+                        rest: s.rest,
+                        compliance: 1. / s.stiffness.m(),
+                    }),
+            );
+        solver
+    }
+
+    /// Adds a distance constraint between the particles `a` and `b`, with
+    /// their current distance as the rest length.
+    pub fn add_distance(&mut self, a: usize, b: usize, compliance: Magnitude) {
+        let rest = (self.particles[a].position - self.particles[b].position).magnitude();
+        self.constraints.push(Constraint::Distance {
+            particles: [a, b],
+            rest: Length::new(rest),
+            compliance,
+        });
+    }
+
+    /// Adds an angle constraint at the particle `b`, between `a` and `c`,
+    /// with their current angle as the rest angle.
+    pub fn add_angle(&mut self, a: usize, b: usize, c: usize, compliance: Magnitude) {
+        let p = |i: usize| self.particles[i].position;
+        let (u, w) = (p(a) - p(b), p(c) - p(b));
+        let cos = u.dot(w) / (u.magnitude() * w.magnitude());
+        self.constraints.push(Constraint::Angle {
+            particles: [a, b, c],
+            rest: cos.clamp(-1., 1.).acos(),
+            compliance,
+        });
+    }
+
+    /// Adds a volume constraint for a tetrahedron, with its current signed
+    /// volume as the rest volume.
+    pub fn add_volume(&mut self, particles: [usize; 4], compliance: Magnitude) {
+        let p = |i: usize| self.particles[particles[i]].position;
+        let (e1, e2, e3) = (p(1) - p(0), p(2) - p(0), p(3) - p(0));
+        self.constraints.push(Constraint::Volume {
+            particles,
+            rest: Volume::new(e1.cross(e2).dot(e3) / 6.),
+            compliance,
+        });
+    }
+
+    /// Advances the particles in [`Time`] under a uniform [`Acceleration`],
+    /// using [*extended position-based dynamics*][0] (XPBD).
+    ///
+    /// For each substep of length `h`, it predicts the positions from the
+    /// velocities, projects them onto the constraints, and derives the new
+    /// velocities from the displacement. Each constraint moves the particles
+    /// along its gradients, weighted by their inverse mass, with the Lagrange
+    /// multiplier update `Δλ = (-C - α̃ λ) / (Σ wᵢ |∇Cᵢ|² + α̃)`, where
+    /// `α̃ = α / h²` is the scaled compliance.
+    ///
+    /// [0]:https://matthias-research.github.io/pages/publications/XPBD.pdf
+    pub fn step(&mut self, dt: Time, gravity: Acceleration) {
+        let substeps = self.substeps.max(1);
+        let h = dt.m() / substeps as Magnitude;
+        let mut previous = alloc::vec![Position::ZERO; self.particles.len()];
+        let mut lambdas = alloc::vec![0.; self.constraints.len()];
+        for _ in 0..substeps {
+            for (p, prev) in self.particles.iter_mut().zip(&mut previous) {
+                *prev = p.position;
+                if !p.is_pinned() {
+                    p.velocity.d += gravity.d * h;
+                    p.position += p.velocity.d * h;
+                }
+            }
+            lambdas.fill(0.);
+            for _ in 0..self.iterations {
+                for (c, lambda) in self.constraints.iter().zip(&mut lambdas) {
+                    project(&mut self.particles, c, lambda, h);
+                }
+            }
+            for (p, prev) in self.particles.iter_mut().zip(&previous) {
+                if !p.is_pinned() {
+                    p.velocity = Velocity::new((p.position - *prev) / h);
+                }
+            }
+        }
+    }
+}
+
+/// Projects the particles onto a single constraint.
+fn project(particles: &mut [Particle], c: &Constraint, lambda: &mut Magnitude, h: Magnitude) {
+    let Some((value, gradients, count)) = c.evaluate(particles) else {
+        return;
+    };
+    let gradients = &gradients[..count];
+    let weight: Magnitude = gradients
+        .iter()
+        .map(|(i, g)| particles[*i].inverse_mass * g.length_squared())
+        .sum();
+    let alpha = c.compliance() / (h * h);
+    if weight + alpha == 0. || alpha.is_infinite() {
+        return;
+    }
+    let delta = (-value - alpha * *lambda) / (weight + alpha);
+    *lambda += delta;
+    for (i, g) in gradients {
+        let p = &mut particles[*i];
+        p.position += Direction::from_vector(*g * (delta * p.inverse_mass));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soft::SpringKind;
+    use crate::units::{DampingCoefficient, Mass, SpringConstant};
+    use float_eq::assert_float_eq;
+
+    fn gravity() -> Acceleration {
+        Acceleration::new(Direction::new(0., -9.8, 0.))
+    }
+
+    /// Checks rigid distance constraints keep a swinging chain inextensible.
+    #[test]
+    fn pbd_distance() {
+        let particles = (0..=10)
+            .map(|i| Particle::new(Position::new(0.1 * i as Magnitude, 0., 0.), Mass::new(0.1)))
+            .collect();
+        let mut chain = PbdSolver::new(particles);
+        chain.particles[0] = Particle::pinned(Position::ZERO);
+        for i in 0..10 {
+            chain.add_distance(i, i + 1, 0.);
+        }
+        chain.substeps = 20;
+        for _ in 0..60 {
+            chain.step(Time::new(1. / 60.), gravity());
+        }
+        for i in 0..10 {
+            let d = chain.particles[i + 1].position - chain.particles[i].position;
+            assert_float_eq!(0.1, d.magnitude(), abs <= 1e-3);
+        }
+        // it swung down, within reach of the pivot
+        let end = chain.particles[10].position;
+        assert!(end.y() < -0.1 && end.magnitude() <= 1. + 1e-2);
+        assert_float_eq!(0., chain.particles[0].position.magnitude(), abs <= 0.);
+    }
+
+    /// Checks springs without stiffness don't constrain the particles.
+    #[test]
+    fn pbd_zero_stiffness() {
+        let mut system = MassSpring::new(
+            alloc::vec![
+                Particle::pinned(Position::ZERO),
+                Particle::new(Position::new(1., 0., 0.), Mass::new(1.)),
+            ],
+            Vec::new(),
+        );
+        let (k, c) = (SpringConstant::new(0.), DampingCoefficient::new(0.));
+        system.connect(0, 1, k, c, SpringKind::Structural);
+        let mut solver = PbdSolver::from_mass_spring(&system);
+        assert!(solver.constraints.is_empty());
+
+        // an infinite compliance is ignored too
+        solver.add_distance(0, 1, Magnitude::INFINITY);
+        for _ in 0..60 {
+            solver.step(Time::new(1. / 60.), gravity());
+        }
+        // it fell freely
+        let p = solver.particles[1].position;
+        assert_float_eq!(1., p.x(), abs <= 1e-9);
+        assert_float_eq!(-4.9, p.y(), rmax <= 0.02);
+    }
+
+    /// Checks the angle, volume and collision constraints.
+    #[test]
+    fn pbd_shapes() {
+        // a bent arm straightens
+        let mut arm = PbdSolver::new(alloc::vec![
+            Particle::new(Position::new(1., 0., 0.), Mass::new(1.)),
+            Particle::new(Position::ZERO, Mass::new(1.)),
+            Particle::new(Position::new(0., 1., 0.), Mass::new(1.)),
+        ]);
+        arm.add_distance(0, 1, 0.);
+        arm.add_distance(1, 2, 0.);
+        arm.constraints.push(Constraint::Angle {
+            particles: [0, 1, 2],
+            rest: 2.,
+            compliance: 0.,
+        });
+        for _ in 0..60 {
+            arm.step(Time::new(1. / 60.), Acceleration::new(Direction::ZERO));
+        }
+        let (u, w) = (
+            arm.particles[0].position - arm.particles[1].position,
+            arm.particles[2].position - arm.particles[1].position,
+        );
+        assert_float_eq!(
+            2.,
+            (u.dot(w) / (u.magnitude() * w.magnitude())).acos(),
+            abs <= 1e-3
+        );
+
+        // a soft tetrahedron falling on the ground keeps its volume
+        let corners = [
+            Position::new(0., 1., 0.),
+            Position::new(1., 1., 0.),
+            Position::new(0., 2., 0.),
+            Position::new(0., 1., 1.),
+        ];
+        let mut tetra = PbdSolver::new(corners.map(|p| Particle::new(p, Mass::new(1.))).to_vec());
+        for a in 0..4 {
+            for b in a + 1..4 {
+                tetra.add_distance(a, b, 1e-3);
+            }
+        }
+        tetra.add_volume([0, 1, 2, 3], 0.);
+        let ground = Plane::new(Direction::new(0., 1., 0.), Length::new(0.));
+        let radius = Length::new(0.01);
+        for particle in 0..4 {
+            tetra.constraints.push(Constraint::Plane {
+                particle,
+                plane: ground,
+                radius,
+            });
+        }
+        for _ in 0..120 {
+            tetra.step(Time::new(1. / 60.), gravity());
+        }
+        let p = |i: usize| tetra.particles[i].position;
+        let volume = (p(1) - p(0)).cross(p(2) - p(0)).dot(p(3) - p(0)) / 6.;
+        assert_float_eq!(1. / 6., volume, rmax <= 1e-3);
+        for p in &tetra.particles {
+            assert!(p.position.y() >= radius.m() - 1e-6);
+        }
+
+        // a particle rests on a ball
+        let mut drop = PbdSolver::new(alloc::vec![Particle::new(
+            Position::new(0., 3., 0.),
+            Mass::new(1.)
+        )]);
+        drop.constraints.push(Constraint::Sphere {
+            particle: 0,
+            ball: Ball::new(Position::ZERO, Length::new(1.)),
+            radius: Length::new(0.1),
+        });
+        for _ in 0..120 {
+            drop.step(Time::new(1. / 60.), gravity());
+        }
+        assert_float_eq!(1.1, drop.particles[0].position.y(), abs <= 1e-3);
+    }
+}
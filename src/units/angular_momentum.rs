@@ -0,0 +1,91 @@
+// fisica::units::angular_momentum
+//
+
+use crate::units::{Momentum, Time, Torque};
+use crate::{Direction, Magnitude};
+
+/// The rotational equivalent of [`Momentum`], in `kg m²/s`.
+///
+/// It's conserved in the absence of external [`Torque`]s.
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Angular_momentum>
+#[derive(Clone, Copy, Debug)]
+pub struct AngularMomentum {
+    pub d: Direction,
+}
+
+/// (== [`AngularMomentum`]) The change in angular momentum produced by a
+/// [`Torque`] acting over some [`Time`].
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Angular_momentum#Angular_impulse>
+pub type AngularImpulse = AngularMomentum;
+
+impl AngularMomentum {
+    /// New AngularMomentum.
+    #[inline]
+    pub const fn new(d: Direction) -> Self {
+        Self { d }
+    }
+
+    /// Returns the magnitude.
+    #[inline]
+    pub fn m(&self) -> Magnitude {
+        self.d.magnitude()
+    }
+}
+
+/// # Formulas
+impl AngularMomentum {
+    /// Returns the `AngularMomentum` of a [`Momentum`] at the lever `r`
+    /// from the pivot (`L = r × p`).
+    pub fn from_lever_momentum(r: Direction, p: Momentum) -> Self {
+        Self::new(r.cross(p.d))
+    }
+
+    /// Returns the `AngularImpulse` of a constant [`Torque`] acting over
+    /// some [`Time`] (`ΔL = τ × t`).
+    pub fn from_torque_time(torque: Torque, t: Time) -> Self {
+        Self::new(torque.d * t.m())
+    }
+
+    /// (Alias of [from_torque_time][AngularMomentum::from_torque_time]).
+    #[inline]
+    pub fn from_time_torque(t: Time, torque: Torque) -> Self {
+        Self::from_torque_time(torque, t)
+    }
+
+    /// Calculates the mean [`Torque`] that produces this `AngularImpulse`
+    /// over the given [`Time`] (`τ = ΔL / t`).
+    #[inline]
+    pub fn calc_torque(&self, t: Time) -> Torque {
+        Torque::new(self.d / t.m())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::units::Force, float_eq::assert_float_eq};
+
+    /// Checks the formulas behave as expected.
+    #[test]
+    fn angular_momentum_formulas() {
+        // a momentum of 2 kg·m/s along +y at 1 m along +x spins around +z
+        let p = Momentum::new(Direction::new(0., 2., 0.));
+        let l = AngularMomentum::from_lever_momentum(Direction::new(1., 0., 0.), p);
+        assert_float_eq!(2., l.d.z(), r2nd <= Magnitude::EPSILON);
+
+        let torque = Torque::from_lever_force(
+            Direction::new(0.5, 0., 0.),
+            Force::new(Direction::new(0., 10., 0.)),
+        );
+        let impulse = AngularImpulse::from_torque_time(torque, Time::new(3.));
+        assert_float_eq!(15., impulse.d.z(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            5.,
+            impulse.calc_torque(Time::new(3.)).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+    }
+}
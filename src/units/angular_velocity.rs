@@ -0,0 +1,83 @@
+// fisica::units::angular_velocity
+//
+
+use crate::units::{Acceleration, Time, Velocity};
+use crate::{Direction, Magnitude, Orientation, Position};
+
+/// The rate of rotation of a body, in radians per [`Time`]: `rad/s`.
+///
+/// This is a vector unit. It points along the axis of rotation, following
+/// the right-hand rule, and its magnitude is the angular speed.
+///
+/// <https://en.wikipedia.org/wiki/Angular_velocity>
+#[derive(Clone, Copy, Debug)]
+pub struct AngularVelocity {
+    pub d: Direction,
+}
+
+impl AngularVelocity {
+    /// New AngularVelocity.
+    #[inline]
+    pub const fn new(d: Direction) -> Self {
+        Self { d }
+    }
+
+    /// Returns the magnitude, the angular speed in `rad/s`.
+    pub fn m(&self) -> Magnitude {
+        self.d.magnitude()
+    }
+
+    /// No rotation.
+    pub const ZERO: Self = Self::new(Direction::ZERO);
+}
+
+/// # Formulas
+impl AngularVelocity {
+    /// Calculates the tangential [`Velocity`] of a point at the offset `r`
+    /// from the axis of rotation (`v = ω × r`).
+    pub fn calc_velocity(&self, r: Position) -> Velocity {
+        Velocity::new(self.d.cross(r))
+    }
+
+    /// Calculates the centripetal [`Acceleration`] of a point at the offset
+    /// `r` from the axis, rotating with it (`a = ω × (ω × r)`).
+    ///
+    /// It points towards the axis, with a magnitude of `ω² r`.
+    pub fn calc_centripetal_acceleration(&self, r: Position) -> Acceleration {
+        Acceleration::new(self.d.cross(self.d.cross(r)))
+    }
+
+    /// Returns the [`Orientation`] reached after rotating during the given
+    /// [`Time`] from the `initial` orientation, at a constant angular velocity.
+    pub fn calc_orientation(&self, initial: Orientation, t: Time) -> Orientation {
+        let angle = self.m() * t.m();
+        if angle <= Magnitude::EPSILON {
+            return initial;
+        }
+        (Orientation::from_axis_angle(self.d.normalize().vector(), angle) * initial).normalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the formulas behave as expected.
+    #[test]
+    fn angular_velocity_formulas() {
+        let w = AngularVelocity::new(Direction::new(0., 0., 2.));
+        let v = w.calc_velocity(Position::new(3., 0., 0.));
+        assert_float_eq!(6., v.d.y(), r2nd <= Magnitude::EPSILON);
+        let a = w.calc_centripetal_acceleration(Position::new(3., 0., 1.));
+        assert_float_eq!(-12., a.d.x(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(0., a.d.z(), abs <= 0.);
+
+        let o = w.calc_orientation(
+            Orientation::IDENTITY,
+            Time::new(core::f64::consts::FRAC_PI_4),
+        );
+        let x = Direction::new(1., 0., 0.).rotate(o);
+        assert_float_eq!(0., x.x(), abs <= 1e-12);
+        assert_float_eq!(1., x.y(), abs <= 1e-12);
+    }
+}
@@ -0,0 +1,69 @@
+// fisica::units::conductivity
+//
+
+use crate::units::{Area, Length, Power, Temperature};
+use crate::Magnitude;
+
+/// The ability of a material to conduct heat, the [`Power`] that flows
+/// through a unit [`Area`] per unit of [`Temperature`] gradient, in
+/// `W/(m K)`.
+///
+/// Steady conduction through a slab follows [*Fourier's law*][0]
+/// (`P = k A ΔT / d`).
+///
+/// [0]:https://en.wikipedia.org/wiki/Thermal_conduction#Fourier's_law
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Thermal_conductivity_and_resistivity>
+#[derive(Clone, Copy, Debug)]
+pub struct ThermalConductivity {
+    pub m: Magnitude,
+}
+
+impl ThermalConductivity {
+    /// New ThermalConductivity.
+    #[inline]
+    pub const fn new(m: Magnitude) -> Self {
+        Self { m }
+    }
+
+    /// Returns the magnitude.
+    #[inline]
+    pub const fn m(&self) -> Magnitude {
+        self.m
+    }
+}
+
+/// # Formulas
+impl ThermalConductivity {
+    /// Calculates the heat [`Power`] conducted through a slab of the given
+    /// [`Area`] and `thickness`, with a [`Temperature`] difference between
+    /// its faces (`P = k A ΔT / d`).
+    #[inline]
+    pub fn calc_heat_flow(&self, a: Area, dt: Temperature, thickness: Length) -> Power {
+        Power::new(self.m * a.m() * dt.m() / thickness.m())
+    }
+
+    /// Calculates the thermal resistance of a slab of the given [`Area`] and
+    /// `thickness`, in `K/W` (`R = d / (k A)`).
+    #[inline]
+    pub fn calc_resistance(&self, a: Area, thickness: Length) -> Magnitude {
+        thickness.m() / (self.m * a.m())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the formulas behave as expected.
+    #[test]
+    fn conductivity_formulas() {
+        // a glass window of 2 m², 4 mm thick, with 20 K of difference
+        let k = ThermalConductivity::new(1.);
+        let (a, d) = (Area::new(2.), Length::new(0.004));
+        let p = k.calc_heat_flow(a, Temperature::new(20.), d);
+        assert_float_eq!(10_000., p.m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(0.002, k.calc_resistance(a, d), r2nd <= Magnitude::EPSILON);
+    }
+}
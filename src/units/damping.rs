@@ -0,0 +1,99 @@
+// fisica::units::damping
+//
+
+use crate::units::{Force, Power, Velocity};
+use crate::{Direction, Magnitude, Position};
+
+/// The resistance of a viscous damper, the [`Force`] produced per unit of
+/// [`Velocity`], in `N s/m`.
+///
+/// A linear damper opposes the motion (`F = -c v`).
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Damping>
+/// - <https://en.wikipedia.org/wiki/Dashpot>
+#[derive(Clone, Copy, Debug)]
+pub struct DampingCoefficient {
+    pub m: Magnitude,
+}
+
+impl DampingCoefficient {
+    /// New DampingCoefficient.
+    #[inline]
+    pub const fn new(m: Magnitude) -> Self {
+        Self { m }
+    }
+
+    /// Returns the magnitude.
+    #[inline]
+    pub const fn m(&self) -> Magnitude {
+        self.m
+    }
+}
+
+/// # Formulas
+impl DampingCoefficient {
+    /// Derives the `DampingCoefficient` from the [`Force`] magnitude produced
+    /// at some [`Velocity`] magnitude (`c = F / v`).
+    #[inline]
+    pub fn from_force_velocity(f: Force, v: Velocity) -> Self {
+        Self::new(f.m() / v.m())
+    }
+
+    /// Calculates the [`Force`] opposing the given [`Velocity`] (`F = -c v`).
+    #[inline]
+    pub fn calc_force(&self, v: Velocity) -> Force {
+        Force::new(v.d * -self.m)
+    }
+
+    /// Calculates the [`Power`] dissipated at the given [`Velocity`] (`P = c v²`).
+    #[inline]
+    pub fn calc_power(&self, v: Velocity) -> Power {
+        Power::new(self.m * v.d.magnitude_squared())
+    }
+
+    /// Calculates the [`Force`] on the end at `a` of a damper between the
+    /// [`Position`]s `a` and `b`, moving at the [`Velocity`]s `va` and `vb`.
+    ///
+    /// It opposes only the relative motion along the axis of the damper.
+    /// The force on `b` is the opposite.
+    pub fn calc_force_between(
+        &self,
+        a: Position,
+        b: Position,
+        va: Velocity,
+        vb: Velocity,
+    ) -> Force {
+        let axis = b - a;
+        let len = axis.magnitude();
+        if len == 0. {
+            return Force::new(Direction::ZERO);
+        }
+        let n = axis / len;
+        Force::new(n * (self.m * (vb.d - va.d).dot(n)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the formulas behave as expected.
+    #[test]
+    fn damping_formulas() {
+        let c = DampingCoefficient::new(4.);
+        let v = Velocity::new(Direction::new(0., -2., 0.));
+        assert_float_eq!(8., c.calc_force(v).d.y(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(16., c.calc_power(v).m(), r2nd <= Magnitude::EPSILON);
+        let back = DampingCoefficient::from_force_velocity(c.calc_force(v), v);
+        assert_float_eq!(4., back.m(), r2nd <= Magnitude::EPSILON);
+
+        // only the relative motion along the axis is damped
+        let (a, b) = (Position::ZERO, Position::new(1., 0., 0.));
+        let va = Velocity::new(Direction::new(0., 5., 0.));
+        let vb = Velocity::new(Direction::new(3., 0., 0.));
+        let f = c.calc_force_between(a, b, va, vb);
+        assert_float_eq!(12., f.d.x(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(0., f.d.y(), abs <= Magnitude::EPSILON);
+    }
+}
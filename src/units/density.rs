@@ -3,7 +3,6 @@
 //!
 //
 
-#[allow(unused_imports)]
 use crate::units::{Mass, Volume};
 use crate::Magnitude;
 
@@ -29,6 +28,33 @@ impl Density {
     }
 }
 
+/// # `Density` formulas
+impl Density {
+    /// Derives the `Density` from the given [`Mass`] and [`Volume`] (`ρ = m / V`).
+    #[inline]
+    pub fn from_mass_volume(m: Mass, v: Volume) -> Self {
+        Self::new(m.m() / v.m())
+    }
+
+    /// (Alias of [from_mass_volume][Density::from_mass_volume]).
+    #[inline]
+    pub fn from_volume_mass(v: Volume, m: Mass) -> Self {
+        Self::from_mass_volume(m, v)
+    }
+
+    /// Calculates the [`Mass`] given the [`Volume`] (`m = ρ × V`).
+    #[inline]
+    pub fn calc_mass(&self, v: Volume) -> Mass {
+        Mass::new(self.m * v.m())
+    }
+
+    /// Calculates the [`Volume`] given the [`Mass`] (`V = m / ρ`).
+    #[inline]
+    pub fn calc_volume(&self, m: Mass) -> Volume {
+        Volume::new(m.m() / self.m)
+    }
+}
+
 impl_scalar_methods_2units_base_kilo![
     Density,
     q1a = g,
@@ -41,3 +67,26 @@ impl_scalar_methods_2units_base_kilo![
     Q1u = "grams",
     Q2u = "metre cubed"
 ];
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the formulas behave as expected.
+    #[test]
+    fn density_formulas() {
+        // Density, Mass & Volume
+        let density = Density::from_mass_volume(Mass::new(800.), Volume::new(2.));
+        assert_float_eq!(400., density.m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            800.,
+            density.calc_mass(Volume::new(2.)).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+        assert_float_eq!(
+            2.,
+            density.calc_volume(Mass::new(800.)).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+    }
+}
@@ -3,8 +3,13 @@
 //!
 //
 
-use crate::units::{Distance, Force, Mass, Power, Speed, Time};
-use crate::Magnitude;
+use alloc::vec::Vec;
+
+use crate::units::{
+    AngularVelocity, Distance, Force, Gfs, Height, InertiaTensor, Length, Mass, MomentOfInertia,
+    Power, Speed, SpringConstant, Time, Velocity,
+};
+use crate::{Direction, Magnitude};
 
 /// `Energy`, in joules: `J`.
 #[derive(Clone, Copy, Debug)]
@@ -19,7 +24,20 @@ pub struct Energy {
 pub type Work = Energy;
 
 impl Work {
+    /// Returns the `Work` done by a constant [`Force`] along the displacement
+    /// `d` (`W = F · d`).
+    ///
+    /// Only the component of the force along the displacement does work,
+    /// which is negative when it opposes the motion.
+    #[inline]
+    pub fn from_force_displacement(f: Force, d: Direction) -> Self {
+        Self::new(f.d.dot(d))
+    }
+
     /// Work (J) = [`Force`] (N) × [`Distance`] (m)
+    ///
+    /// This is the scalar special case of [`from_force_displacement`][Work::from_force_displacement],
+    /// valid only when the force is along the displacement.
     #[inline]
     pub fn from_force_length(f: Force, d: Distance) -> Self {
         Self::new(f.m() * d.m())
@@ -75,8 +93,167 @@ impl Energy {
     }
 }
 
+/// # Mechanical energy formulas
+impl Energy {
+    /// Returns the kinetic `Energy` of a [`Mass`] moving at some [`Velocity`]
+    /// (`K = ½ m v²`).
+    #[inline]
+    pub fn from_mass_velocity(m: Mass, v: Velocity) -> Self {
+        Self::new(0.5 * m.m() * v.d.magnitude_squared())
+    }
+
+    /// Returns the kinetic `Energy` of a [`Mass`] moving at some [`Speed`]
+    /// (`K = ½ m s²`).
+    #[inline]
+    pub fn from_mass_speed(m: Mass, s: Speed) -> Self {
+        Self::new(0.5 * m.m() * s.m() * s.m())
+    }
+
+    /// Calculates the [`Speed`] of a given [`Mass`] with this kinetic `Energy`
+    /// (`s = √(2K / m)`).
+    #[inline]
+    pub fn calc_speed(&self, m: Mass) -> Speed {
+        Speed::new((2. * self.m / m.m()).sqrt())
+    }
+
+    /// Returns the gravitational potential `Energy` of a [`Mass`] at some
+    /// [`Height`] over the reference level, in a uniform [`Gfs`] (`U = m g h`).
+    #[inline]
+    pub fn from_mass_gfs_height(m: Mass, g: Gfs, h: Height) -> Self {
+        Self::new(m.m() * g.m() * h.m())
+    }
+
+    /// Calculates the [`Height`] a [`Mass`] in a uniform [`Gfs`] reaches with
+    /// this potential `Energy` (`h = U / m g`).
+    #[inline]
+    pub fn calc_height(&self, m: Mass, g: Gfs) -> Height {
+        Length::new(self.m / (m.m() * g.m()))
+    }
+
+    /// Returns the elastic potential `Energy` of a spring with the given
+    /// [`SpringConstant`], stretched or compressed by some `extension`
+    /// from its rest length (`U = ½ k x²`).
+    #[inline]
+    pub fn from_stiffness_extension(k: SpringConstant, extension: Length) -> Self {
+        Self::new(0.5 * k.m() * extension.m() * extension.m())
+    }
+
+    /// Returns the rotational kinetic `Energy` of a body spinning at some
+    /// [`AngularVelocity`] about an axis with the given [`MomentOfInertia`]
+    /// (`K = ½ I ω²`).
+    #[inline]
+    pub fn from_inertia_angular_velocity(i: MomentOfInertia, w: AngularVelocity) -> Self {
+        Self::new(0.5 * i.m() * w.d.magnitude_squared())
+    }
+
+    /// Returns the rotational kinetic `Energy` of a body spinning at some
+    /// [`AngularVelocity`], given its [`InertiaTensor`] in the same frame
+    /// (`K = ½ ω · I ω`).
+    #[inline]
+    pub fn from_inertia_tensor_angular_velocity(i: InertiaTensor, w: AngularVelocity) -> Self {
+        let iw = Direction::from_vector(i.t.mul_vec3(w.d.vector()));
+        Self::new(0.5 * w.d.dot(iw))
+    }
+}
+
 impl_scalar_methods![Energy, J, joules];
 
+/// Tracks the [`Energy`] components of a system over time.
+///
+/// The components are meant to add up to a constant total, like the kinetic
+/// and potential energies of a conservative system, plus the energy
+/// dissipated so far, if any. The change of the total from the first record,
+/// the *drift*, measures the error accumulated by a simulation.
+#[derive(Clone, Debug)]
+pub struct EnergyBalance {
+    names: Vec<&'static str>,
+    records: Vec<(Time, Vec<Energy>)>,
+}
+
+impl EnergyBalance {
+    /// New `EnergyBalance` with the names of the tracked components.
+    pub fn new(names: &[&'static str]) -> Self {
+        Self {
+            names: names.to_vec(),
+            records: Vec::new(),
+        }
+    }
+
+    /// Returns the names of the components.
+    pub fn names(&self) -> &[&'static str] {
+        &self.names
+    }
+
+    /// Returns the number of records.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns `true` if there are no records.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Records the value of each component at the given [`Time`].
+    ///
+    /// # Panics
+    /// Panics if the number of values doesn't match the number of components.
+    pub fn record(&mut self, t: Time, values: &[Energy]) {
+        assert_eq!(self.names.len(), values.len());
+        self.records.push((t, values.to_vec()));
+    }
+
+    /// Returns the [`Time`] and the total [`Energy`] of each record.
+    pub fn totals(&self) -> Vec<(Time, Energy)> {
+        self.records
+            .iter()
+            .map(|(t, values)| (*t, Self::sum(values)))
+            .collect()
+    }
+
+    /// Returns the change of the named component from the first record
+    /// to the last one, or `None` if there's no such component or records.
+    pub fn calc_change(&self, name: &str) -> Option<Energy> {
+        let i = self.names.iter().position(|n| *n == name)?;
+        let (first, last) = (self.records.first()?, self.records.last()?);
+        Some(Energy::new(last.1[i].m - first.1[i].m))
+    }
+
+    /// Returns the change of the total from the first record to the last one.
+    pub fn absolute_drift(&self) -> Energy {
+        match (self.records.first(), self.records.last()) {
+            (Some(first), Some(last)) => Energy::new(Self::sum(&last.1).m - Self::sum(&first.1).m),
+            _ => Energy::new(0.),
+        }
+    }
+
+    /// Returns the relative drift of the last total from the first one
+    /// (`|E - E₀| / |E₀|`).
+    ///
+    /// It's not finite if the initial total is zero. See
+    /// [`absolute_drift`][Self::absolute_drift].
+    pub fn drift(&self) -> Magnitude {
+        let initial = self.records.first().map_or(0., |r| Self::sum(&r.1).m);
+        (self.absolute_drift().m / initial).abs()
+    }
+
+    /// Returns the largest relative drift of any total from the first one.
+    pub fn max_drift(&self) -> Magnitude {
+        let totals = self.totals();
+        let Some((_, initial)) = totals.first() else {
+            return 0.;
+        };
+        totals
+            .iter()
+            .map(|(_, e)| ((e.m - initial.m) / initial.m).abs())
+            .fold(0., Magnitude::max)
+    }
+
+    fn sum(values: &[Energy]) -> Energy {
+        Energy::new(values.iter().map(|e| e.m).sum())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {super::*, float_eq::assert_float_eq};
@@ -106,5 +283,76 @@ mod tests {
             energy.calc_time(Power::new(800.)).m(),
             r2nd <= Magnitude::EPSILON
         );
+
+        // Work, Force & displacement
+        let f = Force::new(Direction::new(10., 10., 0.));
+        let w = Work::from_force_displacement(f, Direction::new(3., 0., 0.));
+        assert_float_eq!(30., w.m(), r2nd <= Magnitude::EPSILON);
+        let w = Work::from_force_displacement(f, Direction::new(0., 0., 3.));
+        assert_float_eq!(0., w.m(), abs <= Magnitude::EPSILON);
+    }
+
+    /// Checks the mechanical energy formulas.
+    #[test]
+    fn energy_mechanical() {
+        let m = Mass::new(2.);
+        let v = Velocity::new(Direction::new(3., 4., 0.));
+        let k = Energy::from_mass_velocity(m, v);
+        assert_float_eq!(25., k.m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            k.m(),
+            Energy::from_mass_speed(m, Speed::new(5.)).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+        assert_float_eq!(5., k.calc_speed(m).m(), r2nd <= Magnitude::EPSILON);
+
+        let g = Gfs::new(Direction::new(0., 9.8, 0.));
+        let u = Energy::from_mass_gfs_height(m, g, Length::new(10.));
+        assert_float_eq!(196., u.m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(10., u.calc_height(m, g).m(), r2nd <= Magnitude::EPSILON);
+
+        let spring = Energy::from_stiffness_extension(SpringConstant::new(200.), Length::new(-0.1));
+        assert_float_eq!(1., spring.m(), r2nd <= Magnitude::EPSILON);
+
+        let w = AngularVelocity::new(Direction::new(0., 0., 2.));
+        let i = MomentOfInertia::new(3.);
+        let spin = Energy::from_inertia_angular_velocity(i, w);
+        assert_float_eq!(6., spin.m(), r2nd <= Magnitude::EPSILON);
+        let tensor =
+            InertiaTensor::from_principal(MomentOfInertia::new(1.), MomentOfInertia::new(2.), i);
+        assert_float_eq!(
+            6.,
+            Energy::from_inertia_tensor_angular_velocity(tensor, w).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+    }
+
+    /// Checks the energy balance of a falling body.
+    #[test]
+    fn energy_balance() {
+        let (m, g, h0) = (Mass::new(1.), Gfs::new(Direction::new(0., 9.8, 0.)), 100.);
+        let mut balance = EnergyBalance::new(&["kinetic", "potential"]);
+        let (mut h, mut v, dt) = (h0, 0., 0.1);
+        for i in 0..=20 {
+            if i > 0 {
+                // explicit Euler gains energy
+                h -= v * dt;
+                v += g.m() * dt;
+            }
+            balance.record(
+                Time::new(i as Magnitude * dt),
+                &[
+                    Energy::from_mass_speed(m, Speed::new(v)),
+                    Energy::from_mass_gfs_height(m, g, Length::new(h)),
+                ],
+            );
+        }
+        assert_eq![21, balance.len()];
+        assert!(balance.drift() > 0. && balance.drift() < 0.02);
+        assert!(balance.max_drift() >= balance.drift());
+        assert!(balance.absolute_drift().m() > 0.);
+        let fall = balance.calc_change("potential").unwrap();
+        assert_float_eq!(-9.8 * (h0 - h), fall.m(), r2nd <= 1e-12);
+        assert!(balance.calc_change("thermal").is_none());
     }
 }
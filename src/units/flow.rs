@@ -0,0 +1,142 @@
+// fisica::units::flow
+//
+
+use crate::units::{Area, Density, Mass, Speed, Time, Volume};
+use crate::Magnitude;
+
+/// The [`Volume`] of fluid that passes through a surface per unit of
+/// [`Time`], in `m³/s`.
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Volumetric_flow_rate>
+#[derive(Clone, Copy, Debug)]
+pub struct VolumetricFlowRate {
+    pub m: Magnitude,
+}
+
+/// The [`Mass`] of fluid that passes through a surface per unit of
+/// [`Time`], in `kg/s`.
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Mass_flow_rate>
+#[derive(Clone, Copy, Debug)]
+pub struct MassFlowRate {
+    pub m: Magnitude,
+}
+
+impl VolumetricFlowRate {
+    /// New VolumetricFlowRate.
+    #[inline]
+    pub const fn new(m: Magnitude) -> Self {
+        Self { m }
+    }
+
+    /// Returns the magnitude.
+    #[inline]
+    pub const fn m(&self) -> Magnitude {
+        self.m
+    }
+}
+
+/// # Formulas
+impl VolumetricFlowRate {
+    /// Derives the `VolumetricFlowRate` of a flow at the given mean [`Speed`]
+    /// through a cross-section [`Area`] (`Q = A u`).
+    #[inline]
+    pub fn from_area_speed(a: Area, s: Speed) -> Self {
+        Self::new(a.m() * s.m())
+    }
+
+    /// Derives the `VolumetricFlowRate` of a [`Volume`] passing in a [`Time`]
+    /// (`Q = V / t`).
+    #[inline]
+    pub fn from_volume_time(v: Volume, t: Time) -> Self {
+        Self::new(v.m() / t.m())
+    }
+
+    /// Calculates the mean [`Speed`] of the flow through a cross-section
+    /// [`Area`] (`u = Q / A`).
+    ///
+    /// By continuity the flow rate of an incompressible fluid is the same
+    /// through every section of a pipe, so its speed changes inversely
+    /// with the area (`A₁ u₁ = A₂ u₂`).
+    #[inline]
+    pub fn calc_speed(&self, a: Area) -> Speed {
+        Speed::new(self.m / a.m())
+    }
+
+    /// Calculates the [`Volume`] passed in a [`Time`] (`V = Q t`).
+    #[inline]
+    pub fn calc_volume(&self, t: Time) -> Volume {
+        Volume::new(self.m * t.m())
+    }
+
+    /// Calculates the [`MassFlowRate`] of a fluid of the given [`Density`]
+    /// (`ṁ = ρ Q`).
+    #[inline]
+    pub fn calc_mass_flow_rate(&self, d: Density) -> MassFlowRate {
+        MassFlowRate::new(d.m() * self.m)
+    }
+}
+
+impl MassFlowRate {
+    /// New MassFlowRate.
+    #[inline]
+    pub const fn new(m: Magnitude) -> Self {
+        Self { m }
+    }
+
+    /// Returns the magnitude.
+    #[inline]
+    pub const fn m(&self) -> Magnitude {
+        self.m
+    }
+}
+
+/// # Formulas
+impl MassFlowRate {
+    /// Derives the `MassFlowRate` of a [`Mass`] passing in a [`Time`]
+    /// (`ṁ = m / t`).
+    #[inline]
+    pub fn from_mass_time(m: Mass, t: Time) -> Self {
+        Self::new(m.m() / t.m())
+    }
+
+    /// Calculates the [`Mass`] passed in a [`Time`] (`m = ṁ t`).
+    #[inline]
+    pub fn calc_mass(&self, t: Time) -> Mass {
+        Mass::new(self.m * t.m())
+    }
+
+    /// Calculates the [`VolumetricFlowRate`] of a fluid of the given
+    /// [`Density`] (`Q = ṁ / ρ`).
+    #[inline]
+    pub fn calc_volumetric_flow_rate(&self, d: Density) -> VolumetricFlowRate {
+        VolumetricFlowRate::new(self.m / d.m())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the formulas behave as expected.
+    #[test]
+    fn flow_formulas() {
+        // 2 m/s through a section of 0.01 m², narrowing to 0.004 m²
+        let q = VolumetricFlowRate::from_area_speed(Area::new(0.01), Speed::new(2.));
+        assert_float_eq!(0.02, q.m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(5., q.calc_speed(Area::new(0.004)).m(), r2nd <= 1e-15);
+        assert_float_eq!(1.2, q.calc_volume(Time::new(60.)).m(), r2nd <= 1e-15);
+        let q2 = VolumetricFlowRate::from_volume_time(Volume::new(1.2), Time::new(60.));
+        assert_float_eq!(q.m(), q2.m(), r2nd <= 1e-15);
+
+        let mdot = q.calc_mass_flow_rate(Density::new(1000.));
+        assert_float_eq!(20., mdot.m(), r2nd <= 1e-15);
+        assert_float_eq!(1200., mdot.calc_mass(Time::new(60.)).m(), r2nd <= 1e-15);
+        let back = mdot.calc_volumetric_flow_rate(Density::new(1000.));
+        assert_float_eq!(q.m(), back.m(), r2nd <= 1e-15);
+        let mdot2 = MassFlowRate::from_mass_time(Mass::new(1200.), Time::new(60.));
+        assert_float_eq!(mdot.m(), mdot2.m(), r2nd <= 1e-15);
+    }
+}
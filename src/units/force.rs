@@ -4,7 +4,7 @@
 //
 
 use crate::units::{Acceleration, Distance, GravitationalFieldStrength, Length, Mass, Moment};
-use crate::{Direction, Magnitude};
+use crate::{constants::GRAVITATIONAL_CONSTANT, Direction, Magnitude, Position};
 
 /// Any interaction that, when unopposed, will change the motion of an object,
 /// measured in `N` (newtons).
@@ -86,6 +86,26 @@ impl Force {
     }
 }
 
+/// # Formulas: gravitation
+impl Force {
+    /// Returns the gravitational `Force` exerted on the mass `m1` at `p1`
+    /// by the mass `m2` at `p2`, by [*Newton's law of universal
+    /// gravitation*][0] (`F = G m₁ m₂ / r²`).
+    ///
+    /// The force points from `p1` towards `p2`, and is zero if both
+    /// positions coincide.
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Newton%27s_law_of_universal_gravitation
+    pub fn from_gravitation(m1: Mass, p1: Position, m2: Mass, p2: Position) -> Self {
+        let r = p2 - p1;
+        let r2 = r.magnitude_squared();
+        if r2 == 0. {
+            return Self::new(Direction::ZERO);
+        }
+        Self::new(r * (GRAVITATIONAL_CONSTANT * m1.m() * m2.m() / (r2 * r2.sqrt())))
+    }
+}
+
 /// # Formulas: [`Weight`]
 impl Weight {
     /// Returns the `Weight` given the [`Mass`] and [`GravitationalFieldStrength`]
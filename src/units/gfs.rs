@@ -3,7 +3,9 @@
 //!
 //
 
-use crate::{Direction, Magnitude};
+use crate::celestial::CelestialBody;
+use crate::units::{Force, Mass};
+use crate::{Direction, Magnitude, Position};
 
 /// in `N/kg`.
 #[derive(Clone, Copy, Debug)]
@@ -28,43 +30,77 @@ impl GravitationalFieldStrength {
     }
 }
 
-/// # `Length` constants by order of magnitude
+/// # Formulas
 impl GravitationalFieldStrength {
+    /// Returns the field at the [`Position`] `at`, due to a point [`Mass`]
+    /// at `source` (`g = G m / r²`), pointing towards the source.
+    ///
+    /// It's zero at the source itself.
+    pub fn from_mass_position(m: Mass, source: Position, at: Position) -> Self {
+        Self::new(Force::from_gravitation(Mass::new(1.), at, m, source).d)
+    }
+
+    /// Returns the field at the [`Position`] `at`, due to a set of point
+    /// masses, as the sum of their individual fields.
+    pub fn from_masses(masses: &[(Mass, Position)], at: Position) -> Self {
+        Self::new(masses.iter().fold(Direction::ZERO, |g, (m, p)| {
+            g + Self::from_mass_position(*m, *p, at).d
+        }))
+    }
+}
+
+/// # Surface gravity of celestial bodies
+///
+/// Computed from the [`CelestialBody`] catalogue (`g = G M / R²`), at the
+/// mean radius and without the effect of rotation.
+impl GravitationalFieldStrength {
+    /// At the surface of [`CelestialBody::MERCURY`].
     pub fn in_mercury() -> Self {
-        Self::new(Direction::new(0., 3.8, 0.))
+        CelestialBody::MERCURY.surface_gfs()
     }
+    /// At the surface of [`CelestialBody::VENUS`].
     pub fn in_venus() -> Self {
-        Self::new(Direction::new(0., 8.8, 0.))
+        CelestialBody::VENUS.surface_gfs()
     }
+    /// At the surface of [`CelestialBody::EARTH`].
     pub fn in_earth() -> Self {
-        Self::new(Direction::new(0., 9.8, 0.))
+        CelestialBody::EARTH.surface_gfs()
     }
+    /// At the surface of [`CelestialBody::MARS`].
     pub fn in_mars() -> Self {
-        Self::new(Direction::new(0., 3.8, 0.))
+        CelestialBody::MARS.surface_gfs()
     }
+    /// At the surface of [`CelestialBody::JUPITER`].
     pub fn in_jupiter() -> Self {
-        Self::new(Direction::new(0., 25., 0.))
+        CelestialBody::JUPITER.surface_gfs()
     }
+    /// At the surface of [`CelestialBody::SATURN`].
     pub fn in_saturn() -> Self {
-        Self::new(Direction::new(0., 10.4, 0.))
+        CelestialBody::SATURN.surface_gfs()
     }
+    /// At the surface of [`CelestialBody::URANUS`].
     pub fn in_uranus() -> Self {
-        Self::new(Direction::new(0., 10.4, 0.))
+        CelestialBody::URANUS.surface_gfs()
     }
+    /// At the surface of [`CelestialBody::NEPTUNE`].
     pub fn in_neptune() -> Self {
-        Self::new(Direction::new(0., 13.8, 0.))
+        CelestialBody::NEPTUNE.surface_gfs()
     }
+    /// At the surface of [`CelestialBody::MOON`].
     pub fn in_moon() -> Self {
-        Self::new(Direction::new(0., 1.6, 0.))
+        CelestialBody::MOON.surface_gfs()
     }
+    /// At the surface of [`CelestialBody::PLUTO`].
     pub fn in_pluto() -> Self {
-        Self::new(Direction::new(0., 0.49, 0.))
+        CelestialBody::PLUTO.surface_gfs()
     }
+    /// At the surface of [`CelestialBody::CERES`].
     pub fn in_ceres() -> Self {
-        Self::new(Direction::new(0., 0.27, 0.))
+        CelestialBody::CERES.surface_gfs()
     }
+    /// At the surface of [`CelestialBody::SUN`].
     pub fn in_sun() -> Self {
-        Self::new(Direction::new(0., 293., 0.))
+        CelestialBody::SUN.surface_gfs()
     }
 }
 
@@ -0,0 +1,217 @@
+// fisica::units::inertia
+//
+
+use core::ops::{Add, AddAssign};
+
+use crate::units::{Distance, Length, Mass};
+use crate::{math::Matrix, Direction, Magnitude, Orientation};
+
+/// The resistance of a body to angular acceleration about an axis,
+/// in `kg m²` ([`Mass`] times squared [`Length`]).
+///
+/// This is a scalar unit. See also [`InertiaTensor`].
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Moment_of_inertia>
+#[derive(Clone, Copy, Debug)]
+pub struct MomentOfInertia {
+    pub m: Magnitude,
+}
+
+impl MomentOfInertia {
+    /// New MomentOfInertia.
+    #[inline]
+    pub const fn new(m: Magnitude) -> Self {
+        Self { m }
+    }
+
+    /// Returns the magnitude.
+    #[inline]
+    pub const fn m(&self) -> Magnitude {
+        self.m
+    }
+}
+
+/// # `MomentOfInertia` formulas
+impl MomentOfInertia {
+    /// Derives the `MomentOfInertia` of a point [`Mass`] at some [`Distance`]
+    /// from the axis (`I = m × r²`).
+    #[inline]
+    pub fn from_mass_distance(m: Mass, r: Distance) -> Self {
+        Self::new(m.m() * r.m() * r.m())
+    }
+
+    /// (Alias of [from_mass_distance][MomentOfInertia::from_mass_distance]).
+    #[inline]
+    pub fn from_distance_mass(r: Distance, m: Mass) -> Self {
+        Self::from_mass_distance(m, r)
+    }
+
+    /// Calculates the [*radius of gyration*][0] given the [`Mass`] (`k = √(I / m)`).
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Radius_of_gyration
+    #[inline]
+    pub fn calc_radius_of_gyration(&self, m: Mass) -> Length {
+        Length::new((self.m / m.m()).sqrt())
+    }
+}
+
+/// The distribution of [`Mass`] of a body around its center of mass,
+/// in `kg m²`.
+///
+/// It's a symmetric 3×3 [`Matrix`] whose diagonal holds the moments of inertia
+/// about the X, Y and Z axes, and whose off-diagonal elements are the negated
+/// products of inertia.
+///
+/// $$
+/// \bm{I} = \begin{bmatrix}
+///     I_{xx} & I_{xy} & I_{xz} \cr
+///     I_{yx} & I_{yy} & I_{yz} \cr
+///     I_{zx} & I_{zy} & I_{zz}
+/// \end{bmatrix}
+/// $$
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Moment_of_inertia#Inertia_tensor>
+/// - <https://en.wikipedia.org/wiki/List_of_moments_of_inertia>
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct InertiaTensor {
+    pub t: Matrix,
+}
+
+impl InertiaTensor {
+    /// New InertiaTensor.
+    #[inline]
+    pub const fn new(t: Matrix) -> Self {
+        Self { t }
+    }
+
+    /// New `InertiaTensor` from its principal moments of inertia,
+    /// along the X, Y and Z axes.
+    pub fn from_principal(x: MomentOfInertia, y: MomentOfInertia, z: MomentOfInertia) -> Self {
+        Self::new(Matrix::from_diagonal(glam::DVec3::new(x.m, y.m, z.m)))
+    }
+
+    /// Returns the moments of inertia about the X, Y and Z axes.
+    pub fn diagonal(&self) -> [MomentOfInertia; 3] {
+        [
+            MomentOfInertia::new(self.t.x_axis.x),
+            MomentOfInertia::new(self.t.y_axis.y),
+            MomentOfInertia::new(self.t.z_axis.z),
+        ]
+    }
+
+    /// All zeros.
+    pub const ZERO: Self = Self::new(Matrix::ZERO);
+}
+
+/// # `InertiaTensor` formulas
+impl InertiaTensor {
+    /// Calculates the [`MomentOfInertia`] about an axis passing through the
+    /// center of mass (`I = n̂ᵀ I n̂`).
+    pub fn calc_moment_about(&self, axis: Direction) -> MomentOfInertia {
+        let n = axis.normalize().vector();
+        MomentOfInertia::new(n.dot(self.t * n))
+    }
+
+    /// Returns the tensor expressed in axes rotated by the given [`Orientation`]
+    /// (`I' = R I Rᵀ`).
+    pub fn rotated(&self, o: Orientation) -> Self {
+        let r = Matrix::from_quat(o);
+        Self::new(r * self.t * r.transpose())
+    }
+
+    /// Returns the tensor about a point displaced by `offset` from the center
+    /// of mass of a body with the given [`Mass`], using the
+    /// [*parallel axis theorem*][0].
+    ///
+    /// $$
+    /// \bm{I}' = \bm{I} + m\left(|\bm{d}|^2 \bm{E} - \bm{d}\bm{d}^{T}\right)
+    /// $$
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Parallel_axis_theorem
+    pub fn translated(&self, m: Mass, offset: Direction) -> Self {
+        let d = offset.vector();
+        let outer = Matrix::from_cols(d * d.x, d * d.y, d * d.z);
+        Self::new(self.t + (Matrix::IDENTITY * d.length_squared() - outer) * m.m())
+    }
+
+    /// Returns the inverse of the tensor, in `1 / (kg m²)`.
+    ///
+    /// Returns a zero matrix if the tensor is singular (e.g. for static bodies
+    /// with infinite inertia represented by a zero inverse).
+    ///
+    /// The determinant is compared relative to the cube of the mean principal
+    /// moment, so that small bodies are not mistaken for singular ones.
+    pub fn inverse(&self) -> Matrix {
+        let mean = (self.t.x_axis.x + self.t.y_axis.y + self.t.z_axis.z) / 3.;
+        if self.t.determinant().abs() <= mean * mean * mean * Magnitude::EPSILON {
+            Matrix::ZERO
+        } else {
+            self.t.inverse()
+        }
+    }
+}
+
+impl Add for InertiaTensor {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(self.t + other.t)
+    }
+}
+impl AddAssign for InertiaTensor {
+    fn add_assign(&mut self, other: Self) {
+        self.t += other.t;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the formulas behave as expected.
+    #[test]
+    fn inertia_formulas() {
+        // MomentOfInertia, Mass & Distance
+        let inertia = MomentOfInertia::from_mass_distance(Mass::new(2.), Length::new(3.));
+        assert_float_eq!(18., inertia.m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            3.,
+            inertia.calc_radius_of_gyration(Mass::new(2.)).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+
+        // Parallel axis theorem
+        let tensor = InertiaTensor::from_principal(
+            MomentOfInertia::new(1.),
+            MomentOfInertia::new(2.),
+            MomentOfInertia::new(3.),
+        )
+        .translated(Mass::new(2.), Direction::new(0., 0., 3.));
+        let [x, y, z] = tensor.diagonal();
+        assert_float_eq!(19., x.m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(20., y.m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(3., z.m(), r2nd <= Magnitude::EPSILON);
+
+        // Rotation of axes
+        let rotated = InertiaTensor::from_principal(
+            MomentOfInertia::new(1.),
+            MomentOfInertia::new(2.),
+            MomentOfInertia::new(3.),
+        )
+        .rotated(Orientation::from_rotation_z(core::f64::consts::FRAC_PI_2));
+        assert_float_eq!(
+            2.,
+            rotated.calc_moment_about(Direction::new(1., 0., 0.)).m(),
+            abs <= 1e-12
+        );
+
+        // Inverse of a small body, and of a singular tensor
+        let i = MomentOfInertia::new(1e-4);
+        let small = InertiaTensor::from_principal(i, i, i).inverse();
+        assert_float_eq!(1e4, small.x_axis.x, r2nd <= 1e-12);
+        let rod = InertiaTensor::from_principal(i, i, MomentOfInertia::new(0.));
+        assert_eq![Matrix::ZERO, rod.inverse()];
+        assert_eq![Matrix::ZERO, InertiaTensor::new(Matrix::ZERO).inverse()];
+    }
+}
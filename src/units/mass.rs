@@ -3,7 +3,9 @@
 //!
 //
 
-use crate::units::{Acceleration, Energy, Force, GravitationalFieldStrength, Speed, Weight};
+use crate::units::{
+    Acceleration, Density, Energy, Force, GravitationalFieldStrength, Speed, Volume, Weight,
+};
 use crate::Magnitude;
 
 /// The amount of matter in an object, in `kg` (kilograms).
@@ -40,6 +42,18 @@ impl Mass {
         Self::new(Speed::LIGHT_SQUARED.m() / e.m())
     }
 
+    /// Derive `Mass` from [`Density`] and [`Volume`] (`m = ρ × V`).
+    #[inline]
+    pub fn from_density_volume(d: Density, v: Volume) -> Self {
+        Self::new(d.m() * v.m())
+    }
+
+    /// (Alias of [from_density_volume][Mass::from_density_volume]).
+    #[inline]
+    pub fn from_volume_density(v: Volume, d: Density) -> Self {
+        Self::from_density_volume(d, v)
+    }
+
     /// Derive `Mass` from [`Force`] and [`Acceleration`] (`m = F / a`).
     #[inline]
     pub fn from_force_acceleration(f: Force, a: Acceleration) -> Self {
@@ -29,19 +29,28 @@ pub(crate) mod all {
     #[doc(inline)]
     pub use super::{
         acceleration::Acceleration,
+        angular_momentum::{AngularImpulse, AngularMomentum},
+        angular_velocity::AngularVelocity,
         area::Area,
         charge::Charge,
+        conductivity::ThermalConductivity,
+        damping::DampingCoefficient,
         density::Density,
-        energy::{Energy, Work},
+        energy::{Energy, EnergyBalance, Work},
+        flow::{MassFlowRate, VolumetricFlowRate},
         force::{Force, Weight},
         frequency::Frequency,
         gfs::{Gfs, GravitationalFieldStrength},
+        inertia::{InertiaTensor, MomentOfInertia},
         moment::{Moment, Torque},
-        momentum::Momentum,
+        momentum::{CollisionOutcome, Impulse, Momentum},
         power::Power,
         pressure::Pressure,
+        specific_heat::SpecificHeatCapacity,
         speed::Speed,
+        spring::SpringConstant,
         velocity::Velocity,
+        viscosity::{DynamicViscosity, KinematicViscosity},
         volume::Volume,
     };
 }
@@ -70,16 +79,25 @@ mod time;
 // scalar:
 mod area; // Length²
 mod charge;
+mod conductivity; // Power / (Length × Temperature)
+mod damping; // Force × Time / Length
 mod density;
 mod energy;
+mod flow; // Volume / Time, Mass / Time
 mod frequency;
+mod inertia; // Mass × Length²
 mod power;
 mod pressure;
+mod specific_heat; // Energy / (Mass × Temperature)
 mod speed; // Length / Time
+mod spring; // Force / Length
+mod viscosity; // Pressure × Time, Length² / Time
 mod volume; // Length³
 
 // vector:
 mod acceleration; // Length / Time²
+mod angular_momentum; // Inertia × Angle / Time
+mod angular_velocity; // Angle / Time
 mod force;
 mod gfs; // Force × Mass
 mod moment; // Force × Length
@@ -98,7 +116,7 @@ mod velocity; // Length / Time
 /// [1]:https://en.wikipedia.org/wiki/Motion_(physics)
 pub mod kinematics {
     #[doc(inline)]
-    pub use crate::units::{Distance, Length, Speed, Time};
+    pub use crate::units::{AngularVelocity, Distance, Length, Speed, Time};
 }
 
 /// Units for dynamics.
@@ -191,10 +209,52 @@ impl_unit!(Amount, "mol", "mole", "moles", moles);
 
 impl_unit!(Area, "m²", "square metre", "square metres", square_metres);
 impl_unit!(Charge, "C", "coulomb", "coulombs", coulombs);
+impl_unit!(
+    ThermalConductivity,
+    "W/(m K)",
+    "watt per metre kelvin",
+    "watts per metre kelvin",
+    watts_per_metre_kelvin
+);
+impl_unit!(
+    DampingCoefficient,
+    "N s/m",
+    "newton second per metre",
+    "newton seconds per metre",
+    newton_seconds_per_metre
+);
 impl_unit!(Energy, "J", "joule", "joules", joules);
+impl_unit!(
+    MassFlowRate,
+    "kg/s",
+    "kilogram per second",
+    "kilograms per second",
+    kilograms_per_second
+);
+impl_unit!(
+    VolumetricFlowRate,
+    "m³/s",
+    "cubic metre per second",
+    "cubic metres per second",
+    cubic_metres_per_second
+);
 impl_unit!(Frequency, "Hz", "hertz", "hertzs", hertzs);
+impl_unit!(
+    MomentOfInertia,
+    "kg m²",
+    "kilogram square metre",
+    "kilogram square metres",
+    kilogram_square_metres
+);
 impl_unit!(Power, "W", "watt", "watts", watts);
 impl_unit!(Pressure, "Pa", "pascal", "pascals", pascals);
+impl_unit!(
+    SpecificHeatCapacity,
+    "J/(kg K)",
+    "joule per kilogram kelvin",
+    "joules per kilogram kelvin",
+    joules_per_kilogram_kelvin
+);
 impl_unit!(
     Speed,
     "m/s",
@@ -202,6 +262,27 @@ impl_unit!(
     "metres per second",
     metres_per_second
 );
+impl_unit!(
+    SpringConstant,
+    "N/m",
+    "newton per metre",
+    "newtons per metre",
+    newtons_per_metre
+);
+impl_unit!(
+    DynamicViscosity,
+    "Pa s",
+    "pascal second",
+    "pascal seconds",
+    pascal_seconds
+);
+impl_unit!(
+    KinematicViscosity,
+    "m²/s",
+    "square metre per second",
+    "square metres per second",
+    square_metres_per_second
+);
 impl_unit!(Volume, "m³", "cubic metre", "cubic metres", cubic_metres);
 
 // vector
@@ -213,6 +294,20 @@ impl_unit!(
     "metres per second squared",
     metres_per_second_squared
 );
+impl_unit!(
+    AngularMomentum,
+    "kg m²/s",
+    "kilogram square metre per second",
+    "kilogram square metres per second",
+    kilogram_square_metres_per_second
+);
+impl_unit!(
+    AngularVelocity,
+    "rad/s",
+    "radian per second",
+    "radians per second",
+    radians_per_second
+);
 impl_unit!(Force, "N", "newton", "newtons", newtons);
 impl_unit!(
     GravitationalFieldStrength,
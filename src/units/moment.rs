@@ -3,7 +3,7 @@
 //!
 //
 
-use crate::units::{Distance, Force, Length};
+use crate::units::{AngularImpulse, Distance, Force, Length, Time};
 use crate::{Direction, Magnitude};
 
 /// The turning effect caused by a [`Force`] `F` applied at a [`Distance`] `d`
@@ -42,8 +42,26 @@ pub type Torque = Moment;
 
 /// # Formulas
 impl Moment {
+    /// Returns the `Moment` of a [`Force`] applied at the lever `r`, the
+    /// displacement from the pivot to the point of application (`τ = r × F`).
+    ///
+    /// It's perpendicular to both, along the axis of rotation.
+    pub fn from_lever_force(r: Direction, f: Force) -> Self {
+        Self::new(r.cross(f.d))
+    }
+
+    /// (Alias of [from_lever_force][Moment::from_lever_force]).
+    #[inline]
+    pub fn from_force_lever(f: Force, r: Direction) -> Self {
+        Self::from_lever_force(r, f)
+    }
+
     /// Returns the `Moment` of applying a [`Force`] over some [`Distance`]
     /// (`M = F × d`).
+    ///
+    /// This is the scalar special case of [`from_lever_force`][Moment::from_lever_force],
+    /// valid only for a force perpendicular to the lever. Its magnitude is
+    /// right, but its direction is the one of the force, not the axis.
     pub fn from_force_distance(f: Force, d: Distance) -> Self {
         Self::new(f.d * d.m())
     }
@@ -65,6 +83,13 @@ impl Moment {
     pub fn calc_force(&self, d: Distance) -> Force {
         Force::new(self.d / d.m())
     }
+
+    /// Calculates the [`AngularImpulse`] of this `Moment` acting over some
+    /// [`Time`] (`ΔL = τ × t`).
+    #[inline]
+    pub fn calc_angular_impulse(&self, t: Time) -> AngularImpulse {
+        AngularImpulse::from_torque_time(*self, t)
+    }
 }
 
 impl_vector_methods_2units![
@@ -99,5 +124,16 @@ mod tests {
             moment.calc_force(Length::new(0.2)).m(),
             r2nd <= Magnitude::EPSILON
         );
+
+        // Lever & Force
+        let f = Force::new(Direction::new(0., 30., 0.));
+        let torque = Torque::from_lever_force(Direction::new(0.2, 0., 0.), f);
+        assert_float_eq!(6., torque.d.z(), r2nd <= Magnitude::EPSILON);
+        // only the perpendicular component of the force turns
+        let slanted = Direction::new(0.2, 0.2, 0.);
+        let torque = Torque::from_lever_force(slanted, f);
+        assert_float_eq!(6., torque.m(), r2nd <= Magnitude::EPSILON);
+        let parallel = Torque::from_lever_force(Direction::new(0., 0.2, 0.), f);
+        assert_float_eq!(0., parallel.m(), abs <= Magnitude::EPSILON);
     }
 }
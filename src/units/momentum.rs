@@ -4,15 +4,25 @@
 //
 
 #[allow(unused_imports)]
-use crate::units::{Length, Mass, Time};
+use crate::units::{Energy, Force, Length, Mass, Time, Velocity};
 use crate::{Direction, Magnitude};
 
 /// `Momentum`, in [`Mass`] times [`Length`] per [`Time`]: `kg m/s`.
+///
+/// The total momentum of a system is conserved in the absence of external
+/// [`Force`]s.
 #[derive(Clone, Copy, Debug)]
 pub struct Momentum {
     pub d: Direction,
 }
 
+/// (== [`Momentum`]) The change in momentum produced by a [`Force`] acting
+/// over some [`Time`] (`J = F × t = Δp`).
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Impulse_(physics)>
+pub type Impulse = Momentum;
+
 impl Momentum {
     /// New Momentum.
     #[inline]
@@ -27,6 +37,179 @@ impl Momentum {
     }
 }
 
+/// # Formulas
+impl Momentum {
+    /// Derives the `Momentum` of a [`Mass`] moving at some [`Velocity`] (`p = m × v`).
+    #[inline]
+    pub fn from_mass_velocity(m: Mass, v: Velocity) -> Self {
+        Self::new(v.d * m.m())
+    }
+
+    /// (Alias of [from_mass_velocity][Momentum::from_mass_velocity]).
+    #[inline]
+    pub fn from_velocity_mass(v: Velocity, m: Mass) -> Self {
+        Self::from_mass_velocity(m, v)
+    }
+
+    /// Calculates the [`Velocity`] given the [`Mass`] (`v = p / m`).
+    #[inline]
+    pub fn calc_velocity(&self, m: Mass) -> Velocity {
+        Velocity::new(self.d / m.m())
+    }
+
+    /// Calculates the [`Mass`] given the [`Velocity`] (`m = |p| / |v|`).
+    #[inline]
+    pub fn calc_mass(&self, v: Velocity) -> Mass {
+        Mass::new(self.m() / v.m())
+    }
+
+    /// Calculates the kinetic [`Energy`] given the [`Mass`] (`K = p² / 2m`).
+    #[inline]
+    pub fn calc_kinetic_energy(&self, m: Mass) -> Energy {
+        Energy::new(self.d.magnitude_squared() / (2. * m.m()))
+    }
+
+    /// Returns the total `Momentum` of a system of bodies (`P = Σ mᵢ vᵢ`).
+    ///
+    /// Divided by the total mass, it's the velocity of the center of mass,
+    /// which doesn't change in an isolated system.
+    pub fn from_bodies(bodies: &[(Mass, Velocity)]) -> Self {
+        Self::new(
+            bodies
+                .iter()
+                .fold(Direction::ZERO, |p, (m, v)| p + v.d * m.m()),
+        )
+    }
+}
+
+/// # `Impulse` formulas
+impl Impulse {
+    /// Derives the `Impulse` of a constant [`Force`] acting over some [`Time`]
+    /// (`J = F × t`).
+    #[inline]
+    pub fn from_force_time(f: Force, t: Time) -> Self {
+        Self::new(f.d * t.m())
+    }
+
+    /// (Alias of [from_force_time][Impulse::from_force_time]).
+    #[inline]
+    pub fn from_time_force(t: Time, f: Force) -> Self {
+        Self::from_force_time(f, t)
+    }
+
+    /// Derives the `Impulse` as the change between two momenta (`J = p₁ - p₀`).
+    #[inline]
+    pub fn from_momenta(p0: Momentum, p1: Momentum) -> Self {
+        Self::new(p1.d - p0.d)
+    }
+
+    /// Calculates the mean [`Force`] that produces this `Impulse` over the
+    /// given [`Time`] (`F = J / t`).
+    #[inline]
+    pub fn calc_force(&self, t: Time) -> Force {
+        Force::new(self.d / t.m())
+    }
+
+    /// Calculates the [`Time`] a [`Force`] needs to produce this `Impulse`
+    /// (`t = |J| / |F|`).
+    #[inline]
+    pub fn calc_time(&self, f: Force) -> Time {
+        Time::new(self.m() / f.m())
+    }
+}
+
+/// The outcome of a collision between two bodies, with the [`Velocity`]s
+/// after the impact and the kinetic [`Energy`] lost in it.
+///
+/// The `restitution` coefficient `e` is the ratio between the relative speeds
+/// of separation and approach: `1` for a perfectly elastic collision, which
+/// conserves the kinetic energy, and `0` for a perfectly inelastic one, where
+/// the bodies move together afterwards. The momentum is always conserved.
+///
+/// A [`Mass`] can be infinite, like that of a wall.
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Elastic_collision>
+/// - <https://en.wikipedia.org/wiki/Inelastic_collision>
+/// - <https://en.wikipedia.org/wiki/Coefficient_of_restitution>
+#[derive(Clone, Copy, Debug)]
+pub struct CollisionOutcome {
+    pub v1: Velocity,
+    pub v2: Velocity,
+    pub energy_lost: Energy,
+}
+
+impl CollisionOutcome {
+    /// Returns the outcome of a head-on (1D) perfectly elastic collision.
+    pub fn elastic(m1: Mass, v1: Velocity, m2: Mass, v2: Velocity) -> Self {
+        Self::with_restitution(m1, v1, m2, v2, 1.)
+    }
+
+    /// Returns the outcome of a head-on (1D) perfectly inelastic collision.
+    pub fn inelastic(m1: Mass, v1: Velocity, m2: Mass, v2: Velocity) -> Self {
+        Self::with_restitution(m1, v1, m2, v2, 0.)
+    }
+
+    /// Returns the outcome of a head-on (1D) collision with the given
+    /// coefficient of `restitution`, along the line of the relative velocity
+    /// (`v₁' = (m₁ v₁ + m₂ v₂ + m₂ e (v₂ - v₁)) / (m₁ + m₂)`).
+    pub fn with_restitution(
+        m1: Mass,
+        v1: Velocity,
+        m2: Mass,
+        v2: Velocity,
+        restitution: Magnitude,
+    ) -> Self {
+        let relative = v1.d - v2.d;
+        let len = relative.magnitude();
+        let normal = if len == 0. {
+            Direction::ZERO
+        } else {
+            relative / len
+        };
+        Self::with_normal(m1, v1, m2, v2, normal, restitution)
+    }
+
+    /// Returns the outcome of an oblique (3D) collision with the given
+    /// coefficient of `restitution`, between two frictionless bodies touching
+    /// along the contact `normal`, pointing from the first body to the second.
+    ///
+    /// Only the velocity components along the normal change. If the bodies
+    /// are not approaching along the normal, nothing changes.
+    pub fn with_normal(
+        m1: Mass,
+        v1: Velocity,
+        m2: Mass,
+        v2: Velocity,
+        normal: Direction,
+        restitution: Magnitude,
+    ) -> Self {
+        let len = normal.magnitude();
+        let inverse = 1. / m1.m() + 1. / m2.m();
+        let approach = if len == 0. {
+            0.
+        } else {
+            (v1.d - v2.d).dot(normal) / len
+        };
+        if approach <= 0. || inverse == 0. {
+            return Self {
+                v1,
+                v2,
+                energy_lost: Energy::new(0.),
+            };
+        }
+        let n = normal / len;
+        let j = (1. + restitution) * approach / inverse;
+        // the loss, computed with the reduced mass, stays finite for infinite masses
+        let lost = 0.5 / inverse * (1. - restitution * restitution) * approach * approach;
+        Self {
+            v1: Velocity::new(v1.d - n * (j / m1.m())),
+            v2: Velocity::new(v2.d + n * (j / m2.m())),
+            energy_lost: Energy::new(lost),
+        }
+    }
+}
+
 impl_vector_methods_3units_1base_kilo![
     Momentum,
     q1a = g,
@@ -38,3 +221,103 @@ impl_vector_methods_3units_1base_kilo![
     Ja1 = "",
     Ja2 = per
 ];
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    fn along_x(v: Magnitude) -> Velocity {
+        Velocity::new(Direction::new(v, 0., 0.))
+    }
+
+    /// Checks the formulas behave as expected.
+    #[test]
+    fn momentum_formulas() {
+        // Momentum, Mass & Velocity
+        let p = Momentum::from_mass_velocity(Mass::new(4.), along_x(3.));
+        assert_float_eq!(12., p.m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            3.,
+            p.calc_velocity(Mass::new(4.)).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+        assert_float_eq!(4., p.calc_mass(along_x(3.)).m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            18.,
+            p.calc_kinetic_energy(Mass::new(4.)).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+
+        // Impulse, Force & Time
+        let f = Force::new(Direction::new(0., 50., 0.));
+        let j = Impulse::from_force_time(f, Time::new(0.2));
+        assert_float_eq!(10., j.m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            50.,
+            j.calc_force(Time::new(0.2)).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+        assert_float_eq!(0.2, j.calc_time(f).m(), r2nd <= Magnitude::EPSILON);
+        let p1 = Momentum::new(p.d + j.d);
+        assert_float_eq!(
+            10.,
+            Impulse::from_momenta(p, p1).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+    }
+
+    /// Checks the outcomes of the collisions conserve the momentum.
+    #[test]
+    fn momentum_collisions() {
+        let (m1, m2) = (Mass::new(2.), Mass::new(1.));
+        let before = Momentum::from_bodies(&[(m1, along_x(3.)), (m2, along_x(-1.))]);
+        let energy = |c: &CollisionOutcome| {
+            0.5 * m1.m() * c.v1.d.magnitude_squared() + 0.5 * m2.m() * c.v2.d.magnitude_squared()
+        };
+        let initial = 0.5 * 2. * 9. + 0.5 * 1. * 1.;
+
+        let elastic = CollisionOutcome::elastic(m1, along_x(3.), m2, along_x(-1.));
+        assert_float_eq!(1. / 3., elastic.v1.d.x(), abs <= 1e-12);
+        assert_float_eq!(13. / 3., elastic.v2.d.x(), abs <= 1e-12);
+        assert_float_eq!(0., elastic.energy_lost.m(), abs <= 1e-12);
+        assert_float_eq!(initial, energy(&elastic), abs <= 1e-12);
+
+        let inelastic = CollisionOutcome::inelastic(m1, along_x(3.), m2, along_x(-1.));
+        assert_float_eq!(5. / 3., inelastic.v1.d.x(), abs <= 1e-12);
+        assert_float_eq!(5. / 3., inelastic.v2.d.x(), abs <= 1e-12);
+        assert_float_eq!(
+            initial - energy(&inelastic),
+            inelastic.energy_lost.m(),
+            abs <= 1e-12
+        );
+
+        for c in [
+            elastic,
+            inelastic,
+            CollisionOutcome::with_restitution(m1, along_x(3.), m2, along_x(-1.), 0.5),
+        ] {
+            let after = Momentum::from_bodies(&[(m1, c.v1), (m2, c.v2)]);
+            assert_float_eq!(0., (after.d - before.d).magnitude(), abs <= 1e-12);
+        }
+
+        // an oblique elastic collision between equal masses, one at rest
+        let m = Mass::new(1.);
+        let normal = Direction::new(1., 1., 0.);
+        let c = CollisionOutcome::with_normal(m, along_x(2.), m, along_x(0.), normal, 1.);
+        assert_float_eq!(0., c.v1.d.dot(c.v2.d), abs <= 1e-12);
+        assert_float_eq!(1., c.v2.d.x(), abs <= 1e-12);
+        assert_float_eq!(1., c.v2.d.y(), abs <= 1e-12);
+
+        // bouncing off a wall
+        let wall = CollisionOutcome::with_restitution(
+            m,
+            along_x(5.),
+            Mass::new(Magnitude::INFINITY),
+            along_x(0.),
+            0.8,
+        );
+        assert_float_eq!(-4., wall.v1.d.x(), abs <= 1e-12);
+        assert_float_eq!(0., wall.v2.m(), abs <= 1e-12);
+        assert_float_eq!(0.5 * 25. - 0.5 * 16., wall.energy_lost.m(), abs <= 1e-12);
+    }
+}
@@ -4,7 +4,7 @@
 //
 
 #[allow(unused_imports)]
-use crate::units::{Energy, Time, Work};
+use crate::units::{Energy, Force, Time, Velocity, Work};
 use crate::Magnitude;
 
 /// How quickly the [`Energy`] is transferred, or the [`Work`] is done,
@@ -40,6 +40,19 @@ impl Power {
         Self::from_energy_time(e, t)
     }
 
+    /// Derives the instantaneous Power delivered by a [`Force`] to a body
+    /// moving at the given [`Velocity`] (`P = F · v`).
+    ///
+    /// It's negative when the force opposes the motion.
+    pub fn from_force_velocity(f: Force, v: Velocity) -> Self {
+        Self::new(f.d.dot(v.d))
+    }
+
+    /// (Alias of [from_force_velocity][Power::from_force_velocity]).
+    pub fn from_velocity_force(v: Velocity, f: Force) -> Self {
+        Self::from_force_velocity(f, v)
+    }
+
     /// Calculates the [`Energy`] given the [`Time`] (`E = P × t`).
     #[inline]
     pub fn calc_energy(&self, t: Time) -> Energy {
@@ -80,5 +93,14 @@ mod tests {
             power.calc_time(Energy::in_kJ(144.)).m,
             r2nd <= Magnitude::EPSILON
         );
+
+        // Power, Force & Velocity
+        let f = Force::new(crate::Direction::new(0., 100., 0.));
+        let v = Velocity::new(crate::Direction::new(2., -3., 0.));
+        assert_float_eq!(
+            -300.,
+            Power::from_force_velocity(f, v).m,
+            r2nd <= Magnitude::EPSILON
+        );
     }
 }
@@ -0,0 +1,78 @@
+// fisica::units::specific_heat
+//
+
+use crate::units::{Energy, Mass, Temperature};
+use crate::Magnitude;
+
+/// The heat [`Energy`] needed to raise the [`Temperature`] of a unit of
+/// [`Mass`] by one kelvin, in `J/(kg K)`.
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Specific_heat_capacity>
+#[derive(Clone, Copy, Debug)]
+pub struct SpecificHeatCapacity {
+    pub m: Magnitude,
+}
+
+impl SpecificHeatCapacity {
+    /// New SpecificHeatCapacity.
+    #[inline]
+    pub const fn new(m: Magnitude) -> Self {
+        Self { m }
+    }
+
+    /// Returns the magnitude.
+    #[inline]
+    pub const fn m(&self) -> Magnitude {
+        self.m
+    }
+}
+
+/// # Formulas
+impl SpecificHeatCapacity {
+    /// Derives the `SpecificHeatCapacity` from the heat [`Energy`] absorbed
+    /// by a [`Mass`] over a [`Temperature`] change (`c = Q / (m ΔT)`).
+    #[inline]
+    pub fn from_heat_mass_temperature(q: Energy, m: Mass, dt: Temperature) -> Self {
+        Self::new(q.m() / (m.m() * dt.m()))
+    }
+
+    /// Calculates the heat [`Energy`] absorbed by a [`Mass`] over a
+    /// [`Temperature`] change (`Q = m c ΔT`).
+    #[inline]
+    pub fn calc_heat(&self, m: Mass, dt: Temperature) -> Energy {
+        Energy::new(m.m() * self.m * dt.m())
+    }
+
+    /// Calculates the [`Temperature`] change of a [`Mass`] that absorbs the
+    /// given heat [`Energy`] (`ΔT = Q / (m c)`).
+    #[inline]
+    pub fn calc_temperature_change(&self, m: Mass, q: Energy) -> Temperature {
+        Temperature::new(q.m() / (m.m() * self.m))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the formulas behave as expected.
+    #[test]
+    fn specific_heat_formulas() {
+        // heating 2 kg of water by 10 K
+        let c = SpecificHeatCapacity::new(4182.);
+        let q = c.calc_heat(Mass::new(2.), Temperature::new(10.));
+        assert_float_eq!(83_640., q.m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            10.,
+            c.calc_temperature_change(Mass::new(2.), q).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+        let c2 = SpecificHeatCapacity::from_heat_mass_temperature(
+            q,
+            Mass::new(2.),
+            Temperature::new(10.),
+        );
+        assert_float_eq!(c.m(), c2.m(), r2nd <= Magnitude::EPSILON);
+    }
+}
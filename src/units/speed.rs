@@ -3,7 +3,8 @@
 //!
 //
 
-use crate::units::{Distance, Length, Time};
+use crate::constants::STANDARD_GRAVITY as G0;
+use crate::units::{Distance, Length, Mass, Time};
 use crate::Magnitude;
 
 /// How fast something is moving, in `m/s` ([`Distance`] per [`Time`]).
@@ -52,6 +53,21 @@ impl Speed {
     pub fn calc_time(&self, d: Distance) -> Time {
         Time::new(d.m / self.m)
     }
+
+    /// Derives the exhaust `Speed` of a rocket engine from its specific
+    /// impulse [`Time`] (`vₑ = Iₛₚ g₀`).
+    #[inline]
+    pub fn from_specific_impulse(isp: Time) -> Self {
+        Self::new(isp.m * G0)
+    }
+
+    /// Calculates the delta-v of a burn from the initial [`Mass`] `m0` to the
+    /// final [`Mass`] `mf`, being this the exhaust speed
+    /// (`Δv = vₑ ln(m₀ / m_f)`).
+    #[inline]
+    pub fn calc_delta_v(&self, m0: Mass, mf: Mass) -> Speed {
+        Speed::new(self.m * (m0.m() / mf.m()).ln())
+    }
 }
 
 /// # `Speed` constants by order of magnitude
@@ -114,12 +130,16 @@ impl Speed {
     /// (10e3) [*Escape velocity*][0] from Moon
     /// (`2.375 km/s`).
     ///
+    /// See also [`CelestialBody::escape_speed`][crate::celestial::CelestialBody::escape_speed].
+    ///
     /// [0]:https://en.wikipedia.org/wiki/Escape_velocity
     pub const ESCAPE_VELOCITY_MOON: Self = Speed::new(2_375.);
 
     /// (10e4) [*Escape velocity*][0] from Earth
     /// (`11.2 km/s`).
     ///
+    /// See also [`CelestialBody::escape_speed`][crate::celestial::CelestialBody::escape_speed].
+    ///
     /// [0]:https://en.wikipedia.org/wiki/Escape_velocity
     pub const ESCAPE_VELOCITY_EARTH: Self = Speed::new(11_200.);
 
@@ -190,5 +210,11 @@ mod tests {
             speed.calc_distance(Time::new(25.)).m,
             r2nd <= Magnitude::EPSILON
         );
+
+        // Rocket equation
+        let ve = Speed::from_specific_impulse(Time::new(300.));
+        assert_float_eq!(2_941.995, ve.m, r2nd <= Magnitude::EPSILON);
+        let dv = ve.calc_delta_v(Mass::new(core::f64::consts::E), Mass::new(1.));
+        assert_float_eq!(ve.m, dv.m, r2nd <= Magnitude::EPSILON);
     }
 }
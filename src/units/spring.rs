@@ -0,0 +1,106 @@
+// fisica::units::spring
+//
+
+use crate::units::{Energy, Force, Length};
+use crate::{Direction, Magnitude, Position};
+
+/// The stiffness of a spring, the [`Force`] needed per [`Length`] of
+/// extension, in `N/m`.
+///
+/// A linear spring follows [*Hooke's law*][0] (`F = -k x`).
+///
+/// [0]:https://en.wikipedia.org/wiki/Hooke%27s_law
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Stiffness>
+#[derive(Clone, Copy, Debug)]
+pub struct SpringConstant {
+    pub m: Magnitude,
+}
+
+impl SpringConstant {
+    /// New SpringConstant.
+    #[inline]
+    pub const fn new(m: Magnitude) -> Self {
+        Self { m }
+    }
+
+    /// Returns the magnitude.
+    #[inline]
+    pub const fn m(&self) -> Magnitude {
+        self.m
+    }
+}
+
+/// # Formulas
+impl SpringConstant {
+    /// Derives the `SpringConstant` from the [`Force`] magnitude needed for
+    /// some extension (`k = F / x`).
+    #[inline]
+    pub fn from_force_extension(f: Force, x: Length) -> Self {
+        Self::new(f.m() / x.m())
+    }
+
+    /// Calculates the restoring force magnitude for the given extension,
+    /// negative when stretched (`F = -k x`).
+    #[inline]
+    pub fn calc_restoring_force(&self, x: Length) -> Magnitude {
+        -self.m * x.m()
+    }
+
+    /// Calculates the elastic potential [`Energy`] stored by the given
+    /// extension (`U = ½ k x²`).
+    #[inline]
+    pub fn calc_energy(&self, x: Length) -> Energy {
+        Energy::from_stiffness_extension(*self, x)
+    }
+
+    /// Calculates the [`Force`] on the end at `a` of a spring between the
+    /// [`Position`]s `a` and `b` with the given `rest` length.
+    ///
+    /// It pulls `a` towards `b` when stretched, and pushes it away when
+    /// compressed. The force on `b` is the opposite.
+    pub fn calc_force_between(&self, a: Position, b: Position, rest: Length) -> Force {
+        let axis = b - a;
+        let len = axis.magnitude();
+        if len == 0. {
+            return Force::new(Direction::ZERO);
+        }
+        Force::new(axis * (self.m * (len - rest.m()) / len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the formulas behave as expected.
+    #[test]
+    fn spring_formulas() {
+        let k = SpringConstant::from_force_extension(
+            Force::new(Direction::new(0., 50., 0.)),
+            Length::new(0.25),
+        );
+        assert_float_eq!(200., k.m(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            -20.,
+            k.calc_restoring_force(Length::new(0.1)),
+            r2nd <= Magnitude::EPSILON
+        );
+        assert_float_eq!(
+            1.,
+            k.calc_energy(Length::new(0.1)).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+
+        // stretched by 0.5 m it pulls the ends together
+        let (a, b) = (Position::ZERO, Position::new(0., 0., 1.5));
+        let f = k.calc_force_between(a, b, Length::new(1.));
+        assert_float_eq!(100., f.d.z(), r2nd <= Magnitude::EPSILON);
+        let f = k.calc_force_between(b, a, Length::new(1.));
+        assert_float_eq!(-100., f.d.z(), r2nd <= Magnitude::EPSILON);
+        // compressed it pushes them apart
+        let f = k.calc_force_between(a, b, Length::new(2.));
+        assert_float_eq!(-100., f.d.z(), r2nd <= Magnitude::EPSILON);
+    }
+}
@@ -3,6 +3,7 @@
 //!
 //
 
+use crate::constants::STANDARD_GRAVITY as G0;
 use crate::units::{Distance, Energy, Length, Power, Speed};
 use crate::Magnitude;
 
@@ -95,6 +96,13 @@ impl Time {
     pub fn calc_energy(&self, p: Power) -> Time {
         Time::new(self.m * p.m)
     }
+
+    /// Derives the specific impulse `Time` of a rocket engine from its exhaust
+    /// [`Speed`] (`Iₛₚ = vₑ / g₀`).
+    #[inline]
+    pub fn from_exhaust_speed(ve: Speed) -> Self {
+        Self::new(ve.m / G0)
+    }
 }
 
 /// # `Time` constants by order of magnitude]
@@ -201,6 +209,10 @@ mod tests {
             time.calc_power(Energy::in_kJ(144.)).m,
             r2nd <= Magnitude::EPSILON
         );
+
+        // Specific impulse
+        let isp = Time::from_exhaust_speed(Speed::new(2_941.995));
+        assert_float_eq!(300., isp.m, r2nd <= Magnitude::EPSILON);
     }
 
     /// Checks the constants are defined as expected.
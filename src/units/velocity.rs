@@ -3,13 +3,12 @@
 //!
 //
 
-#![allow(unused_imports)]
-use crate::units::{Distance, Length, Magnitude, Speed, Time};
-use crate::Direction;
+use crate::units::{Speed, Time};
+use crate::{Direction, Magnitude, Position};
 
 /// How fast something is moving in a particular direction, in `m/s`.
 ///
-/// This is a vector unit.
+/// This is a vector unit. Its magnitude is a [`Speed`].
 #[derive(Clone, Copy, Debug)]
 pub struct Velocity {
     pub d: Direction,
@@ -28,29 +27,168 @@ impl Velocity {
 }
 
 /// # `Velocity` formulas
-impl Speed {
-    // /// Derives the `Speed` from the given [`Distance`] and [`Time`] (`s = d / t`).
-    // #[inline]
-    // pub fn from_distance_time(d: Distance, t: Time) -> Self {
-    //     Self::new(d.m / t.m)
-    // }
-    //
-    // /// (Alias of [from_distance_time][Speed::from_distance_time]).
-    // #[inline]
-    // pub fn from_time_distance(t: Time, d: Distance) -> Self {
-    //     Self::from_distance_time(d, t)
-    // }
-    //
-    // /// Calculates the [`Distance`] given the [`Time`] (`d = s × t`).
-    // #[inline]
-    // pub fn calc_distance(&self, t: Time) -> Distance {
-    //     Length::new(self.m * t.m)
-    // }
-    //
-    // /// Calculates the [`Time`] given the [`Distance`] (`t = d / s`).
-    // pub fn calc_time(&self, d: Distance) -> Time {
-    //     Time::new(d.m / self.m)
-    // }
+impl Velocity {
+    /// Derives the `Velocity` from the given displacement and [`Time`] (`v = d / t`).
+    #[inline]
+    pub fn from_displacement_time(d: Direction, t: Time) -> Self {
+        Self::new(d / t.m)
+    }
+
+    /// (Alias of [from_displacement_time][Velocity::from_displacement_time]).
+    #[inline]
+    pub fn from_time_displacement(t: Time, d: Direction) -> Self {
+        Self::from_displacement_time(d, t)
+    }
+
+    /// Derives the `Velocity` from a [`Speed`] along a direction (`v = s × d̂`).
+    ///
+    /// The `direction` doesn't need to be normalized. If it's zero, so is
+    /// the velocity.
+    pub fn from_speed_direction(s: Speed, direction: Direction) -> Self {
+        let len = direction.magnitude();
+        if len == 0. {
+            return Self::new(Direction::ZERO);
+        }
+        Self::new(direction * (s.m / len))
+    }
+
+    /// Calculates the displacement given the [`Time`] (`d = v × t`).
+    #[inline]
+    pub fn calc_displacement(&self, t: Time) -> Direction {
+        self.d * t.m
+    }
+
+    /// Returns the [`Speed`], the magnitude of the velocity.
+    #[inline]
+    pub fn speed(&self) -> Speed {
+        Speed::new(self.m())
+    }
+
+    /// Returns the unit direction of motion, or zero if not moving.
+    pub fn direction(&self) -> Direction {
+        let len = self.m();
+        if len == 0. {
+            Direction::ZERO
+        } else {
+            self.d / len
+        }
+    }
+}
+
+/// # Relative velocity
+///
+/// Between inertial frames moving at constant velocity, velocities add up
+/// (the [*Galilean transformation*][0]), which is valid far below the speed of
+/// light.
+///
+/// [0]:https://en.wikipedia.org/wiki/Galilean_transformation
+impl Velocity {
+    /// Returns this `Velocity` as seen by an `observer` moving at the given
+    /// velocity (`v' = v - u`).
+    #[inline]
+    pub fn calc_relative_to(&self, observer: Velocity) -> Self {
+        Self::new(self.d - observer.d)
+    }
+
+    /// Returns the velocity in the parent frame of this `Velocity`, measured
+    /// in a `frame` that moves at the given velocity (`v = v' + u`).
+    #[inline]
+    pub fn calc_from_frame(&self, frame: Velocity) -> Self {
+        Self::new(self.d + frame.d)
+    }
+}
+
+/// # Velocity from sampled positions
+impl Velocity {
+    /// Returns the average `Velocity` between two [`Position`]s separated by
+    /// some [`Time`] (`v = (p₁ - p₀) / t`).
+    #[inline]
+    pub fn from_positions_time(p0: Position, p1: Position, t: Time) -> Self {
+        Self::from_displacement_time(p1 - p0, t)
+    }
+
+    /// Returns the average `Velocity` over the time-ordered `samples`,
+    /// from the first to the last, or `None` if they span no time.
+    pub fn from_samples_average(samples: &[(Time, Position)]) -> Option<Self> {
+        let ((t0, p0), (t1, p1)) = (samples.first()?, samples.last()?);
+        let dt = t1.m - t0.m;
+        (dt != 0.).then(|| Self::new((*p1 - *p0) / dt))
+    }
+
+    /// Returns the instantaneous `Velocity` at the [`Time`] `at`, estimated
+    /// from the time-ordered `samples`.
+    ///
+    /// It differentiates the parabola through the three samples closest in time,
+    /// which is exact for a constant acceleration. With only two samples
+    /// it's their average velocity. Returns `None` with fewer samples, or if
+    /// some of them share the same time.
+    pub fn from_samples_at(samples: &[(Time, Position)], at: Time) -> Option<Self> {
+        match samples.len() {
+            0 | 1 => None,
+            2 => Self::from_samples_average(samples),
+            n => {
+                let closest = (0..n)
+                    .min_by(|a, b| {
+                        let da = (samples[*a].0.m - at.m).abs();
+                        let db = (samples[*b].0.m - at.m).abs();
+                        da.total_cmp(&db)
+                    })
+                    .unwrap_or(0);
+                let i = closest.clamp(1, n - 2);
+                let ((t0, p0), (t1, p1), (t2, p2)) = (samples[i - 1], samples[i], samples[i + 1]);
+                let (t0, t1, t2, t) = (t0.m, t1.m, t2.m, at.m);
+                if t0 == t1 || t1 == t2 || t0 == t2 {
+                    return None;
+                }
+                // derivatives of the Lagrange basis polynomials at `t`
+                let l0 = (2. * t - t1 - t2) / ((t0 - t1) * (t0 - t2));
+                let l1 = (2. * t - t0 - t2) / ((t1 - t0) * (t1 - t2));
+                let l2 = (2. * t - t0 - t1) / ((t2 - t0) * (t2 - t1));
+                Some(Self::new(p0 * l0 + p1 * l1 + p2 * l2))
+            }
+        }
+    }
+}
+
+/// # Non-SI conversions
+///
+/// Using the same factors as the [`Speed`] constants.
+impl Velocity {
+    /// New `Velocity` in kilometres per hour (see [`Speed::KM_H`]).
+    #[inline]
+    pub fn in_km_h(d: Direction) -> Self {
+        Self::new(d * Speed::KM_H.m)
+    }
+
+    /// Returns the `Velocity` in kilometres per hour.
+    #[inline]
+    pub fn as_km_h(&self) -> Direction {
+        self.d / Speed::KM_H.m
+    }
+
+    /// New `Velocity` in miles per hour (see [`Speed::MPH`]).
+    #[inline]
+    pub fn in_mph(d: Direction) -> Self {
+        Self::new(d * Speed::MPH.m)
+    }
+
+    /// Returns the `Velocity` in miles per hour.
+    #[inline]
+    pub fn as_mph(&self) -> Direction {
+        self.d / Speed::MPH.m
+    }
+
+    /// New `Velocity` in knots (see [`Speed::KNOT`]).
+    #[inline]
+    pub fn in_knots(d: Direction) -> Self {
+        Self::new(d * Speed::KNOT.m)
+    }
+
+    /// Returns the `Velocity` in knots.
+    #[inline]
+    pub fn as_knots(&self) -> Direction {
+        self.d / Speed::KNOT.m
+    }
 }
 
 impl_vector_methods_2units![
@@ -62,23 +200,63 @@ impl_vector_methods_2units![
     Ja = per
 ];
 
-// #[cfg(test)]
-// mod tests {
-//     use {super::*, float_eq::assert_float_eq};
-//
-//     /// Checks the constants are defined as expected.
-//     #[test]
-//     fn speed_constants() {
-//         //assert_float_eq!(0.0000000000162, Length::PLANCK.as_ym(), r2nd <= Magnitude::EPSILON);
-//     }
-//
-//     /// Checks the formulas behave as expected.
-//     #[test]
-//     fn speed_formulas() {
-//         // Distance, Speed & Time
-//         let speed = Speed::from_distance_time(Distance::in_m(300.), Time(25.));
-//         assert_float_eq!(12., speed.m, r2nd <= Magnitude::EPSILON);
-//         assert_float_eq!(25., speed.calc_time(Length(300.)).m, r2nd <= Magnitude::EPSILON);
-//         assert_float_eq!(300., speed.calc_distance(Time(25.)).m, r2nd <= Magnitude::EPSILON);
-//     }
-// }
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the formulas behave as expected.
+    #[test]
+    fn velocity_formulas() {
+        // displacement, Velocity & Time
+        let v = Velocity::from_displacement_time(Direction::new(300., 400., 0.), Time::new(25.));
+        assert_float_eq!(20., v.speed().m, r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            400.,
+            v.calc_displacement(Time::new(25.)).y(),
+            r2nd <= Magnitude::EPSILON
+        );
+        let same = Velocity::from_speed_direction(v.speed(), Direction::new(3., 4., 0.));
+        assert_float_eq!(0., (v.d - same.d).magnitude(), abs <= 1e-12);
+        assert_float_eq!(0.8, v.direction().y(), r2nd <= Magnitude::EPSILON);
+
+        // relative velocity
+        let train = Velocity::new(Direction::new(30., 0., 0.));
+        let walking = Velocity::new(Direction::new(-1.5, 0., 0.));
+        let ground = walking.calc_from_frame(train);
+        assert_float_eq!(28.5, ground.d.x(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            -1.5,
+            ground.calc_relative_to(train).d.x(),
+            r2nd <= Magnitude::EPSILON
+        );
+
+        // conversions
+        let v = Velocity::in_km_h(Direction::new(100., 0., 0.));
+        assert_float_eq!(100., v.as_km_h().x(), r2nd <= Magnitude::EPSILON);
+        assert_float_eq!(
+            Speed::KNOT.m * 2.,
+            Velocity::in_knots(Direction::new(0., 2., 0.)).m(),
+            r2nd <= Magnitude::EPSILON
+        );
+        assert_float_eq!(62.14, v.as_mph().x(), abs <= 0.01);
+    }
+
+    /// Checks the velocity derived from sampled positions.
+    #[test]
+    fn velocity_samples() {
+        // a projectile: p = v₀ t + ½ g t²
+        let (v0, g) = (Direction::new(10., 20., 0.), Direction::new(0., -9.8, 0.));
+        let samples: [(Time, Position); 6] = core::array::from_fn(|i| {
+            let t = i as Magnitude * 0.5;
+            (Time::new(t), v0 * t + g * (0.5 * t * t))
+        });
+        let v = Velocity::from_samples_at(&samples, Time::new(1.2)).unwrap();
+        let expected = v0 + g * 1.2;
+        assert_float_eq!(0., (v.d - expected).magnitude(), abs <= 1e-9);
+
+        let average = Velocity::from_samples_average(&samples).unwrap();
+        let expected = v0 + g * 1.25;
+        assert_float_eq!(0., (average.d - expected).magnitude(), abs <= 1e-9);
+        assert!(Velocity::from_samples_at(&samples[..1], Time::new(0.)).is_none());
+    }
+}
@@ -0,0 +1,132 @@
+// fisica::units::viscosity
+//
+
+use crate::units::{Density, Length, Pressure, Speed};
+use crate::Magnitude;
+
+/// The resistance of a fluid to shear, the shear stress per unit of velocity
+/// gradient, in `Pa s`.
+///
+/// A newtonian fluid follows [*Newton's law of viscosity*][0] (`τ = μ du/dy`).
+///
+/// [0]:https://en.wikipedia.org/wiki/Newtonian_fluid
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Viscosity>
+#[derive(Clone, Copy, Debug)]
+pub struct DynamicViscosity {
+    pub m: Magnitude,
+}
+
+impl DynamicViscosity {
+    /// New DynamicViscosity.
+    #[inline]
+    pub const fn new(m: Magnitude) -> Self {
+        Self { m }
+    }
+
+    /// Returns the magnitude.
+    #[inline]
+    pub const fn m(&self) -> Magnitude {
+        self.m
+    }
+}
+
+/// # Formulas
+impl DynamicViscosity {
+    /// Calculates the shear stress between two layers of fluid separated by
+    /// a `gap`, sliding at a relative [`Speed`] (`τ = μ u / h`).
+    #[inline]
+    pub fn calc_shear_stress(&self, s: Speed, gap: Length) -> Pressure {
+        Pressure::new(self.m * s.m() / gap.m())
+    }
+
+    /// Calculates the [*Reynolds number*][0] of a fluid of the given
+    /// [`Density`] flowing at a [`Speed`] past a characteristic [`Length`]
+    /// (`Re = ρ u L / μ`).
+    ///
+    /// It's the ratio of the inertial to the viscous forces: the flow is
+    /// laminar for low values, and turbulent for high values.
+    ///
+    /// [0]:https://en.wikipedia.org/wiki/Reynolds_number
+    #[inline]
+    pub fn calc_reynolds_number(&self, d: Density, s: Speed, l: Length) -> Magnitude {
+        d.m() * s.m() * l.m() / self.m
+    }
+}
+
+/// The [`DynamicViscosity`] of a fluid divided by its [`Density`], in `m²/s`.
+///
+/// It measures how quickly momentum diffuses through the fluid.
+///
+/// # External links
+/// - <https://en.wikipedia.org/wiki/Viscosity#Kinematic_viscosity>
+#[derive(Clone, Copy, Debug)]
+pub struct KinematicViscosity {
+    pub m: Magnitude,
+}
+
+impl KinematicViscosity {
+    /// New KinematicViscosity.
+    #[inline]
+    pub const fn new(m: Magnitude) -> Self {
+        Self { m }
+    }
+
+    /// Returns the magnitude.
+    #[inline]
+    pub const fn m(&self) -> Magnitude {
+        self.m
+    }
+}
+
+/// # Formulas
+impl KinematicViscosity {
+    /// Derives the `KinematicViscosity` of a fluid from its
+    /// [`DynamicViscosity`] and [`Density`] (`ν = μ / ρ`).
+    #[inline]
+    pub fn from_dynamic_density(mu: DynamicViscosity, d: Density) -> Self {
+        Self::new(mu.m() / d.m())
+    }
+
+    /// Calculates the [`DynamicViscosity`] of a fluid of the given
+    /// [`Density`] (`μ = ν ρ`).
+    #[inline]
+    pub fn calc_dynamic(&self, d: Density) -> DynamicViscosity {
+        DynamicViscosity::new(self.m * d.m())
+    }
+
+    /// Calculates the Reynolds number of a flow at a [`Speed`] past a
+    /// characteristic [`Length`] (`Re = u L / ν`).
+    #[inline]
+    pub fn calc_reynolds_number(&self, s: Speed, l: Length) -> Magnitude {
+        s.m() * l.m() / self.m
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, float_eq::assert_float_eq};
+
+    /// Checks the formulas behave as expected.
+    #[test]
+    fn viscosity_formulas() {
+        // water flowing at 1 m/s through a 5 cm pipe
+        let mu = DynamicViscosity::new(1e-3);
+        let re = mu.calc_reynolds_number(Density::new(1000.), Speed::new(1.), Length::new(0.05));
+        assert_float_eq!(50_000., re, r2nd <= 1e-15);
+
+        let tau = mu.calc_shear_stress(Speed::new(2.), Length::new(0.001));
+        assert_float_eq!(2., tau.m(), r2nd <= 1e-15);
+
+        let nu = KinematicViscosity::from_dynamic_density(mu, Density::new(1000.));
+        assert_float_eq!(1e-6, nu.m(), r2nd <= 1e-15);
+        assert_float_eq!(
+            mu.m(),
+            nu.calc_dynamic(Density::new(1000.)).m(),
+            r2nd <= 1e-15
+        );
+        let re2 = nu.calc_reynolds_number(Speed::new(1.), Length::new(0.05));
+        assert_float_eq!(re, re2, r2nd <= 1e-15);
+    }
+}
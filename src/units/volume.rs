@@ -4,7 +4,7 @@
 //
 
 #[allow(unused_imports)]
-use crate::units::Length;
+use crate::units::{Density, Length, Mass};
 use crate::Magnitude;
 
 /// `Volume`, in `m³` (cubic [`Length`]).
@@ -28,6 +28,27 @@ impl Volume {
 }
 
 /// # Formulas
+impl Volume {
+    /// Derives the `Volume` from the given [`Mass`] and [`Density`] (`V = m / ρ`).
+    #[inline]
+    pub fn from_mass_density(m: Mass, d: Density) -> Self {
+        Self::new(m.m() / d.m())
+    }
+
+    /// (Alias of [from_mass_density][Volume::from_mass_density]).
+    #[inline]
+    pub fn from_density_mass(d: Density, m: Mass) -> Self {
+        Self::from_mass_density(m, d)
+    }
+
+    /// Calculates the [`Mass`] given the [`Density`] (`m = ρ × V`).
+    #[inline]
+    pub fn calc_mass(&self, d: Density) -> Mass {
+        Mass::new(d.m() * self.m)
+    }
+}
+
+/// # Non SI units conversions
 impl Volume {
     scalar_methods![
         Volume,